@@ -1,10 +1,11 @@
 use eframe::egui;
-use crate::{config::{AppConfig, ThemeType}, error::{Result, TwiggyError}, log_error, logging::{log_performance, log_memory_usage}, ui::components::{log_viewer::LogViewer, commit_list::CommitListComponent}, git::repository::GitRepository};
-use std::{time::Instant, path::PathBuf};
+use crate::{bench::{self, BenchmarkReport}, diagnostics::{write_report, ErrorSummary}, config::{parse_hex_color, AppConfig, CustomTheme, KeybindAction, RepoOverrides, ThemeType, MAX_UI_SCALE, MIN_UI_SCALE}, error::{Result, Severity, TwiggyError}, log_error, logging::{log_performance, log_memory_usage}, ui::components::{command_palette::{PaletteCommand, PaletteEntry}, log_viewer::LogViewer, commit_list::{CommitListComponent, CopyTarget}, BlameView, CommandPalette, CommitDetail, CommitGraph, DiffViewer, FileTree, FileViewer, StatusBar, pickaxe_search::PickaxeSearch}, ui::{selection_history::SelectionHistory, settings_search}, util::{debounce::Debouncer, fonts::font_definitions_with, keys::format_shortcut, os::{open_url, open_path_in_file_manager, open_terminal_at}}, git::{filter::{hide_merge_commits, merge_stashes}, graph_export::to_dot, web_url, repository::{discover_repository_root, is_git_repository, validate_config_entry, BranchFilter, BranchInfo, GitRepository, RepositoryHealth, MIN_COMMIT_PREFIX_LEN}, types::{Commit, CommitId, CommitMatch, DiffAlgorithm, FetchSummary, RefKind, RefLabel, RemoteInfo, SignatureStatus, StatusCategory, StatusEntry, SubmoduleInfo, TreeEntry}}};
+use std::{collections::HashSet, time::Instant, path::{Path, PathBuf}, sync::{mpsc, Arc, atomic::{AtomicBool, AtomicUsize, Ordering}}};
 
 #[derive(Debug)]
 pub struct ErrorState {
     pub message: String,
+    pub details: String,
     pub error_code: u32,
     pub is_recoverable: bool,
     pub suggested_action: Option<String>,
@@ -16,11 +17,12 @@ impl ErrorState {
     pub fn new(error: &TwiggyError) -> Self {
         Self {
             message: error.user_message(),
+            details: format!("{:?}", error),
             error_code: error.error_code(),
             is_recoverable: error.is_recoverable(),
             suggested_action: error.suggested_action(),
             timestamp: Instant::now(),
-            show_dialog: true,
+            show_dialog: error.severity().shows_dialog(),
         }
     }
 }
@@ -29,22 +31,87 @@ pub struct TwiggyApp {
     config: AppConfig,
     error_state: Option<ErrorState>,
     notifications: Vec<Notification>,
+    notification_history: Vec<NotificationRecord>,
+    show_notification_history: bool,
     last_config_save: Option<Instant>,
     performance_metrics: PerformanceMetrics,
     show_settings: bool,
     settings_tab: SettingsTab,
+    settings_query: String,
+    show_non_default_settings: bool,
     temp_config: AppConfig,
     pending_window_changes: bool,
     last_window_state: Option<WindowState>,
+    window_save: Debouncer,
     log_viewer: LogViewer,
     show_log_viewer: bool,
     show_about: bool,
+    show_repository_info: bool,
+    show_statistics: bool,
+    /// Font setting last handed to egui; `None` until fonts are first set.
+    applied_font_family: Option<Option<String>>,
     show_shortcuts: bool,
+    shortcuts: Vec<(KeybindAction, egui::KeyboardShortcut)>,
+    recording_keybind: Option<KeybindAction>,
+    command_palette: CommandPalette,
     current_repository: Option<GitRepository>,
     repository_loading: bool,
     last_branch_refresh: Option<Instant>,
     branch_refresh_interval: std::time::Duration,
     commit_list: CommitListComponent,
+    commit_graph: CommitGraph,
+    diff_viewer: DiffViewer,
+    file_tree: FileTree,
+    commit_detail: CommitDetail,
+    selection_history: SelectionHistory,
+    blame_view: BlameView,
+    file_viewer: FileViewer,
+    working_status: Vec<StatusEntry>,
+    status_bar: StatusBar,
+    local_branches: Vec<BranchInfo>,
+    remote_branches: Vec<BranchInfo>,
+    viewed_branch: Option<String>,
+    /// Path whose history replaces the commit list, with its commits.
+    file_history: Option<(String, Vec<Commit>)>,
+    branch_commits: Vec<Commit>,
+    stash_commits: Vec<Commit>,
+    file_tree_entries: Option<(CommitId, Vec<TreeEntry>)>,
+    pickaxe_search: PickaxeSearch,
+    /// Text of the "go to hash" box and the outcome of its last lookup.
+    commit_search: String,
+    commit_search_match: Option<CommitMatch>,
+    /// Text of the open "Go to commit" dialog, if any.
+    go_to_commit: Option<String>,
+    window_title: Option<String>,
+    last_closed: Option<GitRepository>,
+    pending_close_confirmation: Option<String>,
+    /// Local branch whose ref chip was clicked, waiting for the checkout to
+    /// be confirmed.
+    pending_checkout: Option<String>,
+    repo_config_edits: Option<Vec<ConfigEntryEdit>>,
+    new_config_entry: (String, String),
+    pending_fetch: Option<FetchDialog>,
+    pending_open: Option<PendingOpen>,
+    repo_overrides: Option<RepoOverrides>,
+    /// `config` with the open repository's `.twiggy.json` applied.
+    repo_settings: Option<AppConfig>,
+    splash_started: Option<Instant>,
+    splash_dismissed: bool,
+    benchmark_receiver: Option<mpsc::Receiver<Result<BenchmarkReport>>>,
+    web_remote_url: Option<String>,
+    remotes: Vec<RemoteInfo>,
+    submodules: Vec<SubmoduleInfo>,
+    window_focused: Option<bool>,
+    last_focus_refresh: Option<Instant>,
+    last_auto_fetch: Option<Instant>,
+    pending_remote_fetch: Option<PendingFetch>,
+    pending_ref_listing: Option<PendingRefListing>,
+    pending_refresh: Option<PendingRefresh>,
+    /// Repository found around the working directory at startup, offered
+    /// through a notification.
+    detected_repository: Option<PathBuf>,
+    cwd_detection: Option<mpsc::Receiver<Option<PathBuf>>>,
+    cwd_detection_started: bool,
 }
 
 #[derive(Debug)]
@@ -53,6 +120,22 @@ pub struct Notification {
     pub notification_type: NotificationType,
     pub timestamp: Instant,
     pub auto_dismiss_seconds: Option<u32>,
+    pub action: Option<NotificationAction>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NotificationAction {
+    ReopenRepository,
+    OpenDetectedRepository,
+}
+
+impl NotificationAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            NotificationAction::ReopenRepository => "Reopen",
+            NotificationAction::OpenDetectedRepository => "Open",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -63,6 +146,50 @@ pub enum NotificationType {
     Success,
 }
 
+impl NotificationType {
+    pub fn color(&self) -> egui::Color32 {
+        match self {
+            NotificationType::Info => egui::Color32::from_rgb(70, 130, 180),
+            NotificationType::Warning => egui::Color32::from_rgb(255, 165, 0),
+            NotificationType::Error => egui::Color32::from_rgb(220, 50, 50),
+            NotificationType::Success => egui::Color32::from_rgb(50, 180, 50),
+        }
+    }
+    
+    pub fn label(&self) -> &'static str {
+        match self {
+            NotificationType::Info => "Info",
+            NotificationType::Warning => "Warning",
+            NotificationType::Error => "Error",
+            NotificationType::Success => "Success",
+        }
+    }
+}
+
+/// Saves and closes the settings dialog while it is open. Fixed rather than
+/// configurable, so no keybind defaults to it.
+const SAVE_SETTINGS_SHORTCUT: egui::KeyboardShortcut = egui::KeyboardShortcut::new(egui::Modifiers::CTRL, egui::Key::S);
+
+/// How many notifications the Notifications window keeps.
+const NOTIFICATION_HISTORY_LIMIT: usize = 50;
+
+/// A notification as it was shown, kept after its toast is dismissed.
+#[derive(Debug, Clone)]
+pub struct NotificationRecord {
+    pub message: String,
+    pub notification_type: NotificationType,
+    pub time: chrono::DateTime<chrono::Local>,
+}
+
+/// Quiet period after the last resize or move before the window state is
+/// written, so a drag saves once instead of every frame.
+const WINDOW_SAVE_DELAY: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Changes smaller than these, in points, are treated as jitter rather than
+/// a resize or move.
+const WINDOW_SIZE_THRESHOLD: f32 = 5.0;
+const WINDOW_POSITION_THRESHOLD: f32 = 10.0;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct WindowState {
     pub width: f32,
@@ -84,6 +211,89 @@ impl WindowState {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct ConfigEntryEdit {
+    pub key: String,
+    pub original: String,
+    pub value: String,
+    pub inherited: Option<String>,
+    /// Keys with several values are listed once, read-only.
+    pub multivar: bool,
+}
+
+/// File formats offered by File > Export Commits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitExportFormat {
+    Json,
+    Csv,
+}
+
+impl CommitExportFormat {
+    pub const ALL: [CommitExportFormat; 2] = [CommitExportFormat::Json, CommitExportFormat::Csv];
+    
+    pub fn label(&self) -> &'static str {
+        match self {
+            CommitExportFormat::Json => "JSON",
+            CommitExportFormat::Csv => "CSV",
+        }
+    }
+    
+    pub fn extension(&self) -> &'static str {
+        match self {
+            CommitExportFormat::Json => "json",
+            CommitExportFormat::Csv => "csv",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FetchDialog {
+    pub remote: String,
+    pub refs: Vec<(String, bool)>,
+    pub filter: String,
+}
+
+/// A repository being opened on a background thread. The worker sends the
+/// opened repository together with the error from the initial commit load, if any.
+pub struct PendingOpen {
+    pub path: PathBuf,
+    pub revision: Option<String>,
+    pub loaded: Arc<AtomicUsize>,
+    /// Expected number of commits, 0 while unknown.
+    pub total: Arc<AtomicUsize>,
+    /// Set to make the worker stop loading; its result is then discarded.
+    pub cancel: Arc<AtomicBool>,
+    pub receiver: mpsc::Receiver<Result<(GitRepository, Option<TwiggyError>)>>,
+}
+
+/// A background reload of the repository at `path`; the worker sends back a
+/// freshly opened and loaded repository that replaces the current one.
+pub struct PendingRefresh {
+    pub path: PathBuf,
+    /// Report the outcome even when nothing changed; off for automatic
+    /// refreshes.
+    pub announce: bool,
+    pub receiver: mpsc::Receiver<Result<GitRepository>>,
+}
+
+/// A background fetch of the repository at `path`, started from the Fetch
+/// menu or by the auto-fetch timer.
+pub struct PendingFetch {
+    pub path: PathBuf,
+    /// Started from the menu: failures open the error dialog and success is
+    /// always reported.
+    pub manual: bool,
+    pub receiver: mpsc::Receiver<Result<FetchSummary>>,
+}
+
+/// Remote refs being listed in the background before a manual fetch, to
+/// decide whether to ask which ones to fetch.
+pub struct PendingRefListing {
+    pub path: PathBuf,
+    pub remote: String,
+    pub receiver: mpsc::Receiver<Result<Vec<String>>>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum SettingsTab {
     Window,
@@ -91,6 +301,82 @@ pub enum SettingsTab {
     Git,
     Performance,
     Logging,
+    Keybinds,
+}
+
+impl SettingsTab {
+    pub const ALL: [SettingsTab; 6] = [
+        SettingsTab::Window,
+        SettingsTab::Theme,
+        SettingsTab::Git,
+        SettingsTab::Performance,
+        SettingsTab::Logging,
+        SettingsTab::Keybinds,
+    ];
+    
+    pub fn label(&self) -> &'static str {
+        match self {
+            SettingsTab::Window => "Window",
+            SettingsTab::Theme => "Theme",
+            SettingsTab::Git => "Git",
+            SettingsTab::Performance => "Performance",
+            SettingsTab::Logging => "Logging",
+            SettingsTab::Keybinds => "Keybinds",
+        }
+    }
+}
+
+/// Searchable label of every row in the settings dialog. Each must match the
+/// label its tab passes to `setting_visible`.
+const SETTINGS_LABELS: &[(SettingsTab, &str)] = &[
+    (SettingsTab::Window, "Width"),
+    (SettingsTab::Window, "Height"),
+    (SettingsTab::Window, "Maximize Window"),
+    (SettingsTab::Window, "Position"),
+    (SettingsTab::Window, "Splash Screen"),
+    (SettingsTab::Window, "Splash Timeout"),
+    (SettingsTab::Window, "Window Title"),
+    (SettingsTab::Theme, "Theme Type"),
+    (SettingsTab::Theme, "Font Size"),
+    (SettingsTab::Theme, "Font"),
+    (SettingsTab::Theme, "Max Author Length"),
+    (SettingsTab::Theme, "Dark Mode Override"),
+    (SettingsTab::Theme, "Accent Color"),
+    (SettingsTab::Theme, "Custom Theme"),
+    (SettingsTab::Git, "Max Commits"),
+    (SettingsTab::Git, "Recent Repositories"),
+    (SettingsTab::Git, "Auto Fetch"),
+    (SettingsTab::Git, "Refresh on Focus"),
+    (SettingsTab::Git, "Reopen on Start"),
+    (SettingsTab::Git, "Working Directory"),
+    (SettingsTab::Git, "Confirm Large Fetch"),
+    (SettingsTab::Git, "Ref Threshold"),
+    (SettingsTab::Git, "Diff Algorithm"),
+    (SettingsTab::Git, "Expanded Commits"),
+    (SettingsTab::Git, "Show Stashes"),
+    (SettingsTab::Git, "Default Clone Path"),
+    (SettingsTab::Git, "Fetch Interval (minutes)"),
+    (SettingsTab::Performance, "Developer Mode"),
+    (SettingsTab::Performance, "Enable Caching"),
+    (SettingsTab::Performance, "Cache Size (MB)"),
+    (SettingsTab::Performance, "Background Operations"),
+    (SettingsTab::Performance, "Max Background Threads"),
+    (SettingsTab::Performance, "Render FPS Limit"),
+    (SettingsTab::Logging, "Log Level"),
+    (SettingsTab::Logging, "File Logging"),
+    (SettingsTab::Logging, "Console Logging"),
+    (SettingsTab::Logging, "Max File Size (MB)"),
+    (SettingsTab::Logging, "Max Log Files"),
+    (SettingsTab::Logging, "Log Directory"),
+];
+
+/// Every searchable settings row in tab order, keybind actions included.
+pub fn settings_index() -> Vec<(SettingsTab, String)> {
+    SETTINGS_LABELS
+        .iter()
+        .map(|(tab, label)| (tab.clone(), label.to_string()))
+        .chain(KeybindAction::ALL.iter().map(|action| (SettingsTab::Keybinds, action.label().to_string())))
+        .collect()
 }
 
 #[derive(Debug, Default)]
@@ -98,31 +384,88 @@ pub struct PerformanceMetrics {
     pub frame_count: u64,
     pub last_frame_time: Option<Instant>,
     pub average_frame_time_ms: f32,
+    pub list_render_time_ms: f32,
 }
 
 impl Default for TwiggyApp {
     fn default() -> Self {
         let config = AppConfig::default();
         Self {
+            shortcuts: config.keybinds.shortcuts(),
+            recording_keybind: None,
+            command_palette: CommandPalette::new(),
             temp_config: config.clone(),
             config,
             error_state: None,
             notifications: Vec::new(),
+            notification_history: Vec::new(),
+            show_notification_history: false,
             last_config_save: None,
             performance_metrics: PerformanceMetrics::default(),
             show_settings: false,
             settings_tab: SettingsTab::Window,
+            settings_query: String::new(),
+            show_non_default_settings: false,
             pending_window_changes: false,
             last_window_state: None,
+            window_save: Debouncer::new(WINDOW_SAVE_DELAY),
             log_viewer: LogViewer::new(),
             show_log_viewer: false,
             show_about: false,
+            show_repository_info: false,
+            show_statistics: false,
+            applied_font_family: None,
             show_shortcuts: false,
             current_repository: None,
             repository_loading: false,
             last_branch_refresh: None,
             branch_refresh_interval: std::time::Duration::from_secs(5),
             commit_list: CommitListComponent::new(),
+            commit_graph: CommitGraph::new(),
+            diff_viewer: DiffViewer::new(),
+            file_tree: FileTree::new(),
+            commit_detail: CommitDetail::new(),
+            selection_history: SelectionHistory::default(),
+            blame_view: BlameView::new(),
+            file_viewer: FileViewer::new(),
+            working_status: Vec::new(),
+            status_bar: StatusBar::new(),
+            local_branches: Vec::new(),
+            remote_branches: Vec::new(),
+            viewed_branch: None,
+            file_history: None,
+            branch_commits: Vec::new(),
+            stash_commits: Vec::new(),
+            file_tree_entries: None,
+            pickaxe_search: PickaxeSearch::new(),
+            commit_search: String::new(),
+            commit_search_match: None,
+            go_to_commit: None,
+            window_title: None,
+            last_closed: None,
+            pending_close_confirmation: None,
+            pending_checkout: None,
+            repo_config_edits: None,
+            new_config_entry: (String::new(), String::new()),
+            pending_fetch: None,
+            pending_open: None,
+            repo_overrides: None,
+            repo_settings: None,
+            splash_started: None,
+            splash_dismissed: false,
+            benchmark_receiver: None,
+            web_remote_url: None,
+            remotes: Vec::new(),
+            submodules: Vec::new(),
+            window_focused: None,
+            last_focus_refresh: None,
+            last_auto_fetch: None,
+            pending_remote_fetch: None,
+            pending_ref_listing: None,
+            pending_refresh: None,
+            detected_repository: None,
+            cwd_detection: None,
+            cwd_detection_started: false,
         }
     }
 }
@@ -156,27 +499,92 @@ impl TwiggyApp {
             }
         }
 
+        let pruned = config.recent_repositories.validate_and_clean();
+        if pruned > 0 {
+            tracing::info!("Removed {} missing repositories from the recent list", pruned);
+            if let Err(e) = config.save() {
+                tracing::warn!("Failed to save config: {}", e);
+            }
+        }
+
         let mut app = Self {
+            shortcuts: config.keybinds.shortcuts(),
+            recording_keybind: None,
+            command_palette: CommandPalette::new(),
             temp_config: config.clone(),
             config,
             error_state: None,
             notifications: Vec::new(),
+            notification_history: Vec::new(),
+            show_notification_history: false,
             last_config_save: Some(Instant::now()),
             performance_metrics: PerformanceMetrics::default(),
             show_settings: false,
             settings_tab: SettingsTab::Window,
+            settings_query: String::new(),
+            show_non_default_settings: false,
             pending_window_changes: false,
             last_window_state: None,
+            window_save: Debouncer::new(WINDOW_SAVE_DELAY),
             log_viewer: LogViewer::new(),
             show_log_viewer: false,
             show_about: false,
+            show_repository_info: false,
+            show_statistics: false,
+            applied_font_family: None,
             show_shortcuts: false,
             current_repository: None,
             repository_loading: false,
             last_branch_refresh: None,
             branch_refresh_interval: std::time::Duration::from_secs(5),
             commit_list: CommitListComponent::new(),
+            commit_graph: CommitGraph::new(),
+            diff_viewer: DiffViewer::new(),
+            file_tree: FileTree::new(),
+            commit_detail: CommitDetail::new(),
+            selection_history: SelectionHistory::default(),
+            blame_view: BlameView::new(),
+            file_viewer: FileViewer::new(),
+            working_status: Vec::new(),
+            status_bar: StatusBar::new(),
+            local_branches: Vec::new(),
+            remote_branches: Vec::new(),
+            viewed_branch: None,
+            file_history: None,
+            branch_commits: Vec::new(),
+            stash_commits: Vec::new(),
+            file_tree_entries: None,
+            pickaxe_search: PickaxeSearch::new(),
+            commit_search: String::new(),
+            commit_search_match: None,
+            go_to_commit: None,
+            window_title: None,
+            last_closed: None,
+            pending_close_confirmation: None,
+            pending_checkout: None,
+            repo_config_edits: None,
+            new_config_entry: (String::new(), String::new()),
+            pending_fetch: None,
+            pending_open: None,
+            repo_overrides: None,
+            repo_settings: None,
+            splash_started: None,
+            splash_dismissed: false,
+            benchmark_receiver: None,
+            web_remote_url: None,
+            remotes: Vec::new(),
+            submodules: Vec::new(),
+            window_focused: None,
+            last_focus_refresh: None,
+            last_auto_fetch: None,
+            pending_remote_fetch: None,
+            pending_ref_listing: None,
+            pending_refresh: None,
+            detected_repository: None,
+            cwd_detection: None,
+            cwd_detection_started: false,
         };
+        app.sync_syntax_highlighting();
 
         app.add_notification(
             "Twiggy initialized successfully".to_string(),
@@ -194,30 +602,52 @@ impl TwiggyApp {
         log_error!(error);
         
         let error_state = ErrorState::new(&error);
-        let is_recoverable = error_state.is_recoverable;
+        let severity = error.severity();
         
-        tracing::debug!("Error is recoverable: {}", is_recoverable);
+        tracing::debug!("Error severity: {:?}, recoverable: {}", severity, error_state.is_recoverable);
         
         self.error_state = Some(error_state);
         
-        if !is_recoverable {
-            tracing::warn!("Critical error occurred, application may be unstable");
-            self.add_notification(
-                "Critical error occurred. Application may be unstable.".to_string(),
-                NotificationType::Error,
-                None,
-            );
+        match severity {
+            Severity::Critical => {
+                tracing::warn!("Critical error occurred, application may be unstable");
+                self.add_notification(
+                    "Critical error occurred. Application may be unstable.".to_string(),
+                    NotificationType::Error,
+                    None,
+                );
+            }
+            Severity::Error => {}
+            Severity::Warning => self.add_notification(
+                error.user_message(),
+                NotificationType::Warning,
+                Some(5),
+            ),
         }
     }
 
     pub fn add_notification(&mut self, message: String, notification_type: NotificationType, auto_dismiss_seconds: Option<u32>) {
+        self.add_notification_with_action(message, notification_type, auto_dismiss_seconds, None);
+    }
+
+    pub fn add_notification_with_action(&mut self, message: String, notification_type: NotificationType, auto_dismiss_seconds: Option<u32>, action: Option<NotificationAction>) {
         tracing::debug!("Adding notification: {:?} - {}", notification_type, message);
         
+        self.notification_history.push(NotificationRecord {
+            message: message.clone(),
+            notification_type: notification_type.clone(),
+            time: chrono::Local::now(),
+        });
+        if self.notification_history.len() > NOTIFICATION_HISTORY_LIMIT {
+            self.notification_history.remove(0);
+        }
+        
         let notification = Notification {
             message,
             notification_type,
             timestamp: Instant::now(),
             auto_dismiss_seconds,
+            action,
         };
         
         self.notifications.push(notification);
@@ -317,8 +747,38 @@ impl TwiggyApp {
                 true
             }
         });
+        
+        if self.last_closed.is_some() && !self.has_notification_action(NotificationAction::ReopenRepository) {
+            tracing::debug!("Reopen window expired, releasing closed repository");
+            self.last_closed = None;
+        }
+    }
+    
+    fn has_notification_action(&self, action: NotificationAction) -> bool {
+        self.notifications.iter().any(|notification| notification.action == Some(action))
     }
 
+    fn update_window_title(&mut self, ctx: &egui::Context) {
+        let title = match self.current_repository {
+            Some(ref repo) if self.settings().ui.show_repo_in_title => match repo.current_branch() {
+                Some(branch) => format!("{} ({}) — Twiggy", repo.repository_name(), branch),
+                None => format!("{} — Twiggy", repo.repository_name()),
+            },
+            _ => "Twiggy".to_string(),
+        };
+        
+        if self.window_title.as_deref() != Some(title.as_str()) {
+            tracing::debug!("Updating window title: {}", title);
+            ctx.send_viewport_cmd(egui::ViewportCommand::Title(title.clone()));
+            self.window_title = Some(title);
+        }
+    }
+    
+    fn sync_syntax_highlighting(&mut self) {
+        self.diff_viewer.syntax_highlighting = self.config.ui.syntax_highlighting;
+        self.file_viewer.syntax_highlighting = self.config.ui.syntax_highlighting;
+    }
+    
     fn refresh_branch_info_if_needed(&mut self) {
         if let Some(ref mut repo) = self.current_repository {
             let now = Instant::now();
@@ -332,6 +792,7 @@ impl TwiggyApp {
                     tracing::warn!("Failed to refresh branch information: {}", e);
                 } else {
                     self.last_branch_refresh = Some(now);
+                    self.refresh_status_bar();
                 }
             }
         }
@@ -340,6 +801,7 @@ impl TwiggyApp {
     fn render_error_dialog(&mut self, ctx: &egui::Context) {
         let mut should_close = false;
         let mut should_recover = false;
+        let mut should_save_report = false;
         
         if let Some(ref error_state) = self.error_state {
             if error_state.show_dialog {
@@ -378,6 +840,10 @@ impl TwiggyApp {
                                     should_recover = true;
                                 }
                                 
+                                if ui.button("💾 Save Report…").clicked() {
+                                    should_save_report = true;
+                                }
+                                
                                 if ui.button("✅ OK").clicked() {
                                     should_close = true;
                                 }
@@ -393,6 +859,10 @@ impl TwiggyApp {
             }
         }
         
+        if should_save_report {
+            self.save_error_report();
+        }
+        
         if should_recover {
             if let Err(e) = self.try_recover_from_error() {
                 tracing::error!("Recovery failed: {}", e);
@@ -406,6 +876,7 @@ impl TwiggyApp {
         }
 
         let mut to_remove = Vec::new();
+        let mut triggered_action = None;
         
         egui::Area::new("notifications")
             .anchor(egui::Align2::RIGHT_TOP, egui::vec2(-10.0, 10.0))
@@ -424,17 +895,19 @@ impl TwiggyApp {
                             ui.horizontal(|ui| {
                                 ui.label(egui::RichText::new(&notification.message)
                                     .size(14.0)
-                                    .color(match notification.notification_type {
-                                        NotificationType::Info => egui::Color32::from_rgb(70, 130, 180),
-                                        NotificationType::Warning => egui::Color32::from_rgb(255, 165, 0),
-                                        NotificationType::Error => egui::Color32::from_rgb(220, 50, 50),
-                                        NotificationType::Success => egui::Color32::from_rgb(50, 180, 50),
-                                    }));
+                                    .color(notification.notification_type.color()));
                                 
                                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                                     if ui.small_button("✕").clicked() {
                                         to_remove.push(index);
                                     }
+                                    
+                                    if let Some(action) = notification.action {
+                                        if ui.small_button(action.label()).clicked() {
+                                            triggered_action = Some(action);
+                                            to_remove.push(index);
+                                        }
+                                    }
                                 });
                             });
                         });
@@ -447,6 +920,21 @@ impl TwiggyApp {
         for &index in to_remove.iter().rev() {
             self.notifications.remove(index);
         }
+        
+        if let Some(action) = triggered_action {
+            self.handle_notification_action(action);
+        }
+    }
+    
+    fn handle_notification_action(&mut self, action: NotificationAction) {
+        match action {
+            NotificationAction::ReopenRepository => self.reopen_closed_repository(),
+            NotificationAction::OpenDetectedRepository => {
+                if let Some(path) = self.detected_repository.take() {
+                    self.open_repository_path(path);
+                }
+            }
+        }
     }
 
     pub fn config(&self) -> &AppConfig {
@@ -461,6 +949,10 @@ impl TwiggyApp {
         if !self.show_settings {
             return;
         }
+        
+        if self.recording_keybind.is_none() && ctx.input_mut(|i| i.consume_shortcut(&SAVE_SETTINGS_SHORTCUT)) {
+            self.save_settings(ctx, frame);
+        }
 
         egui::Window::new("Settings")
             .collapsible(false)
@@ -468,23 +960,55 @@ impl TwiggyApp {
             .default_width(600.0)
             .default_height(500.0)
             .show(ctx, |ui| {
+                let index = settings_index();
+                
+                ui.horizontal(|ui| {
+                    ui.label("🔍");
+                    let response = ui.add(egui::TextEdit::singleline(&mut self.settings_query).hint_text("Search settings"));
+                    if response.changed() && settings_search::match_count(&self.settings_query, &index, &self.settings_tab) == 0 {
+                        if let Some(tab) = settings_search::first_match(&self.settings_query, &index) {
+                            self.settings_tab = tab;
+                        }
+                    }
+                    
+                    if !self.settings_query.is_empty() && ui.small_button("✖").clicked() {
+                        self.settings_query.clear();
+                    }
+                    
+                    ui.checkbox(&mut self.show_non_default_settings, "Show non-default settings");
+                });
+                
+                let searching = !self.settings_query.trim().is_empty();
                 ui.horizontal(|ui| {
-                    ui.selectable_value(&mut self.settings_tab, SettingsTab::Window, "Window");
-                    ui.selectable_value(&mut self.settings_tab, SettingsTab::Theme, "Theme");
-                    ui.selectable_value(&mut self.settings_tab, SettingsTab::Git, "Git");
-                    ui.selectable_value(&mut self.settings_tab, SettingsTab::Performance, "Performance");
-                    ui.selectable_value(&mut self.settings_tab, SettingsTab::Logging, "Logging");
+                    for tab in SettingsTab::ALL {
+                        let text = if searching {
+                            format!("{} ({})", tab.label(), settings_search::match_count(&self.settings_query, &index, &tab))
+                        } else {
+                            tab.label().to_string()
+                        };
+                        ui.selectable_value(&mut self.settings_tab, tab, text);
+                    }
                 });
+                
+                if searching && settings_search::first_match(&self.settings_query, &index).is_none() {
+                    ui.weak("No settings match your search");
+                }
 
                 ui.separator();
 
                 egui::ScrollArea::vertical().show(ui, |ui| {
+                    if self.show_non_default_settings {
+                        self.render_non_default_settings(ui, ctx);
+                        return;
+                    }
+                    
                     match self.settings_tab {
                         SettingsTab::Window => self.render_window_settings(ui, ctx),
                         SettingsTab::Theme => self.render_theme_settings(ui, ctx),
                         SettingsTab::Git => self.render_git_settings(ui, ctx),
                         SettingsTab::Performance => self.render_performance_settings(ui, ctx),
                         SettingsTab::Logging => self.render_logging_settings(ui, ctx),
+                        SettingsTab::Keybinds => self.render_keybind_settings(ui),
                     }
                 });
 
@@ -510,32 +1034,9 @@ impl TwiggyApp {
                         }
                     }
 
-                    if ui.button("💾 Save").clicked() {
-                        match self.apply_configuration_with_frame(ctx, frame) {
-                            Ok(()) => {
-                                if let Err(e) = self.config.save() {
-                                    self.add_notification(
-                                        format!("Failed to save settings: {}", e),
-                                        NotificationType::Error,
-                                        Some(5),
-                                    );
-                                } else {
-                                    self.add_notification(
-                                        "Settings saved successfully".to_string(),
-                                        NotificationType::Success,
-                                        Some(3),
-                                    );
-                                    self.show_settings = false;
-                                }
-                            }
-                            Err(e) => {
-                                self.add_notification(
-                                    format!("Failed to apply settings: {}", e),
-                                    NotificationType::Error,
-                                    Some(5),
-                                );
-                            }
-                        }
+                    let save_button = egui::Button::new("💾 Save").shortcut_text(format_shortcut(&SAVE_SETTINGS_SHORTCUT));
+                    if ui.add(save_button).clicked() {
+                        self.save_settings(ctx, frame);
                     }
 
                     if ui.button("Reset to Defaults").clicked() {
@@ -544,416 +1045,935 @@ impl TwiggyApp {
                         ctx.request_repaint();
                     }
 
+                    if ui.button("Export Settings…").clicked() {
+                        self.export_settings();
+                    }
+
+                    if ui.button("Import Settings…").clicked() {
+                        self.import_settings(ctx);
+                    }
+
                     if ui.button("Cancel").clicked() {
                         self.temp_config = self.config.clone();
                         self.apply_theme_to_context(ctx);
+                        self.recording_keybind = None;
                         self.show_settings = false;
                     }
                 });
             });
     }
 
-    fn render_window_settings(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
-        ui.heading("Window Settings");
-        ui.add_space(10.0);
-
-        let mut changed = false;
-
-        ui.horizontal(|ui| {
-            ui.label("Width:");
-            if ui.add(egui::Slider::new(&mut self.temp_config.window.width, 400.0..=4000.0)
-                .suffix(" px")).changed() {
-                changed = true;
+    fn save_settings(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        match self.apply_configuration_with_frame(ctx, frame) {
+            Ok(()) => {
+                if let Err(e) = self.config.save() {
+                    self.add_notification(
+                        format!("Failed to save settings: {}", e),
+                        NotificationType::Error,
+                        Some(5),
+                    );
+                } else {
+                    self.add_notification(
+                        "Settings saved successfully".to_string(),
+                        NotificationType::Success,
+                        Some(3),
+                    );
+                    self.show_settings = false;
+                }
             }
-        });
-
-        ui.horizontal(|ui| {
-            ui.label("Height:");
-            if ui.add(egui::Slider::new(&mut self.temp_config.window.height, 300.0..=3000.0)
-                .suffix(" px")).changed() {
-                changed = true;
+            Err(e) => {
+                self.add_notification(
+                    format!("Failed to apply settings: {}", e),
+                    NotificationType::Error,
+                    Some(5),
+                );
             }
-        });
+        }
+    }
 
-        ui.add_space(10.0);
-        
-        ui.horizontal(|ui| {
-            let is_maximized = ctx.input(|i| i.viewport().maximized.unwrap_or(false));
-            let button_text = if is_maximized { "Restore Window" } else { "Maximize Window" };
-            
-            if ui.button(button_text).clicked() {
-                ctx.send_viewport_cmd(egui::ViewportCommand::Maximized(!is_maximized));
-                changed = true;
-            }
-            
-            ui.label("Toggle between normal and maximized window");
-        });
+    /// Failures are reported as notifications rather than through
+    /// `handle_error`, which would replace the error being reported.
+    fn save_error_report(&mut self) {
+        let Some(error) = self.error_state.as_ref().map(|state| ErrorSummary {
+            code: state.error_code,
+            message: state.message.clone(),
+            details: state.details.clone(),
+            recoverable: state.is_recoverable,
+        }) else {
+            return;
+        };
 
-        ui.add_space(10.0);
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("JSON files", &["json"])
+            .set_file_name("twiggy_report.json")
+            .save_file()
+        else {
+            return;
+        };
 
+        let log_file = crate::logging::log_directory(&self.config.logging).join(crate::logging::LOG_FILE_NAME);
+        let result = std::fs::File::create(&path)
+            .map_err(|e| TwiggyError::Io {
+                operation: format!("creating {}", path.display()),
+                source: e,
+            })
+            .and_then(|file| write_report(std::io::BufWriter::new(file), &error, &self.config, &log_file));
 
-        if let (Some(x), Some(y)) = (self.temp_config.window.position_x, self.temp_config.window.position_y) {
-            ui.horizontal(|ui| {
-                ui.label("Position:");
-                ui.label(format!("({:.0}, {:.0})", x, y));
-            });
+        match result {
+            Ok(()) => self.add_notification(
+                format!("Error report saved to {}", path.display()),
+                NotificationType::Success,
+                Some(3),
+            ),
+            Err(e) => self.add_notification(
+                format!("Failed to save error report: {}", e),
+                NotificationType::Error,
+                Some(5),
+            ),
         }
+    }
 
-        if changed {
-            ctx.request_repaint();
+    fn export_settings(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("JSON files", &["json"])
+            .set_file_name("twiggy_settings.json")
+            .save_file()
+        else {
+            return;
+        };
+
+        match self.config.export_to(&path) {
+            Ok(()) => self.add_notification(
+                format!("Settings exported to {}", path.display()),
+                NotificationType::Success,
+                Some(3),
+            ),
+            Err(e) => self.add_notification(
+                format!("Failed to export settings: {}", e),
+                NotificationType::Error,
+                Some(5),
+            ),
         }
     }
 
-    fn render_theme_settings(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
-        ui.heading("Theme Settings");
-        ui.add_space(10.0);
-
-        let mut changed = false;
+    /// Loads an exported configuration into the dialog; it takes effect once
+    /// applied or saved like any other edit.
+    fn import_settings(&mut self, ctx: &egui::Context) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("JSON files", &["json"])
+            .pick_file()
+        else {
+            return;
+        };
 
-        ui.horizontal(|ui| {
-            ui.label("Theme Type:");
-            let response = egui::ComboBox::from_label("")
-                .selected_text(format!("{:?}", self.temp_config.theme.theme_type))
-                .show_ui(ui, |ui| {
-                    ui.selectable_value(&mut self.temp_config.theme.theme_type, ThemeType::Light, "Light");
-                    ui.selectable_value(&mut self.temp_config.theme.theme_type, ThemeType::Dark, "Dark");
-                    ui.selectable_value(&mut self.temp_config.theme.theme_type, ThemeType::System, "System");
-                });
-            if response.response.changed() {
-                changed = true;
+        match AppConfig::import_from(&path) {
+            Ok(config) => {
+                self.temp_config = config;
+                self.apply_theme_to_temp_context(ctx);
+                ctx.request_repaint();
+                self.add_notification(
+                    "Settings imported; apply or save to keep them".to_string(),
+                    NotificationType::Info,
+                    Some(5),
+                );
             }
-        });
+            Err(e) => self.add_notification(
+                format!("Failed to import settings: {}", e),
+                NotificationType::Error,
+                Some(5),
+            ),
+        }
+    }
+
+    /// Lists every setting that differs from the defaults, each with a
+    /// button that resets just that field.
+    fn render_non_default_settings(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
+        ui.heading("Non-default Settings");
+        ui.add_space(10.0);
         
-        ui.horizontal(|ui| {
-            ui.label("Font Size:");
-            if ui.add(egui::Slider::new(&mut self.temp_config.theme.font_size, 8.0..=32.0)
-                .suffix(" px")).changed() {
-                changed = true;
-            }
-        });
+        let differences: Vec<_> = self.temp_config
+            .diff_from_default()
+            .into_iter()
+            .filter(|(field, _, _)| settings_search::matches(&self.settings_query, field))
+            .collect();
         
-        ui.horizontal(|ui| {
-            ui.label("Dark Mode Override:");
-            if ui.checkbox(&mut self.temp_config.theme.dark_mode, "Force dark mode").changed() {
-                changed = true;
-            }
-        });
+        if differences.is_empty() {
+            ui.weak("All settings are at their defaults");
+            return;
+        }
         
-        ui.horizontal(|ui| {
-            ui.label("Accent Color:");
-            let mut color_text = self.temp_config.theme.accent_color.clone();
-            if ui.text_edit_singleline(&mut color_text).changed() {
-                self.temp_config.theme.accent_color = color_text;
-                changed = true;
-            }
-            
-            if let Ok(color) = self.parse_hex_color(&self.temp_config.theme.accent_color) {
-                let mut color32 = color;
-                if ui.color_edit_button_srgba(&mut color32).changed() {
-                    self.temp_config.theme.accent_color = format!(
-                        "#{:02X}{:02X}{:02X}",
-                        color32.r(),
-                        color32.g(),
-                        color32.b()
-                    );
-                    changed = true;
+        let mut reset = None;
+        egui::Grid::new("non_default_settings")
+            .num_columns(4)
+            .spacing([16.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.strong("Setting");
+                ui.strong("Current");
+                ui.strong("Default");
+                ui.label("");
+                ui.end_row();
+                
+                for (field, current, default) in &differences {
+                    ui.monospace(field);
+                    ui.monospace(current);
+                    ui.weak(default);
+                    if ui.button("Reset").clicked() {
+                        reset = Some(field.clone());
+                    }
+                    ui.end_row();
+                }
+            });
+        
+        if let Some(field) = reset {
+            match self.temp_config.reset_field(&field) {
+                Ok(()) => {
+                    self.apply_theme_to_temp_context(ctx);
+                    ctx.request_repaint();
                 }
+                Err(e) => self.add_notification(
+                    format!("Failed to reset {}: {}", field, e),
+                    NotificationType::Error,
+                    Some(5),
+                ),
             }
-        });
-
-        if changed {
-            self.apply_theme_to_temp_context(ctx);
-            ctx.request_repaint();
         }
     }
 
-    fn render_git_settings(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
-        ui.heading("Git Settings");
-        ui.add_space(10.0);
-
-        let mut changed = false;
-
-        ui.horizontal(|ui| {
-            ui.label("Max Commits:");
-            if ui.add(egui::Slider::new(&mut self.temp_config.git.max_commits, 100..=50000)
-                .logarithmic(true)).changed() {
-                changed = true;
-            }
-        });
+    fn setting_visible(&self, label: &str) -> bool {
+        settings_search::matches(&self.settings_query, label)
+    }
 
-        ui.horizontal(|ui| {
-            ui.label("Auto Fetch:");
-            if ui.checkbox(&mut self.temp_config.git.auto_fetch, "Automatically fetch from remote").changed() {
-                changed = true;
-            }
-        });
+    fn render_keybind_settings(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Keyboard Shortcuts");
+        ui.add_space(10.0);
+        
+        egui::Grid::new("keybind_settings")
+            .num_columns(3)
+            .spacing([20.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for action in KeybindAction::ALL {
+                    if !self.setting_visible(action.label()) {
+                        continue;
+                    }
+                    
+                    ui.label(action.label());
+                    
+                    if self.recording_keybind == Some(action) {
+                        ui.label(egui::RichText::new("Press a key… (Esc to cancel)").italics());
+                    } else {
+                        ui.monospace(self.temp_config.keybinds.get(action));
+                    }
+                    
+                    if ui.button("Change").clicked() {
+                        self.recording_keybind = Some(action);
+                    }
+                    ui.end_row();
+                }
+            });
+        
+        ui.add_space(10.0);
+        
+        if let Err(e) = self.temp_config.keybinds.validate() {
+            ui.colored_label(egui::Color32::RED, e.to_string());
+        }
+        
+        if ui.button("Reset Shortcuts").clicked() {
+            self.temp_config.keybinds = Default::default();
+            self.recording_keybind = None;
+        }
+    }
 
-        ui.horizontal(|ui| {
-            ui.label("Show Stashes:");
-            if ui.checkbox(&mut self.temp_config.git.show_stashes, "Display stashes in history").changed() {
-                changed = true;
-            }
-        });
+    fn render_window_settings(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
+        ui.heading("Window Settings");
+        ui.add_space(10.0);
 
-        ui.horizontal(|ui| {
-            ui.label("Default Clone Path:");
-            ui.text_edit_singleline(&mut self.temp_config.git.default_clone_path);
-            
-            if ui.button("Browse").clicked() {
-                if let Some(path) = rfd::FileDialog::new()
-                    .set_directory(&self.temp_config.git.default_clone_path)
-                    .pick_folder() {
-                    self.temp_config.git.default_clone_path = path.to_string_lossy().to_string();
+        let mut changed = false;
+
+        if self.setting_visible("Width") {
+            ui.horizontal(|ui| {
+                ui.label("Width:");
+                if ui.add(egui::Slider::new(&mut self.temp_config.window.width, 400.0..=4000.0)
+                    .suffix(" px")).changed() {
                     changed = true;
                 }
-            }
-        });
+            });
+        }
 
-        ui.horizontal(|ui| {
-            ui.label("Fetch Interval (minutes):");
-            if ui.add(egui::Slider::new(&mut self.temp_config.git.fetch_interval_minutes, 1..=1440)
-                .suffix(" min")).changed() {
-                changed = true;
-            }
-        });
+        if self.setting_visible("Height") {
+            ui.horizontal(|ui| {
+                ui.label("Height:");
+                if ui.add(egui::Slider::new(&mut self.temp_config.window.height, 300.0..=3000.0)
+                    .suffix(" px")).changed() {
+                    changed = true;
+                }
+            });
+        }
 
-        if changed {
-            ctx.request_repaint();
+        ui.add_space(10.0);
+        
+        if self.setting_visible("Maximize Window") {
+            ui.horizontal(|ui| {
+                let is_maximized = ctx.input(|i| i.viewport().maximized.unwrap_or(false));
+                let button_text = if is_maximized { "Restore Window" } else { "Maximize Window" };
+                
+                if ui.button(button_text).clicked() {
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Maximized(!is_maximized));
+                    changed = true;
+                }
+                
+                ui.label("Toggle between normal and maximized window");
+            });
         }
-    }
 
-    fn render_performance_settings(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
-        ui.heading("Performance Settings");
         ui.add_space(10.0);
 
-        let mut changed = false;
 
-        ui.horizontal(|ui| {
-            ui.label("Enable Caching:");
-            if ui.checkbox(&mut self.temp_config.performance.enable_caching, "Cache repository data").changed() {
-                changed = true;
+        if let (Some(x), Some(y)) = (self.temp_config.window.position_x, self.temp_config.window.position_y) {
+            if self.setting_visible("Position") {
+                ui.horizontal(|ui| {
+                    ui.label("Position:");
+                    ui.label(format!("({:.0}, {:.0})", x, y));
+                });
             }
-        });
+        }
 
-        ui.horizontal(|ui| {
-            ui.label("Cache Size (MB):");
-            if ui.add(egui::Slider::new(&mut self.temp_config.performance.cache_size_mb, 10..=2048)
-                .logarithmic(true)
-                .suffix(" MB")).changed() {
-                changed = true;
-            }
-        });
+        ui.add_space(10.0);
 
-        ui.horizontal(|ui| {
-            ui.label("Background Operations:");
-            if ui.checkbox(&mut self.temp_config.performance.enable_background_operations, "Enable background tasks").changed() {
-                changed = true;
-            }
-        });
+        if self.setting_visible("Splash Screen") {
+            ui.horizontal(|ui| {
+                ui.label("Splash Screen:");
+                if ui.checkbox(&mut self.temp_config.ui.show_splash, "Show on startup").changed() {
+                    changed = true;
+                }
+            });
+        }
 
-        ui.horizontal(|ui| {
-            ui.label("Max Background Threads:");
-            if ui.add(egui::Slider::new(&mut self.temp_config.performance.max_background_threads, 1..=16)).changed() {
-                changed = true;
+        ui.add_enabled_ui(self.temp_config.ui.show_splash, |ui| {
+            if self.setting_visible("Splash Timeout") {
+                ui.horizontal(|ui| {
+                    ui.label("Splash Timeout:");
+                    if ui.add(egui::Slider::new(&mut self.temp_config.ui.splash_timeout_ms, 250..=5000)
+                        .suffix(" ms")).changed() {
+                        changed = true;
+                    }
+                });
             }
         });
 
-        ui.horizontal(|ui| {
-            ui.label("Render FPS Limit:");
-            if ui.add(egui::Slider::new(&mut self.temp_config.performance.target_fps, 30..=144)
-                .suffix(" FPS")).changed() {
-                changed = true;
-            }
-        });
+        if self.setting_visible("Window Title") {
+            ui.horizontal(|ui| {
+                ui.label("Window Title:");
+                if ui.checkbox(&mut self.temp_config.ui.show_repo_in_title, "Show repository and branch").changed() {
+                    changed = true;
+                }
+            });
+        }
 
         if changed {
             ctx.request_repaint();
         }
     }
 
-    fn render_logging_settings(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
-        ui.heading("Logging Settings");
+    fn render_theme_settings(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
+        ui.heading("Theme Settings");
         ui.add_space(10.0);
 
         let mut changed = false;
 
-        ui.horizontal(|ui| {
-            ui.label("Log Level:");
-            let current_level = match self.temp_config.logging.level {
-                crate::config::LogLevel::Error => 0,
-                crate::config::LogLevel::Warn => 1,
-                crate::config::LogLevel::Info => 2,
-                crate::config::LogLevel::Debug => 3,
-                crate::config::LogLevel::Trace => 4,
-            };
-            let mut selected = current_level;
+        if self.setting_visible("Theme Type") {
+            ui.horizontal(|ui| {
+                ui.label("Theme Type:");
+                let response = egui::ComboBox::from_label("")
+                    .selected_text(format!("{:?}", self.temp_config.theme.theme_type))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.temp_config.theme.theme_type, ThemeType::Light, "Light");
+                        ui.selectable_value(&mut self.temp_config.theme.theme_type, ThemeType::Dark, "Dark");
+                        ui.selectable_value(&mut self.temp_config.theme.theme_type, ThemeType::System, "System");
+                    });
+                if response.response.changed() {
+                    changed = true;
+                }
+            });
+        }
+        
+        if self.setting_visible("Font Size") {
+            ui.horizontal(|ui| {
+                ui.label("Font Size:");
+                if ui.add(egui::Slider::new(&mut self.temp_config.theme.font_size, 8.0..=32.0)
+                    .suffix(" px")).changed() {
+                    changed = true;
+                }
+            });
+        }
+        
+        if self.setting_visible("Font") {
+            ui.horizontal(|ui| {
+                ui.label("Font:");
+                let current = self.temp_config.theme.font_family.as_deref()
+                    .and_then(|path| Path::new(path).file_name())
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_else(|| "Default".to_string());
+                ui.label(current);
             
-            egui::ComboBox::from_label("")
-                .selected_text(match current_level {
-                    0 => "Error",
-                    1 => "Warn",
-                    2 => "Info",
-                    3 => "Debug",
-                    4 => "Trace",
-                    _ => "Info",
-                })
-                .show_ui(ui, |ui| {
-                    ui.selectable_value(&mut selected, 0, "Error");
-                    ui.selectable_value(&mut selected, 1, "Warn");
-                    ui.selectable_value(&mut selected, 2, "Info");
-                    ui.selectable_value(&mut selected, 3, "Debug");
-                    ui.selectable_value(&mut selected, 4, "Trace");
-                });
+                if ui.button("Choose…").clicked() {
+                    if let Some(path) = rfd::FileDialog::new()
+                        .add_filter("Fonts", &["ttf", "otf"])
+                        .pick_file() {
+                        self.temp_config.theme.font_family = Some(path.to_string_lossy().to_string());
+                        changed = true;
+                    }
+                }
             
-            if selected != current_level {
-                self.temp_config.logging.level = match selected {
-                    0 => crate::config::LogLevel::Error,
-                    1 => crate::config::LogLevel::Warn,
-                    2 => crate::config::LogLevel::Info,
-                    3 => crate::config::LogLevel::Debug,
-                    4 => crate::config::LogLevel::Trace,
-                    _ => crate::config::LogLevel::Info,
-                };
-                changed = true;
-            }
-        });
-
-        ui.horizontal(|ui| {
-            ui.label("File Logging:");
-            if ui.checkbox(&mut self.temp_config.logging.file_enabled, "Enable file logging").changed() {
+                if self.temp_config.theme.font_family.is_some() && ui.button("Reset").clicked() {
+                    self.temp_config.theme.font_family = None;
+                    changed = true;
+                }
+            });
+        }
+        
+        if self.setting_visible("Max Author Length") {
+            ui.horizontal(|ui| {
+                ui.label("Max Author Length:");
+                if ui.add(egui::Slider::new(&mut self.temp_config.ui.max_author_length, 8..=80)
+                    .suffix(" chars")).changed() {
+                    changed = true;
+                }
+            });
+        }
+        
+        if self.setting_visible("Dark Mode Override") {
+            ui.horizontal(|ui| {
+                ui.label("Dark Mode Override:");
+                if ui.checkbox(&mut self.temp_config.theme.dark_mode, "Force dark mode").changed() {
+                    changed = true;
+                }
+            });
+        }
+        
+        if self.setting_visible("Accent Color") {
+            ui.horizontal(|ui| {
+                ui.label("Accent Color:");
+                let mut color_text = self.temp_config.theme.accent_color.clone();
+                if ui.text_edit_singleline(&mut color_text).changed() {
+                    self.temp_config.theme.accent_color = color_text;
+                    changed = true;
+                }
+            
+                if let Ok(color) = parse_hex_color(&self.temp_config.theme.accent_color) {
+                    let mut color32 = color;
+                    if ui.color_edit_button_srgba(&mut color32).changed() {
+                        self.temp_config.theme.accent_color = Self::format_hex_color(color32);
+                        changed = true;
+                    }
+                }
+            });
+        }
+        
+        if self.setting_visible("Custom Theme") {
+            ui.add_space(10.0);
+            ui.separator();
+            if self.render_custom_theme_settings(ui) {
                 changed = true;
             }
-        });
+        }
 
-        ui.horizontal(|ui| {
-            ui.label("Console Logging:");
-            if ui.checkbox(&mut self.temp_config.logging.console_enabled, "Enable console logging").changed() {
-                changed = true;
-            }
-        });
+        if changed {
+            self.apply_theme_to_temp_context(ctx);
+            ctx.request_repaint();
+        }
+    }
 
+    /// Preset picker and editor for `ThemeConfig::custom_themes`; returns
+    /// whether anything changed.
+    fn render_custom_theme_settings(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = false;
+        
         ui.horizontal(|ui| {
-            ui.label("Max File Size (MB):");
-            let mut size_mb = (self.temp_config.logging.max_file_size / (1024 * 1024)) as f32;
-            if ui.add(egui::Slider::new(&mut size_mb, 1.0..=100.0)
-                .suffix(" MB")).changed() {
-                self.temp_config.logging.max_file_size = (size_mb * 1024.0 * 1024.0) as u64;
+            ui.label("Custom Theme:");
+            let selected = self.temp_config.theme.custom_theme.clone().unwrap_or_else(|| "None".to_string());
+            let names: Vec<String> = self.temp_config.theme.custom_themes.iter().map(|theme| theme.name.clone()).collect();
+            egui::ComboBox::from_id_source("custom_theme")
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    changed |= ui.selectable_value(&mut self.temp_config.theme.custom_theme, None, "None").changed();
+                    for name in names {
+                        changed |= ui.selectable_value(&mut self.temp_config.theme.custom_theme, Some(name.clone()), name).changed();
+                    }
+                });
+            
+            if ui.button("Add").clicked() {
+                let themes = &mut self.temp_config.theme.custom_themes;
+                let name = (1..)
+                    .map(|n| format!("Custom {}", n))
+                    .find(|name| !themes.iter().any(|theme| &theme.name == name))
+                    .unwrap_or_default();
+                themes.push(CustomTheme::new(name.clone()));
+                self.temp_config.theme.custom_theme = Some(name);
                 changed = true;
             }
-        });
-
-        ui.horizontal(|ui| {
-            ui.label("Max Log Files:");
-            if ui.add(egui::Slider::new(&mut self.temp_config.logging.max_files, 1..=20)).changed() {
-                changed = true;
+            
+            let has_selection = self.temp_config.theme.active_custom_theme().is_some();
+            if ui.add_enabled(has_selection, egui::Button::new("Delete")).clicked() {
+                if let Some(name) = self.temp_config.theme.custom_theme.take() {
+                    self.temp_config.theme.custom_themes.retain(|theme| theme.name != name);
+                    changed = true;
+                }
             }
         });
+        
+        let Some(name) = self.temp_config.theme.custom_theme.clone() else {
+            return changed;
+        };
+        let mut renamed = None;
+        if let Some(theme) = self.temp_config.theme.custom_themes.iter_mut().find(|theme| theme.name == name) {
+            ui.horizontal(|ui| {
+                ui.label("Name:");
+                if ui.text_edit_singleline(&mut theme.name).changed() {
+                    renamed = Some(theme.name.clone());
+                }
+            });
+            
+            egui::Grid::new("custom_theme_colors")
+                .num_columns(2)
+                .spacing([16.0, 4.0])
+                .show(ui, |ui| {
+                    for (label, value) in theme.colors_mut() {
+                        let mut enabled = value.is_some();
+                        if ui.checkbox(&mut enabled, label).changed() {
+                            *value = enabled.then(|| "#808080".to_string());
+                            changed = true;
+                        }
+                        
+                        if let Some(mut color) = value.as_deref().and_then(|hex| parse_hex_color(hex).ok()) {
+                            if ui.color_edit_button_srgba(&mut color).changed() {
+                                *value = Some(Self::format_hex_color(color));
+                                changed = true;
+                            }
+                        } else {
+                            ui.weak("base theme");
+                        }
+                        ui.end_row();
+                    }
+                });
+        }
+        
+        if let Some(new_name) = renamed {
+            self.temp_config.theme.custom_theme = Some(new_name);
+            changed = true;
+        }
+        
+        changed
+    }
 
-        ui.add_space(10.0);
-        ui.separator();
+    fn render_git_settings(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
+        ui.heading("Git Settings");
         ui.add_space(10.0);
 
-        ui.label("Log Directory:");
-        if let Some(log_dir) = &self.temp_config.logging.log_directory {
-            ui.label(format!("📁 {}", log_dir));
-        } else {
-            ui.label("📁 Default system directory");
-        }
+        let mut changed = false;
 
-        ui.horizontal(|ui| {
-            if ui.button("Open Log Directory").clicked() {
-                let log_dir = self.temp_config.logging.log_directory.clone()
-                    .or_else(|| {
-                        directories::ProjectDirs::from("dev", "twiggy", "Twiggy")
-                            .map(|dirs| dirs.data_dir().join("logs").to_string_lossy().to_string())
-                    })
-                    .unwrap_or_else(|| "logs".to_string());
-                
-                if let Err(e) = std::process::Command::new("explorer")
-                    .arg(&log_dir)
-                    .spawn() {
-                    self.add_notification(
-                        format!("Failed to open log directory: {}", e),
-                        NotificationType::Error,
-                        Some(5),
-                    );
+        if self.setting_visible("Max Commits") {
+            ui.horizontal(|ui| {
+                ui.label("Max Commits:");
+                if ui.add(egui::Slider::new(&mut self.temp_config.git.max_commits, 100..=50000)
+                    .logarithmic(true)).changed() {
+                    changed = true;
                 }
-            }
+            });
+        }
 
-            if ui.button("Clear Logs").clicked() {
-                let log_dir = self.temp_config.logging.log_directory.clone()
-                    .or_else(|| {
-                        directories::ProjectDirs::from("dev", "twiggy", "Twiggy")
-                            .map(|dirs| dirs.data_dir().join("logs").to_string_lossy().to_string())
-                    })
-                    .unwrap_or_else(|| "logs".to_string());
-                
-                let log_path = std::path::PathBuf::from(&log_dir);
-                if log_path.exists() {
-                    match std::fs::read_dir(&log_path) {
-                        Ok(entries) => {
-                            let mut cleared_count = 0;
-                            for entry in entries {
-                                if let Ok(entry) = entry {
-                                    if entry.path().extension().map_or(false, |ext| ext == "log") {
-                                        if std::fs::remove_file(entry.path()).is_ok() {
-                                            cleared_count += 1;
-                                        }
-                                    }
-                                }
-                            }
-                            self.add_notification(
-                                format!("Cleared {} log files", cleared_count),
-                                NotificationType::Success,
-                                Some(3),
-                            );
-                        }
-                        Err(e) => {
-                            self.add_notification(
-                                format!("Failed to clear logs: {}", e),
-                                NotificationType::Error,
-                                Some(5),
-                            );
-                        }
-                    }
+        if self.setting_visible("Recent Repositories") {
+            ui.horizontal(|ui| {
+                ui.label("Recent Repositories:");
+                let mut max_count = self.temp_config.recent_repositories.max_count;
+                if ui.add(egui::Slider::new(&mut max_count, 1..=50)).changed() {
+                    self.temp_config.recent_repositories.set_max_count(max_count);
+                    changed = true;
                 }
-            }
+            });
+        }
 
-            if ui.button("View Logs").clicked() {
-                let log_dir = self.temp_config.logging.log_directory.clone()
-                    .or_else(|| {
-                        directories::ProjectDirs::from("dev", "twiggy", "Twiggy")
-                            .map(|dirs| dirs.data_dir().join("logs").to_string_lossy().to_string())
-                    })
-                    .unwrap_or_else(|| "logs".to_string());
-                
-                let log_path = std::path::PathBuf::from(&log_dir);
-                if let Ok(entries) = std::fs::read_dir(&log_path) {
-                    for entry in entries {
-                        if let Ok(entry) = entry {
-                            if entry.path().extension().map_or(false, |ext| ext == "log") {
-                                if let Err(e) = self.log_viewer.set_log_file(entry.path()) {
-                                    self.add_notification(
-                                        format!("Failed to load log file: {}", e),
-                                        NotificationType::Error,
-                                        Some(5),
-                                    );
-                                } else {
-                                    self.show_log_viewer = true;
-                                }
-                                break;
-                            }
-                        }
-                    }
-                } else {
-                    self.add_notification(
-                        "No log files found".to_string(),
-                        NotificationType::Warning,
-                        Some(3),
-                    );
+        if self.setting_visible("Auto Fetch") {
+            ui.horizontal(|ui| {
+                ui.label("Auto Fetch:");
+                if ui.checkbox(&mut self.temp_config.git.auto_fetch, "Automatically fetch from remote").changed() {
+                    changed = true;
                 }
-            }
-        });
+            });
+        }
+
+        if self.setting_visible("Refresh on Focus") {
+            ui.horizontal(|ui| {
+                ui.label("Refresh on Focus:");
+                if ui.checkbox(&mut self.temp_config.git.refresh_on_focus, "Reload the repository when Twiggy regains focus").changed() {
+                    changed = true;
+                }
+            });
+        }
+
+        if self.setting_visible("Reopen on Start") {
+            ui.horizontal(|ui| {
+                ui.label("Reopen on Start:");
+                if ui.checkbox(&mut self.temp_config.git.reopen_last_on_start, "Reopen the last repository when Twiggy starts").changed() {
+                    changed = true;
+                }
+            });
+        }
+
+        if self.setting_visible("Working Directory") {
+            ui.horizontal(|ui| {
+                ui.label("Working Directory:");
+                if ui.checkbox(&mut self.temp_config.git.auto_open_cwd_repo, "Offer to open the repository Twiggy was started in").changed() {
+                    changed = true;
+                }
+            });
+        }
+
+        if self.setting_visible("Confirm Large Fetch") {
+            ui.horizontal(|ui| {
+                ui.label("Confirm Large Fetch:");
+                if ui.checkbox(&mut self.temp_config.git.confirm_large_fetch, "Ask before fetching many refs").changed() {
+                    changed = true;
+                }
+            });
+        }
+
+        ui.add_enabled_ui(self.temp_config.git.confirm_large_fetch, |ui| {
+            if self.setting_visible("Ref Threshold") {
+                ui.horizontal(|ui| {
+                    ui.label("Ref Threshold:");
+                    if ui.add(egui::Slider::new(&mut self.temp_config.git.large_fetch_threshold, 10..=1000)
+                        .logarithmic(true)
+                        .suffix(" refs")).changed() {
+                        changed = true;
+                    }
+                });
+            }
+        });
+
+        if self.setting_visible("Diff Algorithm") {
+            ui.horizontal(|ui| {
+                ui.label("Diff Algorithm:");
+                egui::ComboBox::from_id_source("diff_algorithm")
+                    .selected_text(self.temp_config.git.diff_algorithm.to_string())
+                    .show_ui(ui, |ui| {
+                        for algorithm in DiffAlgorithm::ALL {
+                            if ui.selectable_value(&mut self.temp_config.git.diff_algorithm, algorithm, algorithm.to_string()).changed() {
+                                changed = true;
+                            }
+                        }
+                    });
+            });
+        }
+
+        if self.setting_visible("Expanded Commits") {
+            ui.horizontal(|ui| {
+                ui.label("Expanded Commits:");
+                if ui.checkbox(&mut self.temp_config.ui.allow_multiple_expanded, "Allow more than one expanded message").changed() {
+                    changed = true;
+                }
+            });
+        }
+
+        if self.setting_visible("Show Stashes") {
+            ui.horizontal(|ui| {
+                ui.label("Show Stashes:");
+                if ui.checkbox(&mut self.temp_config.git.show_stashes, "Display stashes in history").changed() {
+                    changed = true;
+                }
+            });
+        }
+
+        if self.setting_visible("Default Clone Path") {
+            ui.horizontal(|ui| {
+                ui.label("Default Clone Path:");
+                ui.text_edit_singleline(&mut self.temp_config.git.default_clone_path);
+            
+                if ui.button("Browse").clicked() {
+                    if let Some(path) = rfd::FileDialog::new()
+                        .set_directory(&self.temp_config.git.default_clone_path)
+                        .pick_folder() {
+                        self.temp_config.git.default_clone_path = path.to_string_lossy().to_string();
+                        changed = true;
+                    }
+                }
+            });
+        }
+
+        if self.setting_visible("Fetch Interval (minutes)") {
+            ui.horizontal(|ui| {
+                ui.label("Fetch Interval (minutes):");
+                if ui.add(egui::Slider::new(&mut self.temp_config.git.fetch_interval_minutes, 1..=1440)
+                    .suffix(" min")).changed() {
+                    changed = true;
+                }
+            });
+        }
+
+        if changed {
+            ctx.request_repaint();
+        }
+    }
+
+    fn render_performance_settings(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
+        ui.heading("Performance Settings");
+        ui.add_space(10.0);
+
+        let mut changed = false;
+
+        if self.setting_visible("Developer Mode") {
+            ui.horizontal(|ui| {
+                ui.label("Developer Mode:");
+                if ui.checkbox(&mut self.temp_config.performance.developer_mode, "Show developer tools such as the benchmark").changed() {
+                    changed = true;
+                }
+            });
+        }
+
+        if self.setting_visible("Enable Caching") {
+            ui.horizontal(|ui| {
+                ui.label("Enable Caching:");
+                if ui.checkbox(&mut self.temp_config.performance.enable_caching, "Cache repository data").changed() {
+                    changed = true;
+                }
+            });
+        }
+
+        if self.setting_visible("Cache Size (MB)") {
+            ui.horizontal(|ui| {
+                ui.label("Cache Size (MB):");
+                if ui.add(egui::Slider::new(&mut self.temp_config.performance.cache_size_mb, 10..=2048)
+                    .logarithmic(true)
+                    .suffix(" MB")).changed() {
+                    changed = true;
+                }
+            });
+        }
+
+        if self.setting_visible("Background Operations") {
+            ui.horizontal(|ui| {
+                ui.label("Background Operations:");
+                if ui.checkbox(&mut self.temp_config.performance.enable_background_operations, "Enable background tasks").changed() {
+                    changed = true;
+                }
+            });
+        }
+
+        if self.setting_visible("Max Background Threads") {
+            ui.horizontal(|ui| {
+                ui.label("Max Background Threads:");
+                if ui.add(egui::Slider::new(&mut self.temp_config.performance.max_background_threads, 1..=16)).changed() {
+                    changed = true;
+                }
+            });
+        }
+
+        if self.setting_visible("Render FPS Limit") {
+            ui.horizontal(|ui| {
+                ui.label("Render FPS Limit:");
+                if ui.add(egui::Slider::new(&mut self.temp_config.performance.target_fps, 30..=144)
+                    .suffix(" FPS")).changed() {
+                    changed = true;
+                }
+            });
+        }
+
+        if changed {
+            ctx.request_repaint();
+        }
+    }
+
+    fn render_logging_settings(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
+        ui.heading("Logging Settings");
+        ui.add_space(10.0);
+
+        let mut changed = false;
+
+        if self.setting_visible("Log Level") {
+            ui.horizontal(|ui| {
+                ui.label("Log Level:");
+                let current_level = match self.temp_config.logging.level {
+                    crate::config::LogLevel::Error => 0,
+                    crate::config::LogLevel::Warn => 1,
+                    crate::config::LogLevel::Info => 2,
+                    crate::config::LogLevel::Debug => 3,
+                    crate::config::LogLevel::Trace => 4,
+                };
+                let mut selected = current_level;
+            
+                egui::ComboBox::from_label("")
+                    .selected_text(match current_level {
+                        0 => "Error",
+                        1 => "Warn",
+                        2 => "Info",
+                        3 => "Debug",
+                        4 => "Trace",
+                        _ => "Info",
+                    })
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut selected, 0, "Error");
+                        ui.selectable_value(&mut selected, 1, "Warn");
+                        ui.selectable_value(&mut selected, 2, "Info");
+                        ui.selectable_value(&mut selected, 3, "Debug");
+                        ui.selectable_value(&mut selected, 4, "Trace");
+                    });
+            
+                if selected != current_level {
+                    self.temp_config.logging.level = match selected {
+                        0 => crate::config::LogLevel::Error,
+                        1 => crate::config::LogLevel::Warn,
+                        2 => crate::config::LogLevel::Info,
+                        3 => crate::config::LogLevel::Debug,
+                        4 => crate::config::LogLevel::Trace,
+                        _ => crate::config::LogLevel::Info,
+                    };
+                    changed = true;
+                }
+            });
+        }
+
+        if self.setting_visible("File Logging") {
+            ui.horizontal(|ui| {
+                ui.label("File Logging:");
+                if ui.checkbox(&mut self.temp_config.logging.file_enabled, "Enable file logging").changed() {
+                    changed = true;
+                }
+            });
+        }
+
+        if self.setting_visible("Console Logging") {
+            ui.horizontal(|ui| {
+                ui.label("Console Logging:");
+                if ui.checkbox(&mut self.temp_config.logging.console_enabled, "Enable console logging").changed() {
+                    changed = true;
+                }
+            });
+        }
+
+        if self.setting_visible("Max File Size (MB)") {
+            ui.horizontal(|ui| {
+                ui.label("Max File Size (MB):");
+                let mut size_mb = (self.temp_config.logging.max_file_size / (1024 * 1024)) as f32;
+                if ui.add(egui::Slider::new(&mut size_mb, 1.0..=100.0)
+                    .suffix(" MB")).changed() {
+                    self.temp_config.logging.max_file_size = (size_mb * 1024.0 * 1024.0) as u64;
+                    changed = true;
+                }
+            });
+        }
+
+        if self.setting_visible("Max Log Files") {
+            ui.horizontal(|ui| {
+                ui.label("Max Log Files:");
+                if ui.add(egui::Slider::new(&mut self.temp_config.logging.max_files, 1..=20)).changed() {
+                    changed = true;
+                }
+            });
+        }
+
+        if self.setting_visible("Log Directory") {
+            ui.add_space(10.0);
+            ui.separator();
+            ui.add_space(10.0);
+
+            ui.label("Log Directory:");
+            if let Some(log_dir) = &self.temp_config.logging.log_directory {
+                ui.label(format!("📁 {}", log_dir));
+            } else {
+                ui.label("📁 Default system directory");
+            }
+
+            ui.horizontal(|ui| {
+                if ui.button("Open Log Directory").clicked() {
+                    let log_dir = self.temp_config.logging.log_directory.clone()
+                        .or_else(|| {
+                            directories::ProjectDirs::from("dev", "twiggy", "Twiggy")
+                                .map(|dirs| dirs.data_dir().join("logs").to_string_lossy().to_string())
+                        })
+                        .unwrap_or_else(|| "logs".to_string());
+                
+                    let log_path = Path::new(&log_dir);
+                    let result = std::fs::create_dir_all(log_path)
+                        .map_err(|e| TwiggyError::Io {
+                            operation: format!("create {}", log_dir),
+                            source: e,
+                        })
+                        .and_then(|_| open_path_in_file_manager(log_path));
+                
+                    if let Err(e) = result {
+                        self.add_notification(
+                            format!("Failed to open log directory {}: {}", log_dir, e),
+                            NotificationType::Error,
+                            Some(5),
+                        );
+                    }
+                }
+
+                if ui.button("Clear Logs").clicked() {
+                    let log_dir = self.temp_config.logging.log_directory.clone()
+                        .or_else(|| {
+                            directories::ProjectDirs::from("dev", "twiggy", "Twiggy")
+                                .map(|dirs| dirs.data_dir().join("logs").to_string_lossy().to_string())
+                        })
+                        .unwrap_or_else(|| "logs".to_string());
+                
+                    let log_path = std::path::PathBuf::from(&log_dir);
+                    if log_path.exists() {
+                        match std::fs::read_dir(&log_path) {
+                            Ok(entries) => {
+                                let mut cleared_count = 0;
+                                for entry in entries.flatten() {
+                                    if entry.path().extension().is_some_and(|ext| ext == "log")
+                                        && std::fs::remove_file(entry.path()).is_ok()
+                                    {
+                                        cleared_count += 1;
+                                    }
+                                }
+                                self.add_notification(
+                                    format!("Cleared {} log files", cleared_count),
+                                    NotificationType::Success,
+                                    Some(3),
+                                );
+                            }
+                            Err(e) => {
+                                self.add_notification(
+                                    format!("Failed to clear logs: {}", e),
+                                    NotificationType::Error,
+                                    Some(5),
+                                );
+                            }
+                        }
+                    }
+                }
+
+                if ui.button("View Logs").clicked() {
+                    let log_path = crate::logging::log_directory(&self.temp_config.logging);
+                    if let Ok(entries) = std::fs::read_dir(&log_path) {
+                        for entry in entries.flatten() {
+                            if entry.path().extension().is_some_and(|ext| ext == "log") {
+                                if let Err(e) = self.log_viewer.set_log_file(entry.path()) {
+                                    self.add_notification(
+                                        format!("Failed to load log file: {}", e),
+                                        NotificationType::Error,
+                                        Some(5),
+                                    );
+                                } else {
+                                    self.show_log_viewer = true;
+                                }
+                                break;
+                            }
+                        }
+                    } else {
+                        self.add_notification(
+                            "No log files found".to_string(),
+                            NotificationType::Warning,
+                            Some(3),
+                        );
+                    }
+                }
+            });
+        }
 
         if changed {
             ctx.request_repaint();
@@ -973,15 +1993,17 @@ impl TwiggyApp {
             }
         };
 
-        if let Ok(accent_color) = self.parse_hex_color(&self.config.theme.accent_color) {
+        if let Ok(accent_color) = parse_hex_color(&self.config.theme.accent_color) {
             visuals.selection.bg_fill = accent_color;
             visuals.hyperlink_color = accent_color;
         }
+        
+        if let Some(custom_theme) = self.config.theme.active_custom_theme() {
+            Self::apply_custom_theme(&mut visuals, custom_theme);
+        }
 
         ctx.set_visuals(visuals);
 
-        let fonts = egui::FontDefinitions::default();
-        
         let mut style = (*ctx.style()).clone();
         style.text_styles.insert(
             egui::TextStyle::Body,
@@ -996,8 +2018,44 @@ impl TwiggyApp {
             egui::FontId::new(self.config.theme.font_size * 1.2, egui::FontFamily::Proportional),
         );
         
-        ctx.set_fonts(fonts);
         ctx.set_style(style);
+        
+        // Zoom is driven by the persisted scale rather than egui's own
+        // keyboard handling, which would be lost on restart.
+        ctx.options_mut(|options| options.zoom_with_keyboard = false);
+        ctx.set_zoom_factor(self.config.theme.ui_scale);
+    }
+
+    /// Installs the configured font once it changes. A font that fails to
+    /// load falls back to the built-in one; the failed path is remembered so
+    /// it isn't retried every frame.
+    fn apply_fonts(&mut self, ctx: &egui::Context) {
+        let wanted = self.config.theme.font_family.clone();
+        if self.applied_font_family.as_ref() == Some(&wanted) {
+            return;
+        }
+        
+        let fonts = match &wanted {
+            Some(path) => match font_definitions_with(Path::new(path)) {
+                Ok(fonts) => {
+                    tracing::info!("Using custom font: {}", path);
+                    fonts
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to load font {}: {}", path, e);
+                    self.add_notification(
+                        format!("Couldn't load font, using the default: {}", e),
+                        NotificationType::Warning,
+                        Some(5),
+                    );
+                    egui::FontDefinitions::default()
+                }
+            },
+            None => egui::FontDefinitions::default(),
+        };
+        
+        ctx.set_fonts(fonts);
+        self.applied_font_family = Some(wanted);
     }
 
     fn apply_theme_to_temp_context(&self, ctx: &egui::Context) {
@@ -1013,15 +2071,17 @@ impl TwiggyApp {
             }
         };
 
-        if let Ok(accent_color) = self.parse_hex_color(&self.temp_config.theme.accent_color) {
+        if let Ok(accent_color) = parse_hex_color(&self.temp_config.theme.accent_color) {
             visuals.selection.bg_fill = accent_color;
             visuals.hyperlink_color = accent_color;
         }
+        
+        if let Some(custom_theme) = self.temp_config.theme.active_custom_theme() {
+            Self::apply_custom_theme(&mut visuals, custom_theme);
+        }
 
         ctx.set_visuals(visuals);
 
-        let fonts = egui::FontDefinitions::default();
-        
         let mut style = (*ctx.style()).clone();
         style.text_styles.insert(
             egui::TextStyle::Body,
@@ -1036,7 +2096,6 @@ impl TwiggyApp {
             egui::FontId::new(self.temp_config.theme.font_size * 1.2, egui::FontFamily::Proportional),
         );
         
-        ctx.set_fonts(fonts);
         ctx.set_style(style);
     }
 
@@ -1087,8 +2146,28 @@ impl TwiggyApp {
             || self.config.window.position_y != self.temp_config.window.position_y;
 
         let theme_changed = self.config.theme != self.temp_config.theme;
+        let stashes_changed = self.config.git.show_stashes != self.temp_config.git.show_stashes;
+        let diff_algorithm_changed = self.config.git.diff_algorithm != self.temp_config.git.diff_algorithm;
 
         self.config = self.temp_config.clone();
+        self.shortcuts = self.config.keybinds.shortcuts();
+        self.refresh_repo_settings();
+        self.sync_syntax_highlighting();
+        
+        if diff_algorithm_changed {
+            self.commit_list.clear_commit_stats();
+        }
+        
+        let diff_algorithm = self.settings().git.diff_algorithm;
+        if let Some(ref mut repo) = self.current_repository {
+            repo.set_diff_algorithm(diff_algorithm);
+            repo.set_cache_limit_bytes(self.config.performance.cache_size_mb * 1024 * 1024);
+            repo.set_parse_threads(self.config.performance.commit_parse_threads());
+        }
+        
+        if stashes_changed {
+            self.refresh_ref_labels();
+        }
         
         if window_changed {
             tracing::info!("Window settings changed, applying new window configuration");
@@ -1330,121 +2409,84 @@ impl TwiggyApp {
         });
     }
 
-    fn detect_window_changes(&mut self, ctx: &egui::Context) {
-        log_performance("detect_window_changes", || {
+    /// Picks up resizes, moves and maximize toggles from the viewport, keeps
+    /// both configs in step, and schedules a debounced save.
+    fn sync_window_state(&mut self, ctx: &egui::Context) {
+        log_performance("sync_window_state", || {
             let viewport_info = ctx.input(|i| i.viewport().clone());
-            let current_size = viewport_info.inner_rect.map(|r| r.size()).unwrap_or_default();
-            let current_maximized = viewport_info.maximized.unwrap_or(false);
-            let current_pos = viewport_info.outer_rect.map(|r| r.min).unwrap_or_default();
-            
+            let last_state = self.last_window_state.clone()
+                .unwrap_or_else(|| WindowState::from_config(&self.config));
             let mut config_changed = false;
             
-            if let Some(ref last_state) = self.last_window_state {
-                let size_threshold = 5.0;
-                let pos_threshold = 10.0;
+            if let Some(inner_rect) = viewport_info.inner_rect {
+                let size = inner_rect.size();
                 
-                if (current_size.x - last_state.width).abs() > size_threshold ||
-                   (current_size.y - last_state.height).abs() > size_threshold {
-                    if self.is_valid_window_size(current_size) {
-                        self.config.window.width = current_size.x;
-                        self.config.window.height = current_size.y;
+                if (size.x - last_state.width).abs() > WINDOW_SIZE_THRESHOLD ||
+                   (size.y - last_state.height).abs() > WINDOW_SIZE_THRESHOLD {
+                    if self.is_valid_window_size(size) {
+                        self.config.window.width = size.x;
+                        self.config.window.height = size.y;
+                        self.temp_config.window.width = size.x;
+                        self.temp_config.window.height = size.y;
                         config_changed = true;
-                        tracing::debug!("Window size changed to {}x{}", current_size.x, current_size.y);
+                        tracing::debug!("Window size changed to {}x{}", size.x, size.y);
                     } else {
                         tracing::warn!("Detected invalid window size change, ignoring");
                     }
                 }
-                
-                if current_maximized != last_state.maximized {
-                    self.config.window.maximized = current_maximized;
+            }
+            
+            if let Some(maximized) = viewport_info.maximized {
+                if maximized != last_state.maximized {
+                    self.config.window.maximized = maximized;
+                    self.temp_config.window.maximized = maximized;
                     config_changed = true;
-                    tracing::debug!("Window maximized state changed to {}", current_maximized);
+                    tracing::debug!("Window maximized state changed to {}", maximized);
                 }
+            }
+            
+            if let Some(outer_rect) = viewport_info.outer_rect.filter(|_| self.config.window.remember_position) {
+                let pos = outer_rect.min;
                 
-                if self.config.window.remember_position {
-                    if (current_pos.x - last_state.position_x.unwrap_or(0.0)).abs() > pos_threshold ||
-                       (current_pos.y - last_state.position_y.unwrap_or(0.0)).abs() > pos_threshold {
-                        if self.is_valid_window_position(current_pos) {
-                            self.config.window.position_x = Some(current_pos.x);
-                            self.config.window.position_y = Some(current_pos.y);
-                            config_changed = true;
-                            tracing::debug!("Window position changed to ({}, {})", current_pos.x, current_pos.y);
-                        } else {
-                            tracing::warn!("Detected invalid window position change, ignoring");
-                        }
+                if (pos.x - last_state.position_x.unwrap_or(0.0)).abs() > WINDOW_POSITION_THRESHOLD ||
+                   (pos.y - last_state.position_y.unwrap_or(0.0)).abs() > WINDOW_POSITION_THRESHOLD {
+                    if self.is_valid_window_position(pos) {
+                        self.config.window.position_x = Some(pos.x);
+                        self.config.window.position_y = Some(pos.y);
+                        self.temp_config.window.position_x = Some(pos.x);
+                        self.temp_config.window.position_y = Some(pos.y);
+                        config_changed = true;
+                        tracing::debug!("Window position changed to ({}, {})", pos.x, pos.y);
+                    } else {
+                        tracing::warn!("Detected invalid window position change, ignoring");
                     }
                 }
             }
             
             if config_changed {
-                self.last_window_state = Some(WindowState {
-                    width: self.config.window.width,
-                    height: self.config.window.height,
-                    maximized: self.config.window.maximized,
-                    position_x: self.config.window.position_x,
-                    position_y: self.config.window.position_y,
-                });
-                
-                if let Err(e) = self.config.save() {
-                    tracing::error!("Failed to save window state changes: {}", e);
-                    self.handle_window_operation_error("save_config", &e.to_string());
-                } else {
-                    tracing::debug!("Window state changes saved successfully");
-                }
+                self.last_window_state = Some(WindowState::from_config(&self.config));
+                self.window_save.mark(Instant::now());
             }
         });
     }
 
-    fn handle_viewport_events(&mut self, ctx: &egui::Context) {
-        let viewport_info = ctx.input(|i| i.viewport().clone());
-        let mut config_changed = false;
-        
-        if let Some(inner_size) = viewport_info.inner_rect {
-            let new_width = inner_size.width();
-            let new_height = inner_size.height();
-            
-            if (self.config.window.width - new_width).abs() > 1.0 || 
-               (self.config.window.height - new_height).abs() > 1.0 {
-                self.config.window.width = new_width;
-                self.config.window.height = new_height;
-                self.temp_config.window.width = new_width;
-                self.temp_config.window.height = new_height;
-                config_changed = true;
-            }
-        }
-        
-        if let Some(outer_pos) = viewport_info.outer_rect {
-            if self.config.window.remember_position {
-                let new_x = outer_pos.min.x;
-                let new_y = outer_pos.min.y;
-                
-                if (self.config.window.position_x.unwrap_or(0.0) - new_x).abs() > 1.0 ||
-                   (self.config.window.position_y.unwrap_or(0.0) - new_y).abs() > 1.0 {
-                    self.config.window.position_x = Some(new_x);
-                    self.config.window.position_y = Some(new_y);
-                    self.temp_config.window.position_x = Some(new_x);
-                    self.temp_config.window.position_y = Some(new_y);
-                    config_changed = true;
-                }
-            }
-        }
-        
-        if let Some(maximized) = viewport_info.maximized {
-            if self.config.window.maximized != maximized {
-                self.config.window.maximized = maximized;
-                self.temp_config.window.maximized = maximized;
-                config_changed = true;
-            }
+    /// Writes the window state once it has settled, and keeps frames coming
+    /// until then so the save isn't stranded waiting for input.
+    fn save_window_state_if_due(&mut self, ctx: &egui::Context) {
+        let now = Instant::now();
+        if self.window_save.ready(now) {
+            self.save_window_state();
+        } else if let Some(remaining) = self.window_save.remaining(now) {
+            ctx.request_repaint_after(remaining);
         }
-        
-        if config_changed {
-            self.last_window_state = Some(WindowState::from_config(&self.config));
-            
-            if let Err(e) = self.config.save() {
-                tracing::warn!("Failed to save window state changes: {}", e);
-            } else {
-                tracing::debug!("Window state changes saved");
-            }
+    }
+
+    fn save_window_state(&mut self) {
+        if let Err(e) = self.config.save() {
+            tracing::error!("Failed to save window state changes: {}", e);
+            self.handle_window_operation_error("save_config", &e.to_string());
+        } else {
+            tracing::debug!("Window state changes saved successfully");
         }
     }
 
@@ -1474,19 +2516,34 @@ impl TwiggyApp {
         false
     }
 
-    fn parse_hex_color(&self, hex: &str) -> std::result::Result<egui::Color32, String> {
-        let hex = hex.trim_start_matches('#');
-        if hex.len() != 6 {
-            return Err("Invalid hex color length".to_string());
-        }
+    fn apply_custom_theme(visuals: &mut egui::Visuals, theme: &CustomTheme) {
+        let color = |value: &Option<String>| value.as_deref().and_then(|hex| parse_hex_color(hex).ok());
         
-        let r = u8::from_str_radix(&hex[0..2], 16).map_err(|_| "Invalid red component".to_string())?;
-        let g = u8::from_str_radix(&hex[2..4], 16).map_err(|_| "Invalid green component".to_string())?;
-        let b = u8::from_str_radix(&hex[4..6], 16).map_err(|_| "Invalid blue component".to_string())?;
-        
-        Ok(egui::Color32::from_rgb(r, g, b))
+        if let Some(background) = color(&theme.background) {
+            visuals.panel_fill = background;
+            visuals.window_fill = background;
+            visuals.extreme_bg_color = background;
+            visuals.faint_bg_color = background;
+        }
+        if let Some(text) = color(&theme.text) {
+            visuals.override_text_color = Some(text);
+        }
+        if let Some(selection) = color(&theme.selection) {
+            visuals.selection.bg_fill = selection;
+            visuals.hyperlink_color = selection;
+        }
+        if let Some(warning) = color(&theme.warning) {
+            visuals.warn_fg_color = warning;
+        }
+        if let Some(error) = color(&theme.error) {
+            visuals.error_fg_color = error;
+        }
     }
-
+    
+    fn format_hex_color(color: egui::Color32) -> String {
+        format!("#{:02X}{:02X}{:02X}", color.r(), color.g(), color.b())
+    }
+    
     fn render_menu_bar(&mut self, ctx: &egui::Context) {
         if !self.config.ui.menu_preferences.show_menu_bar {
             return;
@@ -1495,25 +2552,41 @@ impl TwiggyApp {
         egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
             egui::menu::bar(ui, |ui| {
                 ui.menu_button("File", |ui| {
-                    if ui.add(egui::Button::new("Open Repository").shortcut_text("Ctrl+O")).clicked() {
+                    if ui.add(egui::Button::new("Open Repository").shortcut_text(self.config.keybinds.get(KeybindAction::OpenRepository))).clicked() {
                         self.open_repository();
                         ui.close_menu();
                     }
                     
                     let has_repo = self.current_repository.is_some();
-                    if ui.add_enabled(has_repo, egui::Button::new("Close Repository")).clicked() {
-                        self.close_repository();
+                    let has_history = self.has_history();
+                    if ui.add_enabled(has_repo, egui::Button::new("Close Repository").shortcut_text(self.config.keybinds.get(KeybindAction::CloseRepository))).clicked() {
+                        self.request_close_repository();
+                        ui.close_menu();
+                    }
+                    
+                    if ui.add_enabled(has_history, egui::Button::new("Export Graph as DOT...")).clicked() {
+                        self.export_graph_as_dot();
                         ui.close_menu();
                     }
                     
+                    ui.add_enabled_ui(has_history, |ui| {
+                        ui.menu_button("Export Commits", |ui| {
+                            for format in CommitExportFormat::ALL {
+                                if ui.button(format!("As {}...", format.label())).clicked() {
+                                    self.export_commits(format);
+                                    ui.close_menu();
+                                }
+                            }
+                        });
+                    });
+                    
                     ui.separator();
                     
                     if !self.config.recent_repositories.repositories.is_empty() {
                         ui.menu_button("Recent Repositories", |ui| {
                             let recent_repos = self.config.recent_repositories.repositories.clone();
-                            for (i, recent_repo) in recent_repos.iter().enumerate() {
-                                if i >= self.config.recent_repositories.max_count { break; }
-                                
+                            let mut toggled_pin = None;
+                            for recent_repo in &recent_repos {
                                 let display_text = if recent_repo.name.len() > 30 {
                                     format!("{}...", &recent_repo.name[..27])
                                 } else {
@@ -1527,13 +2600,27 @@ impl TwiggyApp {
                                     recent_repo.last_opened.format("%Y-%m-%d %H:%M")
                                 );
                                 
-                                if ui.button(button_text)
-                                    .on_hover_text(tooltip_text)
-                                    .clicked() 
-                                {
-                                    let path = recent_repo.path.clone();
-                                    self.open_recent_repository(path);
-                                    ui.close_menu();
+                                ui.horizontal(|ui| {
+                                    let (pin_icon, pin_hint) = if recent_repo.pinned { ("📌", "Unpin") } else { ("📍", "Pin to top") };
+                                    if ui.small_button(pin_icon).on_hover_text(pin_hint).clicked() {
+                                        toggled_pin = Some((recent_repo.path.clone(), !recent_repo.pinned));
+                                    }
+                                    
+                                    if ui.button(button_text)
+                                        .on_hover_text(tooltip_text)
+                                        .clicked() 
+                                    {
+                                        let path = recent_repo.path.clone();
+                                        self.open_recent_repository(path);
+                                        ui.close_menu();
+                                    }
+                                });
+                            }
+                            
+                            if let Some((path, pinned)) = toggled_pin {
+                                self.config.recent_repositories.set_pinned(&path, pinned);
+                                if let Err(e) = self.config.save() {
+                                    tracing::warn!("Failed to save config: {}", e);
                                 }
                             }
                             
@@ -1550,7 +2637,7 @@ impl TwiggyApp {
                         ui.separator();
                     }
                     
-                    if ui.add(egui::Button::new("Settings").shortcut_text("Ctrl+S")).clicked() {
+                    if ui.add(egui::Button::new("Settings").shortcut_text(self.config.keybinds.get(KeybindAction::Settings))).clicked() {
                         self.temp_config = self.config.clone();
                         self.show_settings = true;
                         ui.close_menu();
@@ -1558,7 +2645,7 @@ impl TwiggyApp {
                     
                     ui.separator();
                     
-                    if ui.add(egui::Button::new("Exit").shortcut_text("Ctrl+Q")).clicked() {
+                    if ui.add(egui::Button::new("Exit").shortcut_text(self.config.keybinds.get(KeybindAction::Quit))).clicked() {
                         ctx.send_viewport_cmd(egui::ViewportCommand::Close);
                         ui.close_menu();
                     }
@@ -1578,6 +2665,72 @@ impl TwiggyApp {
                     }
                 });
                 
+                ui.menu_button("Repository", |ui| {
+                    let has_repo = self.current_repository.is_some();
+                    let has_history = self.has_history();
+                    if ui.add_enabled(has_repo, egui::Button::new("Refresh").shortcut_text(self.config.keybinds.get(KeybindAction::Refresh))).clicked() {
+                        self.refresh_repository();
+                        ui.close_menu();
+                    }
+                    
+                    if ui.add_enabled(has_history, egui::Button::new("Go to Commit…").shortcut_text(self.config.keybinds.get(KeybindAction::GoToCommit))).clicked() {
+                        self.open_go_to_commit();
+                        ui.close_menu();
+                    }
+                    
+                    ui.separator();
+                    
+                    let remotes = self.current_repository
+                        .as_ref()
+                        .and_then(|repo| repo.remote_names().ok())
+                        .unwrap_or_default();
+                    
+                    ui.add_enabled_ui(!remotes.is_empty() && !self.is_fetching(), |ui| {
+                        ui.menu_button("Fetch", |ui| {
+                            for remote in &remotes {
+                                if ui.button(remote).clicked() {
+                                    self.request_fetch(remote);
+                                    ui.close_menu();
+                                }
+                            }
+                        });
+                    });
+                    
+                    if ui.add_enabled(has_repo, egui::Button::new("Repository Info...")).clicked() {
+                        self.show_repository_info = true;
+                        ui.close_menu();
+                    }
+                    
+                    if ui.add_enabled(has_history, egui::Button::new("Statistics...")).clicked() {
+                        self.show_statistics = true;
+                        ui.close_menu();
+                    }
+                    
+                    if ui.add_enabled(has_history, egui::Button::new("Search Diffs...")).clicked() {
+                        self.pickaxe_search.open = true;
+                        ui.close_menu();
+                    }
+                    
+                    ui.separator();
+                    
+                    if ui.add_enabled(has_repo, egui::Button::new("Git Config...")).clicked() {
+                        self.open_repo_config_editor();
+                        ui.close_menu();
+                    }
+                    
+                    ui.separator();
+                    
+                    if ui.add_enabled(has_repo, egui::Button::new("Open in File Manager")).clicked() {
+                        self.open_repository_in_file_manager();
+                        ui.close_menu();
+                    }
+                    
+                    if ui.add_enabled(has_repo, egui::Button::new("Open in Terminal")).clicked() {
+                        self.open_repository_in_terminal();
+                        ui.close_menu();
+                    }
+                });
+                
                 ui.menu_button("View", |ui| {
                     let theme_text = match self.config.theme.theme_type {
                         ThemeType::Light => "Switch to Dark Theme",
@@ -1598,6 +2751,71 @@ impl TwiggyApp {
                         ui.close_menu();
                     }
                     
+                    if ui.button("Notifications").clicked() {
+                        self.show_notification_history = true;
+                        ui.close_menu();
+                    }
+                    
+                    ui.separator();
+                    
+                    if ui.checkbox(&mut self.config.ui.show_file_tree, "Show File Tree").changed() {
+                        self.refresh_repo_settings();
+                        if let Err(e) = self.config.save() {
+                            self.handle_error(e);
+                        }
+                        ui.close_menu();
+                    }
+                    
+                    if ui.checkbox(&mut self.config.ui.show_diff_viewer, "Show Diff Viewer").changed() {
+                        self.refresh_repo_settings();
+                        if let Err(e) = self.config.save() {
+                            self.handle_error(e);
+                        }
+                        ui.close_menu();
+                    }
+                    
+                    if ui.checkbox(&mut self.config.ui.show_commit_details, "Show Commit Details").changed() {
+                        self.refresh_repo_settings();
+                        if let Err(e) = self.config.save() {
+                            self.handle_error(e);
+                        }
+                        ui.close_menu();
+                    }
+                    
+                    if ui.checkbox(&mut self.config.ui.show_working_status, "Show Working Directory").changed() {
+                        self.refresh_repo_settings();
+                        if let Err(e) = self.config.save() {
+                            self.handle_error(e);
+                        }
+                        self.refresh_working_status();
+                        ui.close_menu();
+                    }
+                    
+                    if ui.checkbox(&mut self.config.ui.syntax_highlighting, "Syntax Highlighting").changed() {
+                        self.temp_config.ui.syntax_highlighting = self.config.ui.syntax_highlighting;
+                        self.sync_syntax_highlighting();
+                        if let Err(e) = self.config.save() {
+                            self.handle_error(e);
+                        }
+                        ui.close_menu();
+                    }
+                    
+                    if ui.checkbox(&mut self.config.ui.show_commit_graph, "Show Commit Graph").changed() {
+                        self.refresh_repo_settings();
+                        if let Err(e) = self.config.save() {
+                            self.handle_error(e);
+                        }
+                        ui.close_menu();
+                    }
+                    
+                    if ui.checkbox(&mut self.config.ui.hide_merge_commits, "Hide Merge Commits").changed() {
+                        self.refresh_repo_settings();
+                        if let Err(e) = self.config.save() {
+                            self.handle_error(e);
+                        }
+                        ui.close_menu();
+                    }
+                    
                     ui.separator();
                     
                     let menu_bar_text = if self.config.ui.menu_preferences.show_menu_bar { "Hide Menu Bar" } else { "Show Menu Bar" };
@@ -1633,7 +2851,7 @@ impl TwiggyApp {
                 
                 ui.menu_button("Help", |ui| {
                     if self.config.ui.menu_preferences.show_keyboard_shortcuts {
-                        if ui.add(egui::Button::new("Keyboard Shortcuts").shortcut_text("F1")).clicked() {
+                        if ui.add(egui::Button::new("Keyboard Shortcuts").shortcut_text(self.config.keybinds.get(KeybindAction::ShowShortcuts))).clicked() {
                             self.show_shortcuts = true;
                             ui.close_menu();
                         }
@@ -1645,422 +2863,2643 @@ impl TwiggyApp {
                         self.show_about = true;
                         ui.close_menu();
                     }
+                    
+                    if self.config.performance.developer_mode {
+                        ui.separator();
+                        
+                        let can_run = self.current_repository.is_some() && self.benchmark_receiver.is_none();
+                        if ui.add_enabled(can_run, egui::Button::new("Run Benchmark")).clicked() {
+                            self.start_benchmark();
+                            ui.close_menu();
+                        }
+                    }
                 });
             });
         });
     }
 
-    fn render_status_bar(&self, ctx: &egui::Context) {
-        egui::TopBottomPanel::bottom("status_bar")
-            .min_height(28.0)
+    fn render_diff_panel(&mut self, ctx: &egui::Context) {
+        if !self.settings().ui.show_diff_viewer {
+            return;
+        }
+        
+        let Some(commit_id) = self.commit_list.selected_commit() else {
+            return;
+        };
+        
+        let Some(ref mut repo) = self.current_repository else {
+            return;
+        };
+        
+        let summary = repo.get_commit_by_id(&commit_id)
+            .map(|commit| commit.summary.clone())
+            .unwrap_or_default();
+        let compare_range = self.commit_list.compare_range();
+        
+        egui::TopBottomPanel::bottom("diff_panel")
+            .resizable(true)
+            .default_height(self.config.ui.panel_sizes.bottom_panel_height)
             .show(ctx, |ui| {
-                ui.add_space(4.0);
                 ui.horizontal(|ui| {
-                    ui.spacing_mut().item_spacing.x = 8.0;
-                    
-                    if let Some(ref repo) = self.current_repository {
-                        match repo.get_branch_info() {
-                            Ok(branch_info) => {
-                                match branch_info.state {
-                                    crate::git::repository::BranchState::Normal => {
-                                        ui.label(egui::RichText::new("🌿").size(16.0));
-                                        ui.strong(egui::RichText::new(&branch_info.name).size(14.0));
-                                        ui.colored_label(egui::Color32::from_rgb(34, 139, 34), egui::RichText::new("●").size(12.0));
-                                    }
-                                    crate::git::repository::BranchState::DetachedHead => {
-                                        ui.label(egui::RichText::new("⚠️").size(16.0));
-                                        ui.colored_label(egui::Color32::YELLOW, egui::RichText::new("DETACHED HEAD").size(12.0).strong());
-                                        ui.colored_label(egui::Color32::from_rgb(255, 165, 0), egui::RichText::new("●").size(12.0));
-                                    }
-                                    crate::git::repository::BranchState::Unborn => {
-                                        ui.label(egui::RichText::new("🆕").size(16.0));
-                                        ui.colored_label(egui::Color32::LIGHT_BLUE, egui::RichText::new("UNBORN").size(12.0).strong());
-                                        ui.colored_label(egui::Color32::LIGHT_BLUE, egui::RichText::new("●").size(12.0));
-                                    }
-                                }
-                                
-                                if let Some(ref upstream) = branch_info.upstream {
-                                    ui.separator();
-                                    ui.label(egui::RichText::new("📡").size(14.0));
-                                    ui.label(egui::RichText::new("→").size(12.0).color(egui::Color32::GRAY));
-                                    ui.colored_label(egui::Color32::from_rgb(100, 149, 237), egui::RichText::new(upstream).size(12.0));
-                                    
-                                    if branch_info.ahead > 0 || branch_info.behind > 0 {
-                                        ui.separator();
-                                        
-                                        if branch_info.ahead > 0 {
-                                            ui.colored_label(egui::Color32::GREEN, egui::RichText::new("↑").size(14.0));
-                                            ui.colored_label(egui::Color32::GREEN, egui::RichText::new(format!("{}", branch_info.ahead)).size(12.0).strong());
-                                        }
-                                        
-                                        if branch_info.behind > 0 {
-                                            ui.colored_label(egui::Color32::RED, egui::RichText::new("↓").size(14.0));
-                                            ui.colored_label(egui::Color32::RED, egui::RichText::new(format!("{}", branch_info.behind)).size(12.0).strong());
-                                        }
-                                        
-                                        let sync_status = if branch_info.ahead == 0 && branch_info.behind == 0 {
-                                            ("✅", "In sync", egui::Color32::GREEN)
-                                        } else if branch_info.ahead > 0 && branch_info.behind == 0 {
-                                            ("📤", "Push needed", egui::Color32::BLUE)
-                                        } else if branch_info.ahead == 0 && branch_info.behind > 0 {
-                                            ("📥", "Pull needed", egui::Color32::from_rgb(255, 165, 0))
-                                        } else {
-                                            ("🔄", "Sync needed", egui::Color32::YELLOW)
-                                        };
-                                        
-                                        ui.separator();
-                                        ui.label(egui::RichText::new(sync_status.0).size(14.0));
-                                        ui.colored_label(sync_status.2, egui::RichText::new(sync_status.1).size(11.0));
-                                    } else {
-                                        ui.separator();
-                                        ui.label(egui::RichText::new("✅").size(14.0));
-                                        ui.colored_label(egui::Color32::GREEN, egui::RichText::new("In sync").size(11.0));
-                                    }
-                                } else {
-                                    ui.separator();
-                                    ui.label(egui::RichText::new("🔗").size(14.0));
-                                    ui.colored_label(egui::Color32::GRAY, egui::RichText::new("No upstream").size(11.0).italics());
-                                }
-                            }
-                            Err(_) => {
-                                ui.colored_label(egui::Color32::RED, egui::RichText::new("❌ Error reading branch info").size(12.0));
-                            }
+                    if let Some((older, newer)) = compare_range {
+                        ui.strong(format!("Comparing {} → {}", older.short(), newer.short()));
+                        if ui.small_button("✕").on_hover_text("Back to the selected commit").clicked() {
+                            self.commit_list.clear_compare_range();
                         }
                     } else {
-                        ui.colored_label(egui::Color32::GRAY, egui::RichText::new("No repository open").size(12.0).italics());
+                        ui.strong(format!("{}  {}", commit_id.short(), summary));
+                        if let Ok(stats) = repo.cached_commit_stats(&commit_id) {
+                            ui.separator();
+                            ui.label(stats.summary());
+                        }
                     }
-                    
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        ui.colored_label(egui::Color32::GRAY, egui::RichText::new(format!("Frame: {:.1}ms", self.performance_metrics.average_frame_time_ms)).size(10.0));
+                        if ui.checkbox(&mut self.diff_viewer.syntax_highlighting, "Highlight").changed() {
+                            self.config.ui.syntax_highlighting = self.diff_viewer.syntax_highlighting;
+                            self.temp_config.ui.syntax_highlighting = self.diff_viewer.syntax_highlighting;
+                            self.file_viewer.syntax_highlighting = self.diff_viewer.syntax_highlighting;
+                            if let Err(e) = self.config.save() {
+                                tracing::warn!("Failed to save syntax highlighting setting: {}", e);
+                            }
+                        }
+                        ui.checkbox(&mut self.diff_viewer.show_line_numbers, "Line Numbers");
+                        ui.checkbox(&mut self.diff_viewer.word_diff, "Word Diff");
+                        if ui.checkbox(&mut self.diff_viewer.ignore_whitespace, "Ignore Whitespace").changed() {
+                            repo.set_ignore_whitespace(self.diff_viewer.ignore_whitespace);
+                            self.commit_list.clear_commit_stats();
+                        }
                     });
                 });
-                ui.add_space(4.0);
+                ui.separator();
+                
+                let files = match compare_range {
+                    Some((older, newer)) => repo.cached_diff_between(&older, &newer),
+                    None => repo.cached_diff(&commit_id),
+                };
+                match files {
+                    Ok(files) => self.diff_viewer.render(ui, files),
+                    Err(e) => {
+                        ui.colored_label(egui::Color32::RED, format!("Failed to load diff: {}", e));
+                    }
+                }
             });
     }
-
-    fn handle_keyboard_shortcuts(&mut self, ctx: &egui::Context) {
-        if ctx.input_mut(|i| i.consume_shortcut(&egui::KeyboardShortcut::new(egui::Modifiers::NONE, egui::Key::F10))) {
-            self.config.ui.menu_preferences.show_menu_bar = !self.config.ui.menu_preferences.show_menu_bar;
-            if let Err(e) = self.config.save() {
-                self.handle_error(e);
-            } else {
-                let status = if self.config.ui.menu_preferences.show_menu_bar { "shown" } else { "hidden" };
-                self.add_notification(
-                    format!("Menu bar {}", status),
-                    NotificationType::Info,
-                    Some(2),
-                );
-            }
+    
+    fn render_commit_detail_panel(&mut self, ctx: &egui::Context) {
+        if !self.settings().ui.show_commit_details {
+            return;
         }
         
-        if !self.config.ui.menu_preferences.show_keyboard_shortcuts {
+        let Some(commit_id) = self.commit_list.selected_commit() else {
             return;
-        }
+        };
         
-        if ctx.input_mut(|i| i.consume_shortcut(&egui::KeyboardShortcut::new(egui::Modifiers::CTRL, egui::Key::O))) {
-            self.open_repository();
-        }
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
         
-        if ctx.input_mut(|i| i.consume_shortcut(&egui::KeyboardShortcut::new(egui::Modifiers::CTRL, egui::Key::S))) {
-            self.temp_config = self.config.clone();
-            self.show_settings = true;
-        }
+        let Some(commit) = repo.get_commit_by_id(&commit_id) else {
+            return;
+        };
         
-        if ctx.input_mut(|i| i.consume_shortcut(&egui::KeyboardShortcut::new(egui::Modifiers::CTRL, egui::Key::Q))) {
-            ctx.send_viewport_cmd(egui::ViewportCommand::Close);
-        }
+        let description = repo.describe_commit(&commit_id).unwrap_or_else(|e| {
+            tracing::warn!("Failed to describe {}: {}", commit_id, e);
+            commit_id.short()
+        });
         
-        if ctx.input_mut(|i| i.consume_shortcut(&egui::KeyboardShortcut::new(egui::Modifiers::CTRL | egui::Modifiers::SHIFT, egui::Key::O))) {
-            if let Some(recent_repo) = self.config.recent_repositories.repositories.first() {
-                let path = recent_repo.path.clone();
-                self.open_recent_repository(path);
-            }
+        let mut detail_response = None;
+        let mut navigate = None;
+        egui::SidePanel::right("commit_detail_panel")
+            .resizable(true)
+            .default_width(self.config.ui.panel_sizes.right_panel_width)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.strong("Commit");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let forward = self.config.keybinds.get(KeybindAction::NavigateForward);
+                        if ui.add_enabled(self.selection_history.can_go_forward(), egui::Button::new("▶"))
+                            .on_hover_text(format!("Next selection ({})", forward))
+                            .clicked()
+                        {
+                            navigate = Some(true);
+                        }
+                        let back = self.config.keybinds.get(KeybindAction::NavigateBack);
+                        if ui.add_enabled(self.selection_history.can_go_back(), egui::Button::new("◀"))
+                            .on_hover_text(format!("Previous selection ({})", back))
+                            .clicked()
+                        {
+                            navigate = Some(false);
+                        }
+                    });
+                });
+                ui.separator();
+                detail_response = Some(self.commit_detail.render(ui, commit, &description));
+            });
+        
+        if let Some(parent) = detail_response.and_then(|response| response.selected_parent) {
+            tracing::info!("Selecting parent commit: {}", parent);
+            self.commit_list.scroll_to_commit(parent);
         }
         
-        for i in 1..=9 {
-            let key = match i {
-                1 => egui::Key::Num1,
-                2 => egui::Key::Num2,
-                3 => egui::Key::Num3,
-                4 => egui::Key::Num4,
-                5 => egui::Key::Num5,
-                6 => egui::Key::Num6,
-                7 => egui::Key::Num7,
-                8 => egui::Key::Num8,
-                9 => egui::Key::Num9,
-                _ => continue,
-            };
-            
-            if ctx.input_mut(|i| i.consume_shortcut(&egui::KeyboardShortcut::new(egui::Modifiers::CTRL, key))) {
-                if let Some(recent_repo) = self.config.recent_repositories.repositories.get(i - 1) {
-                    let path = recent_repo.path.clone();
-                    self.open_recent_repository(path);
-                }
-            }
+        if let Some(forward) = navigate {
+            self.navigate_selection(forward);
         }
+    }
+    
+    /// Moves back or forward through previously selected commits.
+    fn navigate_selection(&mut self, forward: bool) {
+        let target = if forward {
+            self.selection_history.go_forward()
+        } else {
+            self.selection_history.go_back()
+        };
         
-        if ctx.input_mut(|i| i.consume_shortcut(&egui::KeyboardShortcut::new(egui::Modifiers::NONE, egui::Key::F1))) {
-            self.show_shortcuts = true;
+        if let Some(commit_id) = target {
+            self.commit_list.scroll_to_commit(commit_id);
         }
     }
-
-    fn render_help_dialogs(&mut self, ctx: &egui::Context) {
-        if self.show_about {
-            egui::Window::new("About Twiggy")
-                .collapsible(false)
-                .resizable(false)
-                .default_size([400.0, 300.0])
+    
+    fn render_file_tree_panel(&mut self, ctx: &egui::Context) {
+        if !self.settings().ui.show_file_tree {
+            return;
+        }
+        
+        let Some(commit_id) = self.commit_list.selected_commit() else {
+            return;
+        };
+        
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        
+        if self.file_tree_entries.as_ref().map(|(id, _)| *id) != Some(commit_id) {
+            match repo.list_tree(&commit_id) {
+                Ok(entries) => self.file_tree_entries = Some((commit_id, entries)),
+                Err(e) => {
+                    self.file_tree_entries = Some((commit_id, Vec::new()));
+                    self.handle_error(e);
+                }
+            }
+        }
+        
+        let Some((_, ref entries)) = self.file_tree_entries else {
+            return;
+        };
+        
+        let mut tree_response = None;
+        egui::SidePanel::right("file_tree_panel")
+            .resizable(true)
+            .default_width(self.config.ui.panel_sizes.right_panel_width)
+            .show(ctx, |ui| {
+                ui.strong("Files");
+                ui.separator();
+                tree_response = Some(self.file_tree.render(ui, entries));
+            });
+        
+        let Some(tree_response) = tree_response else {
+            return;
+        };
+        
+        if let Some(path) = tree_response.clicked {
+            tracing::info!("Showing diff for file: {}", path);
+            self.diff_viewer.scroll_to_file(&path);
+        }
+        
+        if let Some(path) = tree_response.blame {
+            self.blame_file(commit_id, path);
+        }
+        
+        if let Some(path) = tree_response.opened {
+            self.open_file_at_commit(commit_id, path);
+        }
+        
+        if let Some(path) = tree_response.history {
+            self.show_file_history(path);
+        }
+    }
+    
+    fn show_file_history(&mut self, path: String) {
+        let max_commits = self.settings().git.max_commits;
+        let Some(ref mut repo) = self.current_repository else {
+            return;
+        };
+        
+        match repo.file_history(Path::new(&path), Some(max_commits)) {
+            Ok(commits) => {
+                tracing::info!("Showing {} commits touching {}", commits.len(), path);
+                if commits.is_empty() {
+                    self.add_notification(
+                        format!("No commits in HEAD's history touch {}", path),
+                        NotificationType::Info,
+                        Some(3),
+                    );
+                }
+                self.file_history = Some((path, commits));
+            }
+            Err(e) => self.handle_error(e),
+        }
+    }
+    
+    /// Computes diff stats for the rows the list showed last frame, a few per
+    /// frame so scrolling into big commits doesn't stall rendering. Signature
+    /// status is cheap to read, so it's filled in for every visible row.
+    fn load_visible_commit_stats(&mut self, ctx: &egui::Context, commits: &[Commit]) {
+        const MAX_STATS_PER_FRAME: usize = 8;
+        
+        let Some(ref mut repo) = self.current_repository else {
+            return;
+        };
+        
+        let (first, last) = self.commit_list.get_visible_range();
+        let visible = &commits[first.min(commits.len())..last.min(commits.len())];
+        let mut computed = 0;
+        
+        for commit in visible {
+            if !self.commit_list.has_signature_status(&commit.id) {
+                let status = repo.commit_signature_status(&commit.id).unwrap_or_else(|e| {
+                    tracing::warn!("Failed to read signature of {}: {}", commit.id, e);
+                    SignatureStatus::Unknown
+                });
+                self.commit_list.insert_signature_status(commit.id, status);
+            }
+            
+            if self.commit_list.has_commit_stats(&commit.id) {
+                continue;
+            }
+            
+            if computed == MAX_STATS_PER_FRAME {
+                ctx.request_repaint();
+                break;
+            }
+            
+            match repo.cached_commit_stats(&commit.id) {
+                Ok(stats) => self.commit_list.insert_commit_stats(commit.id, stats),
+                Err(e) => {
+                    tracing::warn!("Failed to compute stats for {}: {}", commit.id, e);
+                    self.commit_list.insert_commit_stats(commit.id, Default::default());
+                }
+            }
+            computed += 1;
+        }
+    }
+    
+    fn blame_file(&mut self, commit_id: CommitId, path: String) {
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        
+        let blame = repo.blame_file(std::path::Path::new(&path), Some(commit_id))
+            .and_then(|lines| Ok((repo.blame_parents(&lines)?, lines)));
+        match blame {
+            Ok((parents, lines)) => self.blame_view.show(path, commit_id, lines, parents),
+            Err(e) => self.handle_error(e),
+        }
+    }
+    
+    /// Re-runs the open blame at `parent`, the revision just before the
+    /// clicked line's change.
+    fn blame_before(&mut self, parent: CommitId) {
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        
+        let path = self.blame_view.path().to_string();
+        let blame = repo.blame_file(std::path::Path::new(&path), Some(parent))
+            .and_then(|lines| Ok((repo.blame_parents(&lines)?, lines)));
+        match blame {
+            Ok((parents, lines)) => self.blame_view.step_back(parent, lines, parents),
+            Err(e) => self.add_notification(
+                format!("Cannot blame {} at {}: {}", path, parent.short(), e),
+                NotificationType::Warning,
+                Some(5),
+            ),
+        }
+    }
+    
+    fn open_file_at_commit(&mut self, commit_id: CommitId, path: String) {
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        
+        let file_path = Path::new(&path);
+        let content = repo.file_at_commit(&commit_id, file_path)
+            .and_then(|bytes| Ok((bytes, repo.is_binary_at_commit(&commit_id, file_path)?)));
+        match content {
+            Ok((bytes, is_binary)) => self.file_viewer.show(path, commit_id, bytes, is_binary),
+            Err(e) => self.handle_error(e),
+        }
+    }
+    
+    fn render_status_bar(&mut self, ctx: &egui::Context) {
+        let frame_time_ms = self.performance_metrics.average_frame_time_ms;
+        
+        egui::TopBottomPanel::bottom("status_bar")
+            .min_height(28.0)
+            .show(ctx, |ui| {
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    self.status_bar.render(ui);
+                    
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        ui.colored_label(egui::Color32::GRAY, egui::RichText::new(format!("Frame: {:.1}ms", frame_time_ms)).size(10.0));
+                    });
+                });
+                ui.add_space(4.0);
+            });
+    }
+    
+    fn refresh_status_bar(&mut self) {
+        self.status_bar.update(self.current_repository.as_ref());
+        self.refresh_working_status();
+    }
+    
+    fn refresh_working_status(&mut self) {
+        if !self.settings().ui.show_working_status {
+            self.working_status.clear();
+            return;
+        }
+        
+        self.working_status = match self.current_repository.as_ref().map(|repo| repo.working_status()) {
+            Some(Ok(entries)) => entries,
+            Some(Err(e)) => {
+                tracing::warn!("Failed to read working directory status: {}", e);
+                Vec::new()
+            }
+            None => Vec::new(),
+        };
+    }
+    
+    fn render_working_status_panel(&mut self, ctx: &egui::Context) {
+        if !self.settings().ui.show_working_status {
+            return;
+        }
+        
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        let is_bare = repo.workdir().is_none();
+        
+        let mut refresh = false;
+        egui::SidePanel::left("working_status_panel")
+            .resizable(true)
+            .default_width(self.config.ui.panel_sizes.left_panel_width)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.strong("Working Directory");
+                    refresh = ui.small_button("⟳").on_hover_text("Reload status").clicked();
+                });
+                ui.separator();
+                
+                if is_bare {
+                    ui.label("Bare repositories have no working directory");
+                    return;
+                }
+                if self.working_status.is_empty() {
+                    ui.label("Nothing to commit, working tree clean");
+                    return;
+                }
+                
+                egui::ScrollArea::vertical()
+                    .id_source("working_status_scroll")
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        for category in StatusCategory::ALL {
+                            let paths: Vec<&str> = self.working_status.iter()
+                                .filter(|entry| entry.category == category)
+                                .map(|entry| entry.path.as_str())
+                                .collect();
+                            if paths.is_empty() {
+                                continue;
+                            }
+                            
+                            egui::CollapsingHeader::new(format!("{} ({})", category.label(), paths.len()))
+                                .id_source(("working_status", category.label()))
+                                .default_open(true)
+                                .show(ui, |ui| {
+                                    for path in paths {
+                                        ui.monospace(path);
+                                    }
+                                });
+                        }
+                    });
+            });
+        
+        if refresh {
+            self.refresh_status_bar();
+        }
+    }
+
+    fn handle_keyboard_shortcuts(&mut self, ctx: &egui::Context) {
+        if self.recording_keybind.is_some() {
+            self.record_keybind(ctx);
+            return;
+        }
+        
+        let shortcuts_enabled = self.config.ui.menu_preferences.show_keyboard_shortcuts;
+        
+        for (action, shortcut) in self.shortcuts.clone() {
+            // The menu bar toggle stays available so a hidden menu can
+            // always be brought back.
+            if !shortcuts_enabled && action != KeybindAction::ToggleMenuBar {
+                continue;
+            }
+            
+            if ctx.input_mut(|i| i.consume_shortcut(&shortcut)) {
+                self.run_keybind_action(ctx, action);
+            }
+        }
+        
+        if !shortcuts_enabled {
+            return;
+        }
+        
+        const UI_SCALE_STEP: f32 = 0.1;
+        let zoom_in = egui::KeyboardShortcut::new(egui::Modifiers::CTRL, egui::Key::PlusEquals);
+        let zoom_out = egui::KeyboardShortcut::new(egui::Modifiers::CTRL, egui::Key::Minus);
+        let zoom_reset = egui::KeyboardShortcut::new(egui::Modifiers::CTRL, egui::Key::Num0);
+        if ctx.input_mut(|i| i.consume_shortcut(&zoom_in)) {
+            self.set_ui_scale(self.config.theme.ui_scale + UI_SCALE_STEP);
+        } else if ctx.input_mut(|i| i.consume_shortcut(&zoom_out)) {
+            self.set_ui_scale(self.config.theme.ui_scale - UI_SCALE_STEP);
+        } else if ctx.input_mut(|i| i.consume_shortcut(&zoom_reset)) {
+            self.set_ui_scale(1.0);
+        }
+        
+        for i in 1..=9 {
+            let key = match i {
+                1 => egui::Key::Num1,
+                2 => egui::Key::Num2,
+                3 => egui::Key::Num3,
+                4 => egui::Key::Num4,
+                5 => egui::Key::Num5,
+                6 => egui::Key::Num6,
+                7 => egui::Key::Num7,
+                8 => egui::Key::Num8,
+                9 => egui::Key::Num9,
+                _ => continue,
+            };
+            
+            if ctx.input_mut(|i| i.consume_shortcut(&egui::KeyboardShortcut::new(egui::Modifiers::CTRL, key))) {
+                if let Some(recent_repo) = self.config.recent_repositories.repositories.get(i - 1) {
+                    let path = recent_repo.path.clone();
+                    self.open_recent_repository(path);
+                }
+            }
+        }
+    }
+    
+    fn set_ui_scale(&mut self, scale: f32) {
+        let scale = ((scale * 10.0).round() / 10.0).clamp(MIN_UI_SCALE, MAX_UI_SCALE);
+        if scale == self.config.theme.ui_scale {
+            return;
+        }
+        
+        self.config.theme.ui_scale = scale;
+        self.temp_config.theme.ui_scale = scale;
+        if let Err(e) = self.config.save() {
+            tracing::error!("Failed to save UI scale: {}", e);
+        }
+        
+        self.add_notification(
+            format!("UI scale {:.0}%", scale * 100.0),
+            NotificationType::Info,
+            Some(2),
+        );
+    }
+    
+    fn open_command_palette(&mut self) {
+        let mut entries = vec![
+            PaletteEntry::new("Open Repository…", PaletteCommand::OpenRepository),
+            PaletteEntry::new("Toggle Theme", PaletteCommand::ToggleTheme),
+            PaletteEntry::new("Open Settings", PaletteCommand::OpenSettings),
+            PaletteEntry::new("Show Log Viewer", PaletteCommand::ShowLogViewer),
+        ];
+        
+        if self.current_repository.is_some() {
+            entries.push(PaletteEntry::new("Refresh Commits", PaletteCommand::Refresh));
+            if self.has_history() {
+                entries.push(PaletteEntry::new("Go to Commit…", PaletteCommand::GoToCommit));
+            }
+            entries.push(PaletteEntry::new("Open in File Manager", PaletteCommand::OpenInFileManager));
+            entries.push(PaletteEntry::new("Open in Terminal", PaletteCommand::OpenInTerminal));
+        }
+        
+        for repo in &self.config.recent_repositories.repositories {
+            entries.push(PaletteEntry::new(
+                format!("Open Recent: {} ({})", repo.name, repo.path.display()),
+                PaletteCommand::OpenRecent(repo.path.clone()),
+            ));
+        }
+        
+        self.command_palette.show(entries);
+    }
+    
+    fn run_palette_command(&mut self, command: PaletteCommand) {
+        match command {
+            PaletteCommand::OpenRepository => self.open_repository(),
+            PaletteCommand::OpenRecent(path) => self.open_recent_repository(path),
+            PaletteCommand::ToggleTheme => self.toggle_theme(),
+            PaletteCommand::OpenSettings => {
+                self.temp_config = self.config.clone();
+                self.show_settings = true;
+            }
+            PaletteCommand::ShowLogViewer => self.show_log_viewer = true,
+            PaletteCommand::Refresh => self.refresh_repository(),
+            PaletteCommand::GoToCommit => self.open_go_to_commit(),
+            PaletteCommand::OpenInFileManager => self.open_repository_in_file_manager(),
+            PaletteCommand::OpenInTerminal => self.open_repository_in_terminal(),
+        }
+    }
+    
+    /// Working tree of the open repository, or its git directory when bare.
+    fn repository_root(&self) -> Option<PathBuf> {
+        let repo = self.current_repository.as_ref()?;
+        Some(repo.workdir().unwrap_or(repo.path()).to_path_buf())
+    }
+    
+    fn open_repository_in_file_manager(&mut self) {
+        let Some(root) = self.repository_root() else {
+            return;
+        };
+        
+        if let Err(e) = open_path_in_file_manager(&root) {
+            self.add_notification(
+                format!("Failed to open file manager: {}", e),
+                NotificationType::Error,
+                Some(5),
+            );
+        }
+    }
+    
+    fn open_repository_in_terminal(&mut self) {
+        let Some(root) = self.repository_root() else {
+            return;
+        };
+        
+        if let Err(e) = open_terminal_at(&root) {
+            self.add_notification(
+                format!("Failed to open terminal: {}", e),
+                NotificationType::Error,
+                Some(5),
+            );
+        }
+    }
+    
+    fn run_keybind_action(&mut self, ctx: &egui::Context, action: KeybindAction) {
+        match action {
+            KeybindAction::OpenRepository => self.open_repository(),
+            KeybindAction::OpenLastRepository => {
+                if let Some(recent_repo) = self.config.recent_repositories.repositories.first() {
+                    let path = recent_repo.path.clone();
+                    self.open_recent_repository(path);
+                }
+            }
+            KeybindAction::CloseRepository => self.request_close_repository(),
+            KeybindAction::Refresh => self.refresh_repository(),
+            KeybindAction::Settings => {
+                self.temp_config = self.config.clone();
+                self.show_settings = true;
+            }
+            KeybindAction::Quit => ctx.send_viewport_cmd(egui::ViewportCommand::Close),
+            KeybindAction::ShowShortcuts => self.show_shortcuts = true,
+            KeybindAction::CommandPalette => self.open_command_palette(),
+            KeybindAction::NavigateBack => self.navigate_selection(false),
+            KeybindAction::NavigateForward => self.navigate_selection(true),
+            KeybindAction::GoToCommit => self.open_go_to_commit(),
+            KeybindAction::ToggleMenuBar => {
+                self.config.ui.menu_preferences.show_menu_bar = !self.config.ui.menu_preferences.show_menu_bar;
+                if let Err(e) = self.config.save() {
+                    self.handle_error(e);
+                } else {
+                    let status = if self.config.ui.menu_preferences.show_menu_bar { "shown" } else { "hidden" };
+                    self.add_notification(
+                        format!("Menu bar {}", status),
+                        NotificationType::Info,
+                        Some(2),
+                    );
+                }
+            }
+        }
+    }
+    
+    /// Takes the next key press as the new binding for the action being
+    /// rebound in settings. Escape on its own cancels.
+    fn record_keybind(&mut self, ctx: &egui::Context) {
+        let Some(action) = self.recording_keybind else {
+            return;
+        };
+        
+        let pressed = ctx.input(|i| {
+            i.events.iter().find_map(|event| match event {
+                egui::Event::Key { key, pressed: true, modifiers, .. } => Some((*key, *modifiers)),
+                _ => None,
+            })
+        });
+        
+        let Some((key, modifiers)) = pressed else {
+            return;
+        };
+        
+        self.recording_keybind = None;
+        if key == egui::Key::Escape && modifiers.is_none() {
+            return;
+        }
+        
+        let modifiers = egui::Modifiers {
+            alt: modifiers.alt,
+            ctrl: modifiers.ctrl,
+            shift: modifiers.shift,
+            mac_cmd: modifiers.mac_cmd,
+            command: false,
+        };
+        let binding = format_shortcut(&egui::KeyboardShortcut::new(modifiers, key));
+        self.temp_config.keybinds.set(action, binding);
+    }
+
+    fn render_repository_info_window(&mut self, ctx: &egui::Context) {
+        if !self.show_repository_info {
+            return;
+        }
+        
+        let mut open = true;
+        egui::Window::new("Repository Info")
+            .open(&mut open)
+            .resizable(true)
+            .default_width(420.0)
+            .show(ctx, |ui| {
+                self.render_repository_info(ui);
+            });
+        
+        self.show_repository_info = open;
+    }
+    
+    fn render_statistics_window(&mut self, ctx: &egui::Context) {
+        if !self.show_statistics {
+            return;
+        }
+        
+        let Some(repo) = self.current_repository.as_ref().filter(|repo| repo.has_history()) else {
+            self.show_statistics = false;
+            return;
+        };
+        
+        let stats = match repo.author_stats() {
+            Ok(stats) => stats,
+            Err(e) => {
+                tracing::warn!("Failed to compute author statistics: {}", e);
+                Vec::new()
+            }
+        };
+        let commit_count = repo.commit_count();
+        
+        let mut open = true;
+        egui::Window::new("Statistics")
+            .open(&mut open)
+            .resizable(true)
+            .default_width(520.0)
+            .show(ctx, |ui| {
+                ui.label(format!("{} authors across {} loaded commits", stats.len(), commit_count));
+                ui.separator();
+                
+                egui::ScrollArea::vertical().max_height(360.0).show(ui, |ui| {
+                    egui::Grid::new("author_stats_grid")
+                        .num_columns(4)
+                        .striped(true)
+                        .spacing([16.0, 4.0])
+                        .show(ui, |ui| {
+                            ui.strong("Author");
+                            ui.strong("Commits");
+                            ui.strong("First");
+                            ui.strong("Last");
+                            ui.end_row();
+                            
+                            for stat in &stats {
+                                ui.label(&stat.name).on_hover_text(&stat.email);
+                                ui.label(stat.commit_count.to_string());
+                                ui.label(stat.first_commit.format("%Y-%m-%d").to_string());
+                                ui.label(stat.last_commit.format("%Y-%m-%d").to_string());
+                                ui.end_row();
+                            }
+                        });
+                });
+            });
+        
+        self.show_statistics = open;
+    }
+    
+    fn render_notification_history(&mut self, ctx: &egui::Context) {
+        if !self.show_notification_history {
+            return;
+        }
+        
+        let mut open = true;
+        let mut clear = false;
+        egui::Window::new("Notifications")
+            .open(&mut open)
+            .resizable(true)
+            .default_width(480.0)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(format!("Last {} notifications this session", NOTIFICATION_HISTORY_LIMIT));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        clear = ui.add_enabled(!self.notification_history.is_empty(), egui::Button::new("Clear history")).clicked();
+                    });
+                });
+                ui.separator();
+                
+                if self.notification_history.is_empty() {
+                    ui.weak("No notifications yet");
+                    return;
+                }
+                
+                egui::ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
+                    egui::Grid::new("notification_history_grid")
+                        .num_columns(3)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for record in self.notification_history.iter().rev() {
+                                ui.monospace(record.time.format("%H:%M:%S").to_string());
+                                ui.colored_label(record.notification_type.color(), record.notification_type.label());
+                                ui.label(&record.message);
+                                ui.end_row();
+                            }
+                        });
+                });
+            });
+        
+        if clear {
+            self.notification_history.clear();
+        }
+        self.show_notification_history = open;
+    }
+    
+    fn render_help_dialogs(&mut self, ctx: &egui::Context) {
+        if self.show_about {
+            egui::Window::new("About Twiggy")
+                .collapsible(false)
+                .resizable(false)
+                .default_size([400.0, 300.0])
                 .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
                 .show(ctx, |ui| {
                     ui.vertical_centered(|ui| {
                         ui.add_space(10.0);
-                        ui.heading("🌿 Twiggy");
-                        ui.add_space(5.0);
+                        ui.heading("🌿 Twiggy");
+                        ui.add_space(5.0);
+                        ui.label("Lightning-fast Git Visualization Tool");
+                        ui.add_space(20.0);
+                        
+                        ui.separator();
+                        ui.add_space(10.0);
+                        
+                        ui.horizontal(|ui| {
+                            ui.label("Version:");
+                            ui.label(env!("CARGO_PKG_VERSION"));
+                        });
+                        
+                        ui.horizontal(|ui| {
+                            ui.label("Author:");
+                            ui.label("Twiggy Team");
+                        });
+                        
+                        ui.horizontal(|ui| {
+                            ui.label("Built with:");
+                            ui.label("Rust + egui");
+                        });
+                        
+                        ui.add_space(20.0);
+                        ui.separator();
+                        ui.add_space(10.0);
+                        
+                        ui.label("Built with ❤️ using egui and Rust");
+                        ui.label("© 2024 Twiggy Team");
+                        
+                        ui.add_space(20.0);
+                        
+                        if ui.button("Close").clicked() {
+                            self.show_about = false;
+                        }
+                    });
+                });
+        }
+
+        if self.show_shortcuts {
+            egui::Window::new("Keyboard Shortcuts")
+                .collapsible(false)
+                .resizable(true)
+                .default_size([500.0, 400.0])
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    ui.vertical(|ui| {
+                        ui.heading("Application Shortcuts");
+                        ui.add_space(10.0);
+                        
+                        egui::Grid::new("shortcuts_grid")
+                            .num_columns(2)
+                            .spacing([40.0, 4.0])
+                            .striped(true)
+                            .show(ui, |ui| {
+                                ui.label("Action");
+                                ui.label("Shortcut");
+                                ui.end_row();
+                                
+                                ui.separator();
+                                ui.separator();
+                                ui.end_row();
+                                
+                                for action in KeybindAction::ALL {
+                                    ui.label(action.label());
+                                    ui.label(self.config.keybinds.get(action));
+                                    ui.end_row();
+                                }
+                                
+                                ui.label("Close Dialog");
+                                ui.label("Escape");
+                                ui.end_row();
+                                
+                                ui.label("Zoom In / Out");
+                                ui.label("Ctrl+Plus / Ctrl+Minus");
+                                ui.end_row();
+                                
+                                ui.label("Reset Zoom");
+                                ui.label("Ctrl+0");
+                                ui.end_row();
+                                
+                                ui.label("Save Settings");
+                                ui.label(format_shortcut(&SAVE_SETTINGS_SHORTCUT));
+                                ui.end_row();
+                                
+                                ui.separator();
+                                ui.separator();
+                                ui.end_row();
+                                
+                                ui.label("Copy");
+                                ui.label("Ctrl+C");
+                                ui.end_row();
+                                
+                                ui.label("Select All");
+                                ui.label("Ctrl+A");
+                                ui.end_row();
+                            });
+                        
+                        ui.add_space(20.0);
+                        
+                        ui.horizontal(|ui| {
+                            if ui.button("Close").clicked() {
+                                self.show_shortcuts = false;
+                            }
+                        });
+                    });
+                });
+        }
+    }
+
+    fn open_repository(&mut self) {
+        tracing::info!("Repository opening requested");
+        
+        let mut dialog = rfd::FileDialog::new()
+            .set_title("Open Git Repository - Select folder containing .git");
+        
+        let default_dir = self.get_default_directory_for_dialog();
+        if let Some(dir) = default_dir {
+            dialog = dialog.set_directory(dir);
+        }
+        
+        if let Some(path) = dialog.pick_folder() {
+            self.open_repository_path(path);
+        }
+    }
+    
+    fn export_graph_as_dot(&mut self) {
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        
+        let file_name = format!("{}.dot", repo.repository_name());
+        let commits = self.displayed_commits();
+        
+        let Some(path) = rfd::FileDialog::new()
+            .set_title("Export Commit Graph")
+            .set_file_name(&file_name)
+            .add_filter("GraphViz DOT", &["dot", "gv"])
+            .save_file() else {
+            return;
+        };
+        
+        match std::fs::write(&path, to_dot(&commits)) {
+            Ok(()) => {
+                tracing::info!("Exported {} commits to {}", commits.len(), path.display());
+                self.add_notification(
+                    format!("Graph exported to {}", path.display()),
+                    NotificationType::Success,
+                    Some(3)
+                );
+            }
+            Err(e) => {
+                self.handle_error(TwiggyError::FileSystem {
+                    path: path.display().to_string(),
+                    source: e,
+                });
+            }
+        }
+    }
+    
+    fn export_commits(&mut self, format: CommitExportFormat) {
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        
+        let Some(path) = rfd::FileDialog::new()
+            .set_title("Export Commits")
+            .set_file_name(format!("{}-commits.{}", repo.repository_name(), format.extension()))
+            .add_filter(format!("{} files", format.label()), &[format.extension()])
+            .save_file() else {
+            return;
+        };
+        
+        let result = std::fs::File::create(&path)
+            .map_err(|e| TwiggyError::FileSystem {
+                path: path.display().to_string(),
+                source: e,
+            })
+            .and_then(|file| {
+                let writer = std::io::BufWriter::new(file);
+                match format {
+                    CommitExportFormat::Json => repo.export_commits_json(writer),
+                    CommitExportFormat::Csv => repo.export_commits_csv(writer),
+                }
+            });
+        
+        match result {
+            Ok(()) => {
+                tracing::info!("Exported {} commits to {}", repo.commit_count(), path.display());
+                self.add_notification(
+                    format!("Commits exported to {}", path.display()),
+                    NotificationType::Success,
+                    Some(3)
+                );
+            }
+            Err(e) => self.handle_error(e),
+        }
+    }
+    
+    fn get_default_directory_for_dialog(&self) -> Option<PathBuf> {
+        if let Some(last_repo) = self.config.recent_repositories.repositories.first() {
+            if last_repo.path.exists() {
+                return Some(last_repo.path.clone());
+            }
+            if let Some(parent) = last_repo.path.parent() {
+                if parent.exists() {
+                    return Some(parent.to_path_buf());
+                }
+            }
+        }
+        
+        if let Ok(home_dir) = std::env::var("USERPROFILE").or_else(|_| std::env::var("HOME")) {
+            let home_path = PathBuf::from(home_dir);
+            if home_path.exists() {
+                return Some(home_path);
+            }
+        }
+        
+        None
+    }
+    
+    /// Opens the repository containing `path`, as given on the command line.
+    /// A path outside any repository only produces a warning.
+    pub fn open_repository_at(&mut self, path: PathBuf, revision: Option<String>) {
+        match discover_repository_root(&path) {
+            Ok(Some(root)) => self.start_repository_open(root, revision),
+            Ok(None) => {
+                tracing::warn!("Not a Git repository: {}", path.display());
+                self.add_notification(
+                    format!("{} is not a Git repository", path.display()),
+                    NotificationType::Warning,
+                    Some(6),
+                );
+            }
+            Err(e) => {
+                tracing::warn!("Failed to look up repository at {}: {}", path.display(), e);
+                self.add_notification(
+                    format!("Couldn't open {}: {}", path.display(), e),
+                    NotificationType::Warning,
+                    Some(6),
+                );
+            }
+        }
+    }
+    
+    fn open_repository_path(&mut self, path: std::path::PathBuf) {
+        self.start_repository_open(path, None);
+    }
+    
+    fn start_repository_open(&mut self, path: PathBuf, revision: Option<String>) {
+        if !path.exists() {
+            tracing::error!("Repository path does not exist: {}", path.display());
+            self.show_error_message(format!("Path does not exist: {}", path.display()));
+            return;
+        }
+        
+        if !path.is_dir() {
+            tracing::error!("Repository path is not a directory: {}", path.display());
+            self.show_error_message(format!("Path is not a directory: {}", path.display()));
+            return;
+        }
+        
+        self.cancel_repository_open();
+        tracing::info!("Opening repository in background: {}", path.display());
+        self.repository_loading = true;
+        
+        let global_config = self.config.clone();
+        let cache_limit_bytes = self.config.performance.cache_size_mb * 1024 * 1024;
+        let parse_threads = self.config.performance.commit_parse_threads();
+        let worker_path = path.clone();
+        let loaded = Arc::new(AtomicUsize::new(0));
+        let total = Arc::new(AtomicUsize::new(0));
+        let worker_loaded = Arc::clone(&loaded);
+        let worker_total = Arc::clone(&total);
+        let cancel = Arc::new(AtomicBool::new(false));
+        let worker_cancel = Arc::clone(&cancel);
+        let (sender, receiver) = mpsc::channel();
+        
+        std::thread::spawn(move || {
+            let result = GitRepository::open(&worker_path).map(|mut repo| {
+                let settings = match repo.workdir() {
+                    Some(workdir) => global_config.with_repo_overrides(workdir),
+                    None => global_config,
+                };
+                repo.set_diff_algorithm(settings.git.diff_algorithm);
+                repo.set_cache_limit_bytes(cache_limit_bytes);
+                repo.set_parse_threads(parse_threads);
+                let load_error = repo
+                    .load_commits_cancellable(Some(settings.git.max_commits), &worker_cancel, |count, expected| {
+                        worker_loaded.store(count, Ordering::Relaxed);
+                        worker_total.store(expected.unwrap_or(0), Ordering::Relaxed);
+                    })
+                    .err();
+                (repo, load_error)
+            });
+            let _ = sender.send(result);
+        });
+        
+        self.pending_open = Some(PendingOpen {
+            path,
+            revision,
+            loaded,
+            total,
+            cancel,
+            receiver,
+        });
+    }
+    
+    /// Stops a repository load still running in the background, if any.
+    fn cancel_repository_open(&mut self) {
+        if let Some(pending) = self.pending_open.take() {
+            tracing::info!("Cancelling load of {}", pending.path.display());
+            pending.cancel.store(true, Ordering::Relaxed);
+            self.repository_loading = false;
+        }
+    }
+    
+    fn poll_repository_open(&mut self, ctx: &egui::Context) {
+        let Some(ref pending) = self.pending_open else {
+            return;
+        };
+        
+        let result = match pending.receiver.try_recv() {
+            Ok(result) => result,
+            Err(mpsc::TryRecvError::Empty) => {
+                ctx.request_repaint_after(std::time::Duration::from_millis(50));
+                return;
+            }
+            Err(mpsc::TryRecvError::Disconnected) => {
+                tracing::error!("Repository loader stopped without a result");
+                self.pending_open = None;
+                self.repository_loading = false;
+                return;
+            }
+        };
+        
+        let Some(PendingOpen { path, revision, .. }) = self.pending_open.take() else {
+            return;
+        };
+        self.repository_loading = false;
+        
+        match result {
+            Ok((repo, load_error)) => {
+                self.finish_repository_open(path, repo, load_error);
+                if let Some(revision) = revision {
+                    self.select_startup_revision(&revision);
+                }
+            }
+            Err(e) => {
+                tracing::error!("Failed to open repository: {}", e);
+                self.handle_error(e);
+            }
+        }
+    }
+    
+    fn finish_repository_open(&mut self, path: PathBuf, mut repo: GitRepository, load_error: Option<TwiggyError>) {
+        repo.set_ignore_whitespace(self.diff_viewer.ignore_whitespace);
+        let repo_name = repo.repository_name();
+        tracing::info!("Repository opened: {}", repo_name);
+        
+        if let Some(e) = load_error {
+            tracing::warn!("Failed to load commits: {}", e);
+            self.add_notification(
+                format!("Repository opened but failed to load commits: {}", e),
+                NotificationType::Warning,
+                Some(5)
+            );
+        } else {
+            let commit_count = repo.commit_count();
+            tracing::info!("Loaded {} commits from repository", commit_count);
+            self.add_notification(
+                format!("Repository '{}' opened with {} commits", repo_name, commit_count),
+                NotificationType::Success,
+                Some(3)
+            );
+        }
+        
+        self.config.last_opened_repository = Some(path.clone());
+        self.config.recent_repositories.add_repository(
+            path,
+            repo_name,
+        );
+        
+        if let Err(e) = self.config.save() {
+            tracing::warn!("Failed to save config: {}", e);
+        }
+        
+        self.notifications.retain(|notification| notification.action != Some(NotificationAction::ReopenRepository));
+        self.last_closed = None;
+        self.current_repository = Some(repo);
+        self.load_repo_overrides();
+        self.commit_list.clear_commit_stats();
+        self.commit_list.clear_signature_statuses();
+        self.selection_history.clear();
+        self.last_auto_fetch = Some(Instant::now());
+        self.pending_remote_fetch = None;
+        self.pending_ref_listing = None;
+        self.pending_refresh = None;
+        self.viewed_branch = None;
+        self.branch_commits.clear();
+        self.file_history = None;
+        self.refresh_ref_labels();
+        self.refresh_web_remote();
+        self.refresh_submodules();
+        self.refresh_status_bar();
+    }
+    
+    /// Looks up the hash typed into the "go to hash" box once it is long
+    /// enough, jumping straight to the commit when the prefix is unique.
+    fn go_to_commit_prefix(&mut self) {
+        let prefix = self.commit_search.trim();
+        if prefix.len() < MIN_COMMIT_PREFIX_LEN || !prefix.chars().all(|c| c.is_ascii_hexdigit()) {
+            self.commit_search_match = None;
+            return;
+        }
+        
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        
+        match repo.find_commit_by_prefix(prefix) {
+            Ok(CommitMatch::Unique(commit)) => {
+                self.commit_search_match = None;
+                self.jump_to_commit(commit.id);
+            }
+            Ok(found) => self.commit_search_match = Some(found),
+            Err(e) => {
+                self.commit_search_match = None;
+                self.handle_error(e);
+            }
+        }
+    }
+    
+    fn jump_to_commit(&mut self, commit_id: CommitId) {
+        self.reveal_commit(commit_id, &commit_id.as_str());
+    }
+    
+    /// Whether the open repository has commits for history features to
+    /// work with.
+    fn has_history(&self) -> bool {
+        self.current_repository.as_ref().is_some_and(GitRepository::has_history)
+    }
+    
+    fn open_go_to_commit(&mut self) {
+        if self.has_history() {
+            self.go_to_commit = Some(String::new());
+        }
+    }
+    
+    fn render_go_to_commit_dialog(&mut self, ctx: &egui::Context) {
+        let Some(ref mut reference) = self.go_to_commit else {
+            return;
+        };
+        
+        let mut go = false;
+        let mut cancelled = false;
+        
+        egui::Window::new("Go to Commit")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 80.0])
+            .show(ctx, |ui| {
+                ui.label("Hash, branch, tag or revision:");
+                let response = ui.add(
+                    egui::TextEdit::singleline(reference)
+                        .hint_text("e.g. 3f2a9c1, main, v1.0, HEAD~3")
+                        .desired_width(300.0),
+                );
+                if !response.has_focus() && !response.lost_focus() {
+                    response.request_focus();
+                }
+                if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                    go = true;
+                }
+                
+                ui.add_space(5.0);
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(!reference.trim().is_empty(), egui::Button::new("Go")).clicked() {
+                        go = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        cancelled = true;
+                    }
+                });
+            });
+        
+        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            cancelled = true;
+        }
+        
+        if cancelled {
+            self.go_to_commit = None;
+        } else if go {
+            if let Some(reference) = self.go_to_commit.take() {
+                self.go_to_reference(reference.trim());
+            }
+        }
+    }
+    
+    /// Resolves `reference` and selects the commit it names; several
+    /// matching hashes are offered in the commit panel header instead.
+    fn go_to_reference(&mut self, reference: &str) {
+        if reference.is_empty() {
+            return;
+        }
+        
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        
+        match repo.resolve_commit_reference(reference) {
+            Ok(CommitMatch::Unique(commit)) => self.reveal_commit(commit.id, reference),
+            Ok(CommitMatch::Ambiguous(ids)) => {
+                self.commit_search = reference.to_string();
+                self.commit_search_match = Some(CommitMatch::Ambiguous(ids));
+            }
+            Ok(CommitMatch::NotFound) => self.add_notification(
+                format!("'{}' does not name a commit, branch or tag", reference),
+                NotificationType::Error,
+                Some(5),
+            ),
+            Err(e) => self.handle_error(e),
+        }
+    }
+    
+    /// Selects `commit_id`. A commit outside the shown history is revealed by
+    /// viewing `reference` like a branch, which walks from the commit itself
+    /// rather than paging HEAD history until it shows up.
+    fn reveal_commit(&mut self, commit_id: CommitId, reference: &str) {
+        let is_displayed = |app: &Self| app.displayed_commits().iter().any(|commit| commit.id == commit_id);
+        
+        if !is_displayed(self) {
+            self.view_branch(Some(reference.to_string()));
+        }
+        
+        if is_displayed(self) {
+            tracing::info!("Jumping to commit {}", commit_id);
+            self.commit_list.scroll_to_commit(commit_id);
+        } else {
+            self.add_notification(
+                format!("{} is not in the loaded history", commit_id.short()),
+                NotificationType::Warning,
+                Some(5),
+            );
+        }
+    }
+    
+    fn select_startup_revision(&mut self, revision: &str) {
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        
+        match repo.resolve_revspec(revision) {
+            Ok(commit_id) => {
+                tracing::info!("Selecting {} ({}) at startup", revision, commit_id);
+                self.reveal_commit(commit_id, revision);
+            }
+            Err(e) => {
+                tracing::warn!("Failed to resolve startup revision {}: {}", revision, e);
+                self.add_notification(
+                    format!("Could not resolve '{}'", revision),
+                    NotificationType::Warning,
+                    Some(5),
+                );
+            }
+        }
+    }
+    
+    fn open_recent_repository(&mut self, path: std::path::PathBuf) {
+        if path.exists() {
+            self.open_repository_path(path);
+        } else {
+            tracing::warn!("Recent repository no longer exists: {}", path.display());
+            self.config.recent_repositories.remove_repository(&path);
+            
+            if let Err(e) = self.config.save() {
+                tracing::warn!("Failed to save config: {}", e);
+            }
+            
+            self.add_notification(
+                format!("Repository not found: {}", path.display()),
+                NotificationType::Warning,
+                Some(4)
+            );
+        }
+    }
+    
+    fn request_close_repository(&mut self) {
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        
+        match repo.validate() {
+            Ok(RepositoryHealth::InOperation(operation)) => {
+                tracing::info!("Repository has a {} in progress, asking before closing", operation);
+                self.pending_close_confirmation = Some(operation);
+            }
+            _ => self.close_repository(),
+        }
+    }
+    
+    fn render_close_confirmation(&mut self, ctx: &egui::Context) {
+        let Some(operation) = self.pending_close_confirmation.clone() else {
+            return;
+        };
+        
+        let mut confirmed = false;
+        let mut cancelled = false;
+        
+        egui::Window::new("Close Repository")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(format!("A {} is in progress in this repository.", operation));
+                ui.label("Close it anyway?");
+                ui.add_space(10.0);
+                
+                ui.horizontal(|ui| {
+                    if ui.button("Close").clicked() {
+                        confirmed = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        cancelled = true;
+                    }
+                });
+            });
+        
+        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            cancelled = true;
+        }
+        
+        if confirmed {
+            self.pending_close_confirmation = None;
+            self.close_repository();
+        } else if cancelled {
+            self.pending_close_confirmation = None;
+        }
+    }
+    
+    fn render_splash(&mut self, ctx: &egui::Context) {
+        if self.splash_dismissed {
+            return;
+        }
+        
+        let started = *self.splash_started.get_or_insert_with(Instant::now);
+        let timeout = std::time::Duration::from_millis(self.settings().ui.splash_timeout_ms);
+        let elapsed = started.elapsed();
+        
+        if !self.settings().ui.show_splash || elapsed >= timeout || self.current_repository.is_some() {
+            tracing::debug!("Splash dismissed after {:?}", elapsed);
+            self.splash_dismissed = true;
+            return;
+        }
+        
+        let screen_rect = ctx.screen_rect();
+        let response = egui::Area::new("splash")
+            .order(egui::Order::Foreground)
+            .fixed_pos(screen_rect.min)
+            .show(ctx, |ui| {
+                let (rect, response) = ui.allocate_exact_size(screen_rect.size(), egui::Sense::click());
+                ui.painter().rect_filled(rect, 0.0, ui.visuals().panel_fill);
+                
+                ui.allocate_ui_at_rect(rect, |ui| {
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(rect.height() * 0.35);
+                        ui.heading(egui::RichText::new("🌿 Twiggy").size(40.0));
+                        ui.add_space(10.0);
                         ui.label("Lightning-fast Git Visualization Tool");
                         ui.add_space(20.0);
-                        
-                        ui.separator();
+                        ui.add(egui::Spinner::new().size(24.0));
                         ui.add_space(10.0);
-                        
-                        ui.horizontal(|ui| {
-                            ui.label("Version:");
-                            ui.label(env!("CARGO_PKG_VERSION"));
-                        });
-                        
-                        ui.horizontal(|ui| {
-                            ui.label("Author:");
-                            ui.label("Twiggy Team");
-                        });
-                        
-                        ui.horizontal(|ui| {
-                            ui.label("Built with:");
-                            ui.label("Rust + egui");
-                        });
-                        
-                        ui.add_space(20.0);
-                        ui.separator();
+                        ui.add(
+                            egui::ProgressBar::new(elapsed.as_secs_f32() / timeout.as_secs_f32())
+                                .desired_width(200.0)
+                        );
                         ui.add_space(10.0);
-                        
-                        ui.label("Built with ❤️ using egui and Rust");
-                        ui.label("© 2024 Twiggy Team");
-                        
-                        ui.add_space(20.0);
-                        
-                        if ui.button("Close").clicked() {
-                            self.show_about = false;
+                        ui.label(egui::RichText::new("Click to skip").small().weak());
+                    });
+                });
+                
+                response
+            })
+            .inner;
+        
+        if response.clicked() {
+            self.splash_dismissed = true;
+        }
+        
+        ctx.request_repaint();
+    }
+    
+    fn start_benchmark(&mut self) {
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        
+        let path = repo.path().to_path_buf();
+        let commit_limit = self.settings().git.max_commits;
+        let (sender, receiver) = mpsc::channel();
+        
+        std::thread::spawn(move || {
+            let _ = sender.send(bench::run(&path, commit_limit));
+        });
+        
+        self.benchmark_receiver = Some(receiver);
+        self.add_notification(
+            "Benchmark started".to_string(),
+            NotificationType::Info,
+            Some(2),
+        );
+    }
+    
+    fn poll_benchmark(&mut self, ctx: &egui::Context) {
+        let Some(ref receiver) = self.benchmark_receiver else {
+            return;
+        };
+        
+        let result = match receiver.try_recv() {
+            Ok(result) => result,
+            Err(mpsc::TryRecvError::Empty) => {
+                ctx.request_repaint_after(std::time::Duration::from_millis(100));
+                return;
+            }
+            Err(mpsc::TryRecvError::Disconnected) => {
+                self.benchmark_receiver = None;
+                return;
+            }
+        };
+        
+        self.benchmark_receiver = None;
+        
+        match result {
+            Ok(report) => {
+                let render_ms = self.performance_metrics.list_render_time_ms;
+                tracing::info!("Benchmark render phase: commit list {:.1}ms", render_ms);
+                self.add_notification(
+                    format!("Benchmark: {}, render {:.1}ms", report.summary(), render_ms),
+                    NotificationType::Info,
+                    None,
+                );
+            }
+            Err(e) => {
+                tracing::error!("Benchmark failed: {}", e);
+                self.handle_error(e);
+            }
+        }
+    }
+    
+    fn is_fetching(&self) -> bool {
+        self.pending_remote_fetch.is_some() || self.pending_ref_listing.is_some()
+    }
+    
+    /// Fetches `remote`, first listing its refs on a worker thread when large
+    /// fetches need confirmation.
+    fn request_fetch(&mut self, remote: &str) {
+        if !self.settings().git.confirm_large_fetch {
+            self.run_fetch(remote, None);
+            return;
+        }
+        
+        if self.is_fetching() {
+            tracing::debug!("Fetch already in progress");
+            return;
+        }
+        
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        
+        let path = repo.path().to_path_buf();
+        let worker_path = path.clone();
+        let worker_remote = remote.to_string();
+        let (sender, receiver) = mpsc::channel();
+        
+        std::thread::spawn(move || {
+            let result = GitRepository::open(&worker_path).and_then(|repo| repo.list_remote_refs(&worker_remote));
+            let _ = sender.send(result);
+        });
+        
+        self.pending_ref_listing = Some(PendingRefListing { path, remote: remote.to_string(), receiver });
+    }
+    
+    fn poll_ref_listing(&mut self, ctx: &egui::Context) {
+        let Some(ref pending) = self.pending_ref_listing else {
+            return;
+        };
+        
+        let result = match pending.receiver.try_recv() {
+            Ok(result) => result,
+            Err(mpsc::TryRecvError::Empty) => {
+                ctx.request_repaint_after(std::time::Duration::from_millis(250));
+                return;
+            }
+            Err(mpsc::TryRecvError::Disconnected) => {
+                tracing::error!("Listing remote refs stopped without a result");
+                self.pending_ref_listing = None;
+                return;
+            }
+        };
+        
+        let Some(PendingRefListing { path, remote, .. }) = self.pending_ref_listing.take() else {
+            return;
+        };
+        
+        if self.current_repository.as_ref().map(|repo| repo.path()) != Some(path.as_path()) {
+            tracing::debug!("Ignoring remote refs for {}", path.display());
+            return;
+        }
+        
+        match result {
+            Ok(refs) if refs.len() > self.settings().git.large_fetch_threshold => {
+                self.pending_fetch = Some(FetchDialog {
+                    remote,
+                    refs: refs.into_iter().map(|name| (name, true)).collect(),
+                    filter: String::new(),
+                });
+            }
+            Ok(_) => self.run_fetch(&remote, None),
+            Err(e) if e.is_auth_error() => {
+                tracing::warn!("Listing refs of {} needs credentials: {}", remote, e);
+                self.add_notification(
+                    format!("Fetch from {} skipped: the remote requires authentication", remote),
+                    NotificationType::Warning,
+                    Some(5),
+                );
+            }
+            Err(e) => {
+                tracing::error!("Listing refs of {} failed: {}", remote, e);
+                self.handle_error(e);
+            }
+        }
+    }
+    
+    /// Fetches `refs` of `remote` (all of them when `None`) on a worker
+    /// thread; `poll_fetch` reports the outcome and refreshes.
+    fn run_fetch(&mut self, remote: &str, refs: Option<Vec<String>>) {
+        if self.pending_remote_fetch.is_some() {
+            tracing::debug!("Fetch already in progress");
+            return;
+        }
+        
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        
+        tracing::info!("Fetching from {}", remote);
+        let path = repo.path().to_path_buf();
+        let worker_path = path.clone();
+        let worker_remote = remote.to_string();
+        let (sender, receiver) = mpsc::channel();
+        
+        std::thread::spawn(move || {
+            let result = GitRepository::open(&worker_path).and_then(|repo| repo.fetch(&worker_remote, refs.as_deref()));
+            let _ = sender.send(result);
+        });
+        
+        self.pending_remote_fetch = Some(PendingFetch { path, manual: true, receiver });
+    }
+    
+    /// Starts a background fetch of the default remote once
+    /// `fetch_interval_minutes` have passed since the last one.
+    fn schedule_auto_fetch(&mut self, ctx: &egui::Context) {
+        let (auto_fetch, interval_minutes) = {
+            let git = &self.settings().git;
+            (git.auto_fetch, git.fetch_interval_minutes)
+        };
+        
+        if !auto_fetch || self.pending_remote_fetch.is_some() {
+            return;
+        }
+        
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        
+        let interval = std::time::Duration::from_secs(u64::from(interval_minutes.max(1)) * 60);
+        let elapsed = self.last_auto_fetch.map_or(interval, |last| last.elapsed());
+        if elapsed < interval {
+            ctx.request_repaint_after(interval - elapsed);
+            return;
+        }
+        
+        self.last_auto_fetch = Some(Instant::now());
+        
+        let Some(remote) = repo.default_remote() else {
+            return;
+        };
+        
+        tracing::info!("Auto-fetching from {}", remote);
+        let path = repo.path().to_path_buf();
+        let worker_path = path.clone();
+        let (sender, receiver) = mpsc::channel();
+        
+        std::thread::spawn(move || {
+            let result = GitRepository::open(&worker_path).and_then(|repo| repo.fetch_remote(&remote));
+            let _ = sender.send(result);
+        });
+        
+        self.pending_remote_fetch = Some(PendingFetch { path, manual: false, receiver });
+    }
+    
+    fn poll_fetch(&mut self, ctx: &egui::Context) {
+        let Some(ref pending) = self.pending_remote_fetch else {
+            return;
+        };
+        
+        let result = match pending.receiver.try_recv() {
+            Ok(result) => result,
+            Err(mpsc::TryRecvError::Empty) => {
+                ctx.request_repaint_after(std::time::Duration::from_millis(250));
+                return;
+            }
+            Err(mpsc::TryRecvError::Disconnected) => {
+                tracing::error!("Fetch stopped without a result");
+                self.pending_remote_fetch = None;
+                return;
+            }
+        };
+        
+        let Some(PendingFetch { path, manual, .. }) = self.pending_remote_fetch.take() else {
+            return;
+        };
+        
+        if self.current_repository.as_ref().map(|repo| repo.path()) != Some(path.as_path()) {
+            tracing::debug!("Ignoring fetch result for {}", path.display());
+            return;
+        }
+        
+        if manual {
+            self.finish_manual_fetch(result);
+            return;
+        }
+        
+        match result {
+            Ok(summary) if summary.updated_refs > 0 => {
+                self.start_refresh(false);
+                let refs = if summary.updated_refs == 1 { "ref" } else { "refs" };
+                self.add_notification(
+                    format!("Fetched from {}: {} {} updated", summary.remote, summary.updated_refs, refs),
+                    NotificationType::Info,
+                    Some(4),
+                );
+            }
+            Ok(summary) => {
+                tracing::info!("Auto-fetch from {}: already up to date", summary.remote);
+            }
+            Err(e) if e.is_auth_error() => {
+                tracing::warn!("Auto-fetch needs credentials: {}", e);
+                self.add_notification(
+                    "Auto-fetch skipped: the remote requires authentication".to_string(),
+                    NotificationType::Warning,
+                    Some(5),
+                );
+            }
+            Err(e) => {
+                tracing::warn!("Auto-fetch failed: {}", e);
+                self.add_notification(
+                    format!("Auto-fetch failed: {}", e),
+                    NotificationType::Warning,
+                    Some(5),
+                );
+            }
+        }
+    }
+    
+    fn finish_manual_fetch(&mut self, result: Result<FetchSummary>) {
+        match result {
+            Ok(summary) => {
+                self.start_refresh(false);
+                self.add_notification(
+                    format!("Fetched from {} ({} refs updated)", summary.remote, summary.updated_refs),
+                    NotificationType::Success,
+                    Some(3),
+                );
+            }
+            Err(e) if e.is_auth_error() => {
+                tracing::warn!("Fetch needs credentials: {}", e);
+                self.add_notification(
+                    "Fetch skipped: the remote requires authentication".to_string(),
+                    NotificationType::Warning,
+                    Some(5),
+                );
+            }
+            Err(e) => {
+                tracing::error!("Fetch failed: {}", e);
+                self.handle_error(e);
+            }
+        }
+    }
+    
+    fn render_fetch_dialog(&mut self, ctx: &egui::Context) {
+        let Some(ref mut dialog) = self.pending_fetch else {
+            return;
+        };
+        
+        let mut fetch_refs: Option<Option<Vec<String>>> = None;
+        let mut cancelled = false;
+        
+        egui::Window::new("Fetch")
+            .collapsible(false)
+            .resizable(true)
+            .default_width(450.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(format!("{} has {} refs; fetch all?", dialog.remote, dialog.refs.len()));
+                ui.add_space(5.0);
+                
+                ui.horizontal(|ui| {
+                    ui.label("Filter:");
+                    ui.text_edit_singleline(&mut dialog.filter);
+                    
+                    let filter = dialog.filter.to_lowercase();
+                    if ui.small_button("All").clicked() {
+                        dialog.refs.iter_mut()
+                            .filter(|(name, _)| name.to_lowercase().contains(&filter))
+                            .for_each(|(_, selected)| *selected = true);
+                    }
+                    if ui.small_button("None").clicked() {
+                        dialog.refs.iter_mut()
+                            .filter(|(name, _)| name.to_lowercase().contains(&filter))
+                            .for_each(|(_, selected)| *selected = false);
+                    }
+                });
+                
+                ui.separator();
+                
+                let filter = dialog.filter.to_lowercase();
+                egui::ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                    for (name, selected) in dialog.refs.iter_mut() {
+                        if name.to_lowercase().contains(&filter) {
+                            ui.checkbox(selected, name.as_str());
                         }
-                    });
+                    }
+                });
+                
+                ui.separator();
+                
+                let selected_count = dialog.refs.iter().filter(|(_, selected)| *selected).count();
+                ui.horizontal(|ui| {
+                    if ui.button("Fetch All").clicked() {
+                        fetch_refs = Some(None);
+                    }
+                    
+                    if ui.add_enabled(selected_count > 0, egui::Button::new(format!("Fetch Selected ({})", selected_count))).clicked() {
+                        let selected = dialog.refs.iter()
+                            .filter(|(_, selected)| *selected)
+                            .map(|(name, _)| name.clone())
+                            .collect();
+                        fetch_refs = Some(Some(selected));
+                    }
+                    
+                    if ui.button("Cancel").clicked() {
+                        cancelled = true;
+                    }
                 });
+            });
+        
+        if let Some(refs) = fetch_refs {
+            if let Some(dialog) = self.pending_fetch.take() {
+                self.run_fetch(&dialog.remote, refs);
+            }
+        } else if cancelled {
+            self.pending_fetch = None;
         }
-
-        if self.show_shortcuts {
-            egui::Window::new("Keyboard Shortcuts")
-                .collapsible(false)
-                .resizable(true)
-                .default_size([500.0, 400.0])
-                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
-                .show(ctx, |ui| {
-                    ui.vertical(|ui| {
-                        ui.heading("Application Shortcuts");
-                        ui.add_space(10.0);
-                        
-                        egui::Grid::new("shortcuts_grid")
-                            .num_columns(2)
-                            .spacing([40.0, 4.0])
-                            .striped(true)
-                            .show(ui, |ui| {
-                                ui.label("Action");
-                                ui.label("Shortcut");
-                                ui.end_row();
-                                
-                                ui.separator();
-                                ui.separator();
-                                ui.end_row();
-                                
-                                ui.label("Open Repository");
-                                ui.label("Ctrl+O");
-                                ui.end_row();
-                                
-                                ui.label("Settings");
-                                ui.label("Ctrl+,");
-                                ui.end_row();
-                                
-                                ui.label("Exit Application");
-                                ui.label("Ctrl+Q");
-                                ui.end_row();
-                                
-                                ui.label("Show Shortcuts");
-                                ui.label("F1");
-                                ui.end_row();
-                                
-                                ui.label("Toggle Menu Bar");
-                                ui.label("F10");
-                                ui.end_row();
-                                
-                                ui.label("Close Dialog");
-                                ui.label("Escape");
-                                ui.end_row();
-                                
-                                ui.separator();
-                                ui.separator();
-                                ui.end_row();
+    }
+    
+    fn open_repo_config_editor(&mut self) {
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        
+        let mut edits: Vec<ConfigEntryEdit> = ["user.name", "user.email", "core.autocrlf"]
+            .iter()
+            .map(|key| ConfigEntryEdit {
+                key: key.to_string(),
+                original: String::new(),
+                value: String::new(),
+                inherited: repo.get_effective_config_value(key),
+                multivar: false,
+            })
+            .collect();
+        
+        match repo.get_config_entries(None) {
+            Ok(entries) => {
+                let mut seen: HashSet<String> = HashSet::new();
+                for (key, value) in entries {
+                    let repeated = !seen.insert(key.clone());
+                    if let Some(edit) = edits.iter_mut().find(|edit| edit.key == key) {
+                        if repeated {
+                            edit.multivar = true;
+                            edit.original = format!("{}\n{}", edit.original, value);
+                        } else {
+                            edit.original = value;
+                        }
+                        edit.value = edit.original.clone();
+                    } else if key.starts_with("remote.") || key.starts_with("user.") || key.starts_with("core.") {
+                        edits.push(ConfigEntryEdit {
+                            key,
+                            original: value.clone(),
+                            value,
+                            inherited: None,
+                            multivar: false,
+                        });
+                    }
+                }
+            }
+            Err(e) => {
+                self.handle_error(e);
+                return;
+            }
+        }
+        
+        self.new_config_entry = (String::new(), String::new());
+        self.repo_config_edits = Some(edits);
+    }
+    
+    fn render_repo_config_editor(&mut self, ctx: &egui::Context) {
+        let Some(ref mut edits) = self.repo_config_edits else {
+            return;
+        };
+        
+        let mut apply = false;
+        let mut close = false;
+        let (new_key, new_value) = &mut self.new_config_entry;
+        
+        egui::Window::new("Repository Git Config")
+            .collapsible(false)
+            .resizable(true)
+            .default_width(500.0)
+            .show(ctx, |ui| {
+                ui.label("Values are written to this repository's .git/config. Leave a field empty to unset it.");
+                ui.add_space(10.0);
+                
+                egui::ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                    egui::Grid::new("repo_config_grid")
+                        .num_columns(2)
+                        .spacing([20.0, 4.0])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for edit in edits.iter_mut() {
+                                let label = if edit.value != edit.original {
+                                    egui::RichText::new(format!("{} *", edit.key)).strong()
+                                } else {
+                                    egui::RichText::new(&edit.key)
+                                };
+                                ui.label(label);
                                 
-                                ui.label("Copy");
-                                ui.label("Ctrl+C");
-                                ui.end_row();
+                                if edit.multivar {
+                                    ui.label(egui::RichText::new(&edit.value).weak())
+                                        .on_hover_text("This key has several values; edit it with git config");
+                                    ui.end_row();
+                                    continue;
+                                }
                                 
-                                ui.label("Select All");
-                                ui.label("Ctrl+A");
+                                let mut text_edit = egui::TextEdit::singleline(&mut edit.value)
+                                    .desired_width(280.0);
+                                if let Some(ref inherited) = edit.inherited {
+                                    text_edit = text_edit.hint_text(format!("inherited: {}", inherited));
+                                }
+                                ui.add(text_edit);
                                 ui.end_row();
-                            });
-                        
-                        ui.add_space(20.0);
-                        
-                        ui.horizontal(|ui| {
-                            if ui.button("Close").clicked() {
-                                self.show_shortcuts = false;
                             }
                         });
-                    });
                 });
+                
+                ui.add_space(10.0);
+                
+                ui.horizontal(|ui| {
+                    ui.label("Add:");
+                    ui.add(egui::TextEdit::singleline(new_key).hint_text("section.name").desired_width(160.0));
+                    ui.add(egui::TextEdit::singleline(new_value).hint_text("value").desired_width(160.0));
+                    
+                    let can_add = !new_key.trim().is_empty() && !edits.iter().any(|edit| edit.key == new_key.trim());
+                    if ui.add_enabled(can_add, egui::Button::new("➕")).clicked() {
+                        edits.push(ConfigEntryEdit {
+                            key: new_key.trim().to_string(),
+                            original: String::new(),
+                            value: std::mem::take(new_value),
+                            inherited: None,
+                            multivar: false,
+                        });
+                        new_key.clear();
+                    }
+                });
+                
+                ui.separator();
+                
+                ui.horizontal(|ui| {
+                    let has_changes = edits.iter().any(|edit| edit.value != edit.original);
+                    if ui.add_enabled(has_changes, egui::Button::new("Apply")).clicked() {
+                        apply = true;
+                    }
+                    
+                    if ui.button("Cancel").clicked() {
+                        close = true;
+                    }
+                });
+            });
+        
+        if apply {
+            self.apply_repo_config_edits();
+        } else if close {
+            self.repo_config_edits = None;
+        }
+    }
+    
+    fn apply_repo_config_edits(&mut self) {
+        let (Some(ref repo), Some(ref edits)) = (&self.current_repository, &self.repo_config_edits) else {
+            return;
+        };
+        
+        let changed: Vec<&ConfigEntryEdit> = edits.iter().filter(|edit| edit.value != edit.original).collect();
+        
+        let validation = changed
+            .iter()
+            .try_for_each(|edit| validate_config_entry(&edit.key, edit.value.trim()));
+        if let Err(e) = validation {
+            self.add_notification(
+                format!("Invalid config value: {}", e),
+                NotificationType::Error,
+                Some(5),
+            );
+            return;
+        }
+        
+        let result = changed
+            .iter()
+            .try_for_each(|edit| repo.set_config_entry(&edit.key, edit.value.trim()));
+        let count = changed.len();
+        
+        match result {
+            Ok(()) => {
+                self.repo_config_edits = None;
+                self.add_notification(
+                    format!("Updated {} config value(s)", count),
+                    NotificationType::Success,
+                    Some(3),
+                );
+            }
+            Err(e) => self.handle_error(e),
+        }
+    }
+    
+    fn close_repository(&mut self) {
+        self.cancel_repository_open();
+        if let Some(repo) = self.current_repository.take() {
+            tracing::info!("Closing repository: {}", repo.repository_name());
+            self.last_closed = Some(repo);
+            self.pending_checkout = None;
+            self.repo_config_edits = None;
+            self.pending_fetch = None;
+            self.web_remote_url = None;
+            self.remotes.clear();
+            self.submodules.clear();
+            self.commit_list.set_open_on_web_enabled(false);
+            self.commit_list.set_ref_labels(Default::default());
+            self.pickaxe_search.reset();
+            self.commit_search.clear();
+            self.commit_search_match = None;
+            self.go_to_commit = None;
+            self.commit_list.clear_filter();
+            self.file_tree_entries = None;
+            self.blame_view.close();
+            self.file_viewer.close();
+            self.commit_list.clear_commit_stats();
+            self.commit_list.clear_signature_statuses();
+            self.selection_history.clear();
+            self.last_auto_fetch = None;
+            self.pending_remote_fetch = None;
+            self.pending_ref_listing = None;
+            self.pending_refresh = None;
+            self.repo_overrides = None;
+            self.repo_settings = None;
+            self.status_bar.update(None);
+            self.viewed_branch = None;
+            self.branch_commits.clear();
+            self.file_history = None;
+            self.local_branches.clear();
+            self.remote_branches.clear();
+            
+            self.notifications.retain(|notification| notification.action != Some(NotificationAction::ReopenRepository));
+            self.add_notification_with_action(
+                "Repository closed — Reopen?".to_string(),
+                NotificationType::Info,
+                Some(6),
+                Some(NotificationAction::ReopenRepository),
+            );
+        }
+    }
+    
+    fn settings(&self) -> &AppConfig {
+        self.repo_settings.as_ref().unwrap_or(&self.config)
+    }
+    
+    /// Reads the open repository's `.twiggy.json`. A malformed file is
+    /// reported and skipped.
+    fn load_repo_overrides(&mut self) {
+        let workdir = self.current_repository.as_ref().and_then(|repo| repo.workdir().map(Path::to_path_buf));
+        
+        self.repo_overrides = match workdir.map(|dir| RepoOverrides::load(&dir)) {
+            Some(Ok(overrides)) => overrides,
+            Some(Err(e)) => {
+                self.add_notification(
+                    format!("Ignoring repository settings: {}", e),
+                    NotificationType::Warning,
+                    Some(5),
+                );
+                None
+            }
+            None => None,
+        };
+        
+        self.refresh_repo_settings();
+    }
+    
+    fn refresh_repo_settings(&mut self) {
+        let Some(ref overrides) = self.repo_overrides else {
+            self.repo_settings = None;
+            return;
+        };
+        
+        match self.config.with_overrides(overrides) {
+            Ok(settings) => {
+                tracing::info!("Using repository settings overrides");
+                self.repo_settings = Some(settings);
+            }
+            Err(e) => {
+                self.repo_overrides = None;
+                self.repo_settings = None;
+                self.add_notification(
+                    format!("Ignoring repository settings: {}", e),
+                    NotificationType::Warning,
+                    Some(5),
+                );
+            }
+        }
+    }
+    
+    fn reopen_closed_repository(&mut self) {
+        let Some(repo) = self.last_closed.take() else {
+            return;
+        };
+        
+        if self.current_repository.is_some() {
+            tracing::info!("Another repository is open, not reopening {}", repo.repository_name());
+            return;
+        }
+        
+        tracing::info!("Reopening repository: {}", repo.repository_name());
+        self.current_repository = Some(repo);
+        self.load_repo_overrides();
+        self.last_branch_refresh = None;
+        self.refresh_ref_labels();
+        self.refresh_web_remote();
+        self.refresh_submodules();
+    }
+    
+    /// Appends the next page of history once the list has shown its
+    /// "Loading more…" row.
+    fn load_more_commits(&mut self) {
+        const COMMIT_PAGE_SIZE: usize = 500;
+        
+        self.commit_list.set_loading_more(false);
+        let Some(ref mut repo) = self.current_repository else {
+            return;
+        };
+        
+        if let Err(e) = repo.load_more_commits(COMMIT_PAGE_SIZE) {
+            tracing::error!("Failed to load more commits: {}", e);
+            self.handle_error(e);
+        }
+    }
+    
+    fn displayed_commits(&self) -> Vec<Commit> {
+        let Some(ref repo) = self.current_repository else {
+            return Vec::new();
+        };
+        
+        let commits = if let Some((_, ref commits)) = self.file_history {
+            commits.clone()
+        } else if self.viewed_branch.is_some() {
+            self.branch_commits.clone()
+        } else if self.settings().git.show_stashes {
+            merge_stashes(repo.get_commits(), &self.stash_commits)
+        } else {
+            repo.get_commits().to_vec()
+        };
+        
+        if self.settings().ui.hide_merge_commits {
+            hide_merge_commits(&commits)
+        } else {
+            commits
+        }
+    }
+    
+    fn refresh_submodules(&mut self) {
+        self.submodules = match self.current_repository.as_ref().map(|repo| repo.list_submodules()) {
+            Some(Ok(submodules)) => submodules,
+            Some(Err(e)) => {
+                tracing::warn!("Failed to list submodules: {}", e);
+                Vec::new()
+            }
+            None => Vec::new(),
+        };
+    }
+    
+    fn refresh_web_remote(&mut self) {
+        self.remotes = match self.current_repository.as_ref().map(|repo| repo.list_remotes()) {
+            Some(Ok(remotes)) => remotes,
+            Some(Err(e)) => {
+                tracing::warn!("Failed to list remotes: {}", e);
+                Vec::new()
+            }
+            None => Vec::new(),
+        };
+        
+        let mut remotes: Vec<&RemoteInfo> = self.remotes.iter().collect();
+        if let Some(origin) = remotes.iter().position(|remote| remote.name == "origin") {
+            remotes.swap(0, origin);
+        }
+        
+        self.web_remote_url = remotes
+            .iter()
+            .filter_map(|remote| remote.fetch_url.clone())
+            .find(|url| web_url::repository_url(url).is_some());
+        
+        self.commit_list.set_open_on_web_enabled(self.web_remote_url.is_some());
+    }
+    
+    fn open_commit_on_web(&mut self, commit_id: CommitId) {
+        let Some(url) = self.web_remote_url.as_deref().and_then(|remote| web_url::commit_url(remote, &commit_id.as_str())) else {
+            return;
+        };
+        
+        if let Err(e) = open_url(&url) {
+            self.handle_error(e);
+        }
+    }
+    
+    fn refresh_ref_labels(&mut self) {
+        let mut labels = match self.current_repository {
+            Some(ref repo) => match repo.ref_labels() {
+                Ok(labels) => labels,
+                Err(e) => {
+                    tracing::warn!("Failed to load ref labels: {}", e);
+                    Default::default()
+                }
+            },
+            None => Default::default(),
+        };
+        
+        self.stash_commits.clear();
+        if self.settings().git.show_stashes {
+            if let Some(ref mut repo) = self.current_repository {
+                match repo.list_stashes() {
+                    Ok(stashes) => {
+                        for stash in stashes {
+                            labels.entry(stash.id).or_default().push(RefLabel {
+                                name: stash.reference_name(),
+                                kind: RefKind::Stash,
+                            });
+                            
+                            match repo.find_commit_by_hash(&stash.id.as_str()) {
+                                Ok(Some(commit)) => self.stash_commits.push(commit),
+                                Ok(None) => tracing::warn!("Stash commit {} not found", stash.id),
+                                Err(e) => tracing::warn!("Failed to read stash {}: {}", stash.reference_name(), e),
+                            }
+                        }
+                    }
+                    Err(e) => tracing::warn!("Failed to list stashes: {}", e),
+                }
+            }
+        }
+        
+        self.commit_graph.set_ref_labels(&labels);
+        self.commit_list.set_ref_labels(labels);
+        
+        let tag_messages = match self.current_repository {
+            Some(ref repo) => repo.list_tags()
+                .unwrap_or_else(|e| {
+                    tracing::warn!("Failed to list tags: {}", e);
+                    Vec::new()
+                })
+                .into_iter()
+                .filter_map(|tag| tag.message.map(|message| (tag.name, message)))
+                .collect(),
+            None => Default::default(),
+        };
+        self.commit_list.set_tag_messages(tag_messages);
+        
+        let list = |filter| match self.current_repository {
+            Some(ref repo) => repo.list_branches(filter).unwrap_or_else(|e| {
+                tracing::warn!("Failed to list branches: {}", e);
+                Vec::new()
+            }),
+            None => Vec::new(),
+        };
+        self.local_branches = list(BranchFilter::Local);
+        self.remote_branches = list(BranchFilter::Remote);
+    }
+    
+    fn view_branch(&mut self, branch_name: Option<String>) {
+        let max_commits = self.settings().git.max_commits;
+        let Some(ref mut repo) = self.current_repository else {
+            return;
+        };
+        
+        self.file_history = None;
+        let Some(name) = branch_name else {
+            tracing::info!("Showing commits from HEAD");
+            self.viewed_branch = None;
+            self.branch_commits.clear();
+            return;
+        };
+        
+        match repo.load_commits_for_branch(&name, Some(max_commits)) {
+            Ok(commits) => {
+                tracing::info!("Showing {} commits from branch {}", commits.len(), name);
+                self.branch_commits = commits;
+                self.viewed_branch = Some(name);
+            }
+            Err(e) => {
+                self.viewed_branch = None;
+                self.branch_commits.clear();
+                self.handle_error(e);
+            }
+        }
+    }
+    
+    fn refresh_repository(&mut self) {
+        self.start_refresh(true);
+    }
+    
+    /// Reloads branch info and commits on a worker thread; the result is
+    /// swapped in by `poll_refresh`. Loads at least as many commits as are
+    /// paged in now. Does nothing while a refresh is running.
+    fn start_refresh(&mut self, announce: bool) {
+        if self.pending_refresh.is_some() {
+            tracing::debug!("Refresh already in progress");
+            return;
         }
+        
+        let Some(ref repo) = self.current_repository else {
+            return;
+        };
+        
+        tracing::info!("Refreshing repository: {}", repo.repository_name());
+        let path = repo.path().to_path_buf();
+        let worker_path = path.clone();
+        let settings = self.settings();
+        let load_limit = settings.git.max_commits.max(repo.commit_count());
+        let diff_algorithm = settings.git.diff_algorithm;
+        let cache_limit_bytes = self.config.performance.cache_size_mb * 1024 * 1024;
+        let parse_threads = self.config.performance.commit_parse_threads();
+        let (sender, receiver) = mpsc::channel();
+        
+        std::thread::spawn(move || {
+            let result = GitRepository::open(&worker_path).and_then(|mut repo| {
+                repo.set_diff_algorithm(diff_algorithm);
+                repo.set_cache_limit_bytes(cache_limit_bytes);
+                repo.set_parse_threads(parse_threads);
+                repo.refresh()?;
+                repo.load_commits(Some(load_limit))?;
+                Ok(repo)
+            });
+            let _ = sender.send(result);
+        });
+        
+        self.pending_refresh = Some(PendingRefresh { path, announce, receiver });
     }
-
-    fn open_repository(&mut self) {
-        tracing::info!("Repository opening requested");
+    
+    fn poll_refresh(&mut self, ctx: &egui::Context) {
+        let Some(ref pending) = self.pending_refresh else {
+            return;
+        };
         
-        let mut dialog = rfd::FileDialog::new()
-            .set_title("Open Git Repository - Select folder containing .git");
+        let result = match pending.receiver.try_recv() {
+            Ok(result) => result,
+            Err(mpsc::TryRecvError::Empty) => {
+                ctx.request_repaint_after(std::time::Duration::from_millis(50));
+                return;
+            }
+            Err(mpsc::TryRecvError::Disconnected) => {
+                tracing::error!("Refresh worker stopped without a result");
+                self.pending_refresh = None;
+                return;
+            }
+        };
         
-        let default_dir = self.get_default_directory_for_dialog();
-        if let Some(dir) = default_dir {
-            dialog = dialog.set_directory(dir);
+        let Some(PendingRefresh { path, announce, .. }) = self.pending_refresh.take() else {
+            return;
+        };
+        
+        if self.current_repository.as_ref().map(|repo| repo.path()) != Some(path.as_path()) {
+            tracing::debug!("Ignoring refresh result for {}", path.display());
+            return;
         }
         
-        if let Some(path) = dialog.pick_folder() {
-            self.open_repository_path(path);
+        match result {
+            Ok(repo) => self.finish_refresh(repo, announce),
+            Err(e) => self.handle_error(e),
         }
     }
     
-    fn get_default_directory_for_dialog(&self) -> Option<PathBuf> {
-        if let Some(last_repo) = self.config.recent_repositories.repositories.first() {
-            if last_repo.path.exists() {
-                return Some(last_repo.path.clone());
-            }
-            if let Some(parent) = last_repo.path.parent() {
-                if parent.exists() {
-                    return Some(parent.to_path_buf());
-                }
-            }
+    /// Swaps in a reloaded repository, keeping the selected commit if it is
+    /// still in the shown history and reporting how many commits are new. Cached diffs,
+    /// stats and descriptions carry over from the old handle.
+    fn finish_refresh(&mut self, mut repo: GitRepository, announce: bool) {
+        repo.set_ignore_whitespace(self.diff_viewer.ignore_whitespace);
+        
+        let previous: HashSet<CommitId> = self.current_repository
+            .as_ref()
+            .map(|old| old.get_commits().iter().map(|commit| commit.id).collect())
+            .unwrap_or_default();
+        let new_commits = repo.get_commits().iter().filter(|commit| !previous.contains(&commit.id)).count();
+        
+        let viewed_branch = self.viewed_branch.as_deref();
+        let selection_lost = self.commit_list.selected_commit().is_some_and(|selected| {
+            !repo.is_in_history(&selected, viewed_branch)
+                && !self.stash_commits.iter().any(|stash| stash.id == selected)
+        });
+        if selection_lost {
+            tracing::info!("Selected commit is no longer in the history, clearing selection");
+            self.commit_list.clear_selection();
         }
         
-        if let Ok(home_dir) = std::env::var("USERPROFILE").or_else(|_| std::env::var("HOME")) {
-            let home_path = PathBuf::from(home_dir);
-            if home_path.exists() {
-                return Some(home_path);
-            }
+        if let Some(old) = self.current_repository.take() {
+            repo.take_caches_from(old);
+        }
+        self.current_repository = Some(repo);
+        self.last_branch_refresh = Some(Instant::now());
+        self.refresh_ref_labels();
+        self.refresh_web_remote();
+        self.refresh_submodules();
+        self.refresh_status_bar();
+        let file_history = self.file_history.take().map(|(path, _)| path);
+        if self.viewed_branch.is_some() {
+            let viewed_branch = self.viewed_branch.clone();
+            self.view_branch(viewed_branch);
+        }
+        if let Some(path) = file_history {
+            self.show_file_history(path);
         }
         
-        None
+        if !announce && new_commits == 0 && !selection_lost {
+            return;
+        }
+        
+        let mut summary = match new_commits {
+            0 => "No new commits".to_string(),
+            1 => "1 new commit".to_string(),
+            n => format!("{} new commits", n),
+        };
+        if selection_lost {
+            summary.push_str("; the selected commit was rewritten or removed");
+        }
+        self.add_notification(summary, NotificationType::Info, Some(3));
     }
     
-    fn open_repository_path(&mut self, path: std::path::PathBuf) {
-        if !path.exists() {
-            tracing::error!("Repository path does not exist: {}", path.display());
-            self.show_error_message(format!("Path does not exist: {}", path.display()));
+    fn refresh_on_focus_if_needed(&mut self, ctx: &egui::Context) {
+        let Some(focused) = ctx.input(|i| i.viewport().focused) else {
+            return;
+        };
+        
+        let gained_focus = focused && self.window_focused == Some(false);
+        self.window_focused = Some(focused);
+        
+        if !gained_focus || !self.settings().git.refresh_on_focus || self.current_repository.is_none() {
             return;
         }
         
-        if !path.is_dir() {
-            tracing::error!("Repository path is not a directory: {}", path.display());
-            self.show_error_message(format!("Path is not a directory: {}", path.display()));
+        let debounce = std::time::Duration::from_secs(2);
+        if self.last_focus_refresh.is_some_and(|last| last.elapsed() < debounce) {
+            tracing::debug!("Skipping focus refresh, last one was too recent");
             return;
         }
         
-        self.repository_loading = true;
+        self.last_focus_refresh = Some(Instant::now());
+        self.start_refresh(false);
+    }
+    
+    /// Reopens `last_opened_repository` if it is still a repository;
+    /// otherwise forgets it. Returns whether an open was started.
+    fn reopen_last_repository(&mut self) -> bool {
+        let Some(path) = self.config.last_opened_repository.clone() else {
+            return false;
+        };
         
-        match GitRepository::open(&path) {
-            Ok(mut repo) => {
-                let repo_name = repo.repository_name();
-                tracing::info!("Repository opened: {}", repo_name);
-                
-                if let Err(e) = repo.load_commits(Some(1000)) {
-                    tracing::warn!("Failed to load commits: {}", e);
-                    self.add_notification(
-                        format!("Repository opened but failed to load commits: {}", e),
-                        NotificationType::Warning,
-                        Some(5)
-                    );
-                } else {
-                    let commit_count = repo.commit_count();
-                    tracing::info!("Loaded {} commits from repository", commit_count);
-                    self.add_notification(
-                        format!("Repository '{}' opened with {} commits", repo_name, commit_count),
-                        NotificationType::Success,
-                        Some(3)
-                    );
+        if is_git_repository(&path) {
+            tracing::info!("Reopening last repository: {}", path.display());
+            self.open_repository_path(path);
+            return true;
+        }
+        
+        tracing::info!("Last repository is gone, forgetting it: {}", path.display());
+        self.config.last_opened_repository = None;
+        self.temp_config.last_opened_repository = None;
+        if let Err(e) = self.config.save() {
+            tracing::warn!("Failed to save config: {}", e);
+        }
+        false
+    }
+    
+    /// On the first frame, reopens the last repository or else looks for
+    /// one around the working directory. Discovery runs on a worker thread
+    /// so a slow filesystem can't hold up startup.
+    fn poll_cwd_repository_detection(&mut self, ctx: &egui::Context) {
+        if !self.cwd_detection_started {
+            self.cwd_detection_started = true;
+            if self.current_repository.is_some() || self.repository_loading {
+                return;
+            }
+            if self.config.git.reopen_last_on_start && self.reopen_last_repository() {
+                return;
+            }
+            if !self.config.git.auto_open_cwd_repo {
+                return;
+            }
+            
+            let (sender, receiver) = mpsc::channel();
+            std::thread::spawn(move || {
+                let found = std::env::current_dir()
+                    .ok()
+                    .and_then(|cwd| discover_repository_root(cwd).ok().flatten());
+                let _ = sender.send(found);
+            });
+            self.cwd_detection = Some(receiver);
+        }
+        
+        let Some(ref receiver) = self.cwd_detection else {
+            return;
+        };
+        
+        match receiver.try_recv() {
+            Ok(found) => {
+                self.cwd_detection = None;
+                let Some(path) = found else {
+                    return;
+                };
+                if self.current_repository.is_some() || self.repository_loading {
+                    return;
                 }
                 
-                self.config.recent_repositories.add_repository(
-                    path.clone(),
-                    repo_name.clone(),
+                tracing::info!("Found repository around working directory: {}", path.display());
+                let name = path.file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_else(|| path.display().to_string());
+                self.detected_repository = Some(path);
+                self.add_notification_with_action(
+                    format!("Repository found in the current directory: {}", name),
+                    NotificationType::Info,
+                    Some(20),
+                    Some(NotificationAction::OpenDetectedRepository),
                 );
-                
-                if let Err(e) = self.config.save() {
-                    tracing::warn!("Failed to save config: {}", e);
-                }
-                
-                self.current_repository = Some(repo);
-                self.repository_loading = false;
-            }
-            Err(e) => {
-                tracing::error!("Failed to open repository: {}", e);
-                self.repository_loading = false;
-                self.handle_error(e);
             }
+            Err(mpsc::TryRecvError::Empty) => ctx.request_repaint_after(std::time::Duration::from_millis(100)),
+            Err(mpsc::TryRecvError::Disconnected) => self.cwd_detection = None,
         }
     }
     
-    fn open_recent_repository(&mut self, path: std::path::PathBuf) {
-        if path.exists() {
-            self.open_repository_path(path);
-        } else {
-            tracing::warn!("Recent repository no longer exists: {}", path.display());
-            self.config.recent_repositories.remove_repository(&path);
-            
-            if let Err(e) = self.config.save() {
-                tracing::warn!("Failed to save config: {}", e);
-            }
-            
-            self.add_notification(
-                format!("Repository not found: {}", path.display()),
-                NotificationType::Warning,
-                Some(4)
-            );
+    /// Selects the commit a ref chip points at; a local branch other than
+    /// the current one also asks whether to check it out.
+    fn handle_ref_click(&mut self, commit_id: CommitId, label: RefLabel) {
+        tracing::info!("Ref selected: {} at {}", label.name, commit_id);
+        self.commit_list.set_selected_commit(Some(commit_id));
+        
+        let is_current = self.current_repository
+            .as_ref()
+            .is_some_and(|repo| repo.current_branch() == Some(label.name.as_str()));
+        if label.kind == RefKind::LocalBranch && !is_current {
+            self.pending_checkout = Some(label.name);
         }
     }
     
-    fn close_repository(&mut self) {
-        if let Some(ref repo) = self.current_repository {
-            tracing::info!("Closing repository: {}", repo.repository_name());
-            self.current_repository = None;
-            
-            self.add_notification(
-                "Repository closed".to_string(),
-                NotificationType::Info,
-                Some(2)
-            );
+    fn render_checkout_confirmation(&mut self, ctx: &egui::Context) {
+        let Some(branch_name) = self.pending_checkout.clone() else {
+            return;
+        };
+        
+        let mut confirmed = false;
+        let mut cancelled = false;
+        
+        egui::Window::new("Check Out Branch")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(format!("Check out branch '{}'?", branch_name));
+                ui.add_space(10.0);
+                
+                ui.horizontal(|ui| {
+                    if ui.button("Check Out").clicked() {
+                        confirmed = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        cancelled = true;
+                    }
+                });
+            });
+        
+        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            cancelled = true;
+        }
+        
+        if confirmed {
+            self.pending_checkout = None;
+            self.checkout_branch(&branch_name);
+        } else if cancelled {
+            self.pending_checkout = None;
         }
     }
     
-    fn handle_commit_list_keyboard(&mut self, ctx: &egui::Context) {
-        if let Some(ref repo) = self.current_repository {
-            let commits = repo.get_commits();
-            self.commit_list.handle_keyboard(ctx, commits);
+    fn checkout_branch(&mut self, branch_name: &str) {
+        let max_commits = self.settings().git.max_commits;
+        
+        let Some(ref mut repo) = self.current_repository else {
+            return;
+        };
+        
+        if repo.current_branch() == Some(branch_name) {
+            return;
+        }
+        
+        let result = repo.checkout_branch(branch_name)
+            .and_then(|_| repo.load_commits(Some(max_commits)));
+        
+        match result {
+            Ok(()) => {
+                self.viewed_branch = None;
+                self.branch_commits.clear();
+                self.file_history = None;
+                self.refresh_ref_labels();
+                self.refresh_status_bar();
+                self.add_notification(
+                    format!("Switched to branch '{}'", branch_name),
+                    NotificationType::Success,
+                    Some(3)
+                );
+            }
+            Err(e) => {
+                tracing::error!("Failed to check out branch {}: {}", branch_name, e);
+                self.handle_error(e);
+            }
+        }
+    }
+    
+    fn handle_commit_list_keyboard(&mut self, ctx: &egui::Context, commits: &[Commit]) {
+        if self.command_palette.open {
+            return;
         }
+        
+        self.commit_list.handle_keyboard(ctx, commits);
     }
     
-    fn render_repository_info(&self, ui: &mut egui::Ui) {
+    fn render_repository_info(&mut self, ui: &mut egui::Ui) {
+        let mut refresh = false;
         if let Some(ref repo) = self.current_repository {
             ui.group(|ui| {
                 ui.vertical(|ui| {
@@ -2074,11 +5513,60 @@ impl TwiggyApp {
                     }
                     
                     ui.label(format!("Commits: {} loaded", repo.commit_count()));
-                    ui.label(format!("Cache: {} commits", repo.cache_size()));
+                    ui.label(format!("Cache: {} commits ({:.1} MB)", repo.cache_size(), repo.cache_size_bytes() as f64 / (1024.0 * 1024.0)));
+                    
+                    egui::CollapsingHeader::new(format!("Remotes ({})", self.remotes.len()))
+                        .id_source("repository_info_remotes")
+                        .default_open(true)
+                        .show(ui, |ui| {
+                            if self.remotes.is_empty() {
+                                ui.weak("No remotes configured");
+                            }
+                            
+                            for remote in &self.remotes {
+                                ui.strong(&remote.name);
+                                let fetch_url = remote.fetch_url.as_deref().unwrap_or("(none)");
+                                ui.monospace(format!("  fetch: {}", fetch_url));
+                                if remote.push_url != remote.fetch_url {
+                                    let push_url = remote.push_url.as_deref().unwrap_or("(none)");
+                                    ui.monospace(format!("  push:  {}", push_url));
+                                }
+                            }
+                        });
+                    
+                    if !self.submodules.is_empty() {
+                        egui::CollapsingHeader::new(format!("Submodules ({})", self.submodules.len()))
+                            .id_source("repository_info_submodules")
+                            .default_open(true)
+                            .show(ui, |ui| {
+                                for submodule in &self.submodules {
+                                    ui.horizontal(|ui| {
+                                        ui.strong(&submodule.path);
+                                        match submodule.workdir_id {
+                                            None => {
+                                                ui.weak("not initialized");
+                                            }
+                                            Some(_) if submodule.is_in_sync() => {
+                                                ui.colored_label(egui::Color32::from_rgb(100, 190, 100), "in sync");
+                                            }
+                                            Some(_) => {
+                                                ui.colored_label(egui::Color32::YELLOW, "modified");
+                                            }
+                                        }
+                                    });
+                                    if let Some(ref url) = submodule.url {
+                                        ui.monospace(format!("  url:  {}", url));
+                                    }
+                                    let short = |id: Option<CommitId>| id.map(|id| id.short()).unwrap_or_else(|| "-".to_string());
+                                    ui.monospace(format!("  head: {}  checked out: {}", short(submodule.head_id), short(submodule.workdir_id)));
+                                }
+                            });
+                    }
                     
                     ui.horizontal(|ui| {
                         if ui.button("Refresh Commits").clicked() {
                             tracing::info!("Manual commit refresh requested");
+                            refresh = true;
                         }
                         
                         if ui.button("Load More").clicked() {
@@ -2095,6 +5583,10 @@ impl TwiggyApp {
         } else {
             ui.label("No repository open");
         }
+        
+        if refresh {
+            self.refresh_repository();
+        }
     }
 
     fn show_error_message(&mut self, message: String) {
@@ -2127,17 +5619,34 @@ impl TwiggyApp {
 }
 
 impl eframe::App for TwiggyApp {
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        if self.window_save.flush() {
+            self.save_window_state();
+        }
+    }
+
     fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
         log_performance("frame_update", || {
             self.update_performance_metrics();
             self.auto_save_config_if_needed();
             self.cleanup_old_notifications();
             self.refresh_branch_info_if_needed();
+            self.poll_repository_open(ctx);
+            self.poll_refresh(ctx);
+            self.poll_benchmark(ctx);
+            self.schedule_auto_fetch(ctx);
+            self.poll_ref_listing(ctx);
+            self.poll_fetch(ctx);
+            self.pickaxe_search.poll(ctx);
+            self.refresh_on_focus_if_needed(ctx);
+            self.poll_cwd_repository_detection(ctx);
             
-            self.handle_viewport_events(ctx);
-            self.detect_window_changes(ctx);
+            self.sync_window_state(ctx);
+            self.save_window_state_if_due(ctx);
+            self.update_window_title(ctx);
             
             self.apply_theme_to_context(ctx);
+            self.apply_fonts(ctx);
             
             self.handle_keyboard_shortcuts(ctx);
             self.render_menu_bar(ctx);
@@ -2147,6 +5656,38 @@ impl eframe::App for TwiggyApp {
             self.render_notifications(ctx);
             self.render_settings_dialog(ctx, frame);
             self.render_help_dialogs(ctx);
+            self.render_close_confirmation(ctx);
+            self.render_checkout_confirmation(ctx);
+            self.render_repo_config_editor(ctx);
+            self.render_fetch_dialog(ctx);
+            self.render_go_to_commit_dialog(ctx);
+            self.render_repository_info_window(ctx);
+            self.render_statistics_window(ctx);
+            self.render_notification_history(ctx);
+            self.render_splash(ctx);
+            
+            if let Some(command) = self.command_palette.render(ctx) {
+                self.run_palette_command(command);
+            }
+            
+            let pickaxe_response = self.pickaxe_search.render(ctx, self.current_repository.as_ref());
+            if let Some(id) = pickaxe_response.selected {
+                // Matches can lie far beyond the loaded page of history.
+                self.reveal_commit(id, &id.to_string());
+            }
+            
+            if let Some(parent) = self.blame_view.render(ctx).step_back {
+                self.blame_before(parent);
+            }
+            self.file_viewer.render(ctx);
+            if let Some(commit_id) = self.commit_list.selected_commit() {
+                self.selection_history.push(commit_id);
+            }
+            
+            self.render_diff_panel(ctx);
+            self.render_commit_detail_panel(ctx);
+            self.render_file_tree_panel(ctx);
+            self.render_working_status_panel(ctx);
             
             if self.show_log_viewer {
                 egui::Window::new("Log Viewer")
@@ -2170,32 +5711,204 @@ impl eframe::App for TwiggyApp {
             egui::CentralPanel::default().show(ctx, |ui| {
                 if let Some(ref repo) = self.current_repository {
                     let repo_path = repo.path().display().to_string();
-                    let commits = repo.get_commits().to_vec();
-                    
-                    drop(repo);
+                    let unborn_branch = repo.is_unborn()
+                        .then(|| repo.current_branch().unwrap_or("main").to_string());
+                    let is_empty = repo.is_empty().unwrap_or(false);
+                    let commits = self.displayed_commits();
                     
-                    self.handle_commit_list_keyboard(ctx);
+                    self.handle_commit_list_keyboard(ctx, &commits);
                     
                     ui.vertical(|ui| {
                         ui.horizontal(|ui| {
                             ui.heading("🌿 Twiggy");
+                            ui.add_space(10.0);
+                            
+                            let mut selected_branch = None;
+                            let selected_text = match self.viewed_branch {
+                                Some(ref name) => format!("🌿 {}", name),
+                                None => "🌿 HEAD".to_string(),
+                            };
+                            egui::ComboBox::from_id_source("branch_selector")
+                                .selected_text(selected_text)
+                                .width(180.0)
+                                .show_ui(ui, |ui| {
+                                    if ui.selectable_label(self.viewed_branch.is_none(), "HEAD").clicked() {
+                                        selected_branch = Some(None);
+                                    }
+                                    
+                                    for (heading, branches) in [("Local", &self.local_branches), ("Remote", &self.remote_branches)] {
+                                        if branches.is_empty() {
+                                            continue;
+                                        }
+                                        
+                                        ui.separator();
+                                        ui.label(egui::RichText::new(heading).small().weak());
+                                        for branch in branches {
+                                            let mut text = branch.name.clone();
+                                            if branch.ahead > 0 {
+                                                text.push_str(&format!("  ↑{}", branch.ahead));
+                                            }
+                                            if branch.behind > 0 {
+                                                text.push_str(&format!("  ↓{}", branch.behind));
+                                            }
+                                            
+                                            let is_selected = self.viewed_branch.as_deref() == Some(branch.name.as_str());
+                                            if ui.selectable_label(is_selected, text).clicked() {
+                                                selected_branch = Some(Some(branch.name.clone()));
+                                            }
+                                        }
+                                    }
+                                });
+                            
+                            if let Some(branch) = selected_branch {
+                                self.view_branch(branch);
+                            }
+                            
+                            let mut close_file_history = false;
+                            if let Some((ref path, _)) = self.file_history {
+                                ui.label(format!("📄 History of {}", path));
+                                close_file_history = ui.small_button("✕").on_hover_text("Show all commits").clicked();
+                            }
+                            if close_file_history {
+                                self.file_history = None;
+                            }
+                            
+                            if self.pending_refresh.is_some() {
+                                ui.spinner();
+                            } else if ui.button("⟳").on_hover_text(format!("Refresh ({})", self.config.keybinds.get(KeybindAction::Refresh))).clicked() {
+                                self.refresh_repository();
+                            }
+                            
+                            let search_response = ui.add(
+                                egui::TextEdit::singleline(&mut self.commit_search)
+                                    .hint_text("Go to hash")
+                                    .desired_width(110.0),
+                            );
+                            if search_response.changed() {
+                                self.go_to_commit_prefix();
+                            }
+                            if matches!(self.commit_search_match, Some(CommitMatch::NotFound)) {
+                                ui.label(egui::RichText::new("no match").small().weak());
+                            }
+                            
                             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                                 ui.label(format!("Repository: {}", repo_path));
                             });
                         });
                         
+                        if let Some(CommitMatch::Ambiguous(ref ids)) = self.commit_search_match {
+                            let mut chosen = None;
+                            ui.horizontal_wrapped(|ui| {
+                                ui.label(format!("{} commits match '{}':", ids.len(), self.commit_search.trim()));
+                                for id in ids {
+                                    let summary = commits.iter()
+                                        .find(|commit| commit.id == *id)
+                                        .map(|commit| commit.summary.as_str())
+                                        .unwrap_or("not loaded");
+                                    if ui.button(format!("{}  {}", &id.as_str()[..12], summary)).clicked() {
+                                        chosen = Some(*id);
+                                    }
+                                }
+                            });
+                            if let Some(id) = chosen {
+                                self.commit_search_match = None;
+                                self.jump_to_commit(id);
+                            }
+                        }
+                        
                         ui.separator();
                         ui.add_space(5.0);
                         
-                        let commit_response = self.commit_list.render(ui, &commits);
+                        if let Some(ref branch) = unborn_branch {
+                            ui.vertical_centered(|ui| {
+                                ui.add_space(50.0);
+                                if is_empty {
+                                    ui.heading("This repository has no commits yet");
+                                } else {
+                                    ui.heading(format!("No commits yet on {}", branch));
+                                }
+                                ui.add_space(10.0);
+                                ui.label(format!("Create the first commit on {}, then reload to see the history.", branch));
+                                ui.weak("Diffs, blame and statistics become available once there is a commit.");
+                                ui.add_space(10.0);
+                                if ui.button("Reload").clicked() {
+                                    self.refresh_repository();
+                                }
+                            });
+                            return;
+                        }
+                        
+                        self.load_visible_commit_stats(ctx, &commits);
+                        self.commit_list.set_max_author_length(self.settings().ui.max_author_length);
+                        self.commit_list.set_allow_multiple_expanded(self.settings().ui.allow_multiple_expanded);
+                        let has_more = self.viewed_branch.is_none()
+                            && self.file_history.is_none()
+                            && self.current_repository.as_ref().is_some_and(|repo| repo.has_more_commits());
+                        self.commit_list.set_has_more_commits(has_more);
+                        self.commit_list.render_filter(ui, &commits);
+                        ui.add_space(4.0);
+                        
+                        let render_start = Instant::now();
+                        // Filtered rows leave gaps the graph can't draw across.
+                        let commit_response = if self.settings().ui.show_commit_graph && !self.commit_list.is_filtering() {
+                            let area = ui.available_rect_before_wrap();
+                            let graph_width = self.commit_graph.desired_width(&commits).min(area.width() / 3.0);
+                            let graph_rect = egui::Rect::from_min_size(area.min, egui::vec2(graph_width, area.height()));
+                            let list_rect = egui::Rect::from_min_max(egui::pos2(graph_rect.right(), area.top()), area.max);
+                            
+                            let mut list_ui = ui.child_ui(list_rect, egui::Layout::top_down(egui::Align::Min));
+                            let commit_response = self.commit_list.render(&mut list_ui, &commits);
+                            
+                            self.commit_graph.sync_rows(self.commit_list.row_centers(), self.commit_list.scroll_offset());
+                            let mut graph_ui = ui.child_ui(graph_rect, egui::Layout::top_down(egui::Align::Min));
+                            self.commit_graph.render(&mut graph_ui, &commits, self.commit_list.get_visible_range());
+                            
+                            ui.allocate_rect(area, egui::Sense::hover());
+                            commit_response
+                        } else {
+                            self.commit_list.render(ui, &commits)
+                        };
+                        self.performance_metrics.list_render_time_ms = render_start.elapsed().as_secs_f32() * 1000.0;
+                        
+                        if commit_response.load_more {
+                            self.commit_list.set_loading_more(true);
+                            ctx.request_repaint();
+                        } else if self.commit_list.is_loading_more() {
+                            self.load_more_commits();
+                        }
                         
                         if let Some(clicked_commit) = commit_response.clicked {
                             tracing::info!("Commit selected: {}", clicked_commit);
                         }
                         
+                        if let Some((older, newer)) = commit_response.compared {
+                            tracing::info!("Comparing commits: {}..{}", older, newer);
+                        }
+                        
                         if let Some(double_clicked_commit) = commit_response.double_clicked {
                             tracing::info!("Commit double-clicked: {}", double_clicked_commit);
                         }
+                        
+                        if let Some((commit_id, label)) = commit_response.ref_clicked {
+                            self.handle_ref_click(commit_id, label);
+                        }
+                        
+                        if let Some(commit_id) = commit_response.open_on_web {
+                            self.open_commit_on_web(commit_id);
+                        }
+                        
+                        if let Some((commit_id, target)) = commit_response.copied {
+                            let what = match target {
+                                CopyTarget::FullHash => "full hash",
+                                CopyTarget::ShortHash => "short hash",
+                                CopyTarget::Summary => "summary",
+                            };
+                            self.add_notification(
+                                format!("Copied {} of {}", what, commit_id.short()),
+                                NotificationType::Info,
+                                Some(2),
+                            );
+                        }
                     });
                 } else {
                     ui.vertical_centered(|ui| {
@@ -2203,9 +5916,39 @@ impl eframe::App for TwiggyApp {
                         ui.label("Lightning-fast Git Visualization Tool");
                         ui.add_space(20.0);
                         
-                        ui.label("Phase 10: Repository Management - Ready");
-                        ui.add_space(10.0);
-                        ui.label("Open a repository from the File menu to get started");
+                        if self.repository_loading {
+                            let progress = self.pending_open.as_ref().map(|pending| {
+                                (pending.loaded.load(Ordering::Relaxed), pending.total.load(Ordering::Relaxed))
+                            });
+                            
+                            match progress {
+                                Some((loaded, total)) if total > 0 => {
+                                    ui.add(
+                                        egui::ProgressBar::new(loaded as f32 / total as f32)
+                                            .desired_width(260.0)
+                                            .text(format!("{} / {} commits", loaded, total))
+                                    );
+                                }
+                                Some((loaded, _)) if loaded > 0 => {
+                                    ui.spinner();
+                                    ui.add_space(10.0);
+                                    ui.label(format!("Loaded {} commits...", loaded));
+                                }
+                                _ => {
+                                    ui.spinner();
+                                }
+                            }
+                            ui.add_space(10.0);
+                            ui.label("Loading repository...");
+                            ui.add_space(10.0);
+                            if ui.button("Cancel").clicked() {
+                                self.cancel_repository_open();
+                            }
+                        } else {
+                            ui.label("Phase 10: Repository Management - Ready");
+                            ui.add_space(10.0);
+                            ui.label("Open a repository from the File menu to get started");
+                        }
                         
                         ui.add_space(10.0);
                         ui.separator();
@@ -0,0 +1,78 @@
+use std::path::Path;
+use std::time::{Duration, Instant};
+use crate::error::Result;
+use crate::git::repository::GitRepository;
+use crate::logging::log_performance;
+
+#[derive(Debug, Clone, Default)]
+pub struct BenchmarkReport {
+    pub open: Duration,
+    pub load_commits: Duration,
+    pub commit_count: usize,
+    pub diff: Duration,
+    pub diff_files: usize,
+}
+
+impl BenchmarkReport {
+    pub fn total(&self) -> Duration {
+        self.open + self.load_commits + self.diff
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "open {:.1}ms, load {} commits {:.1}ms, diff {} files {:.1}ms (total {:.1}ms)",
+            as_ms(self.open),
+            self.commit_count,
+            as_ms(self.load_commits),
+            self.diff_files,
+            as_ms(self.diff),
+            as_ms(self.total()),
+        )
+    }
+}
+
+fn as_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+pub fn time_open(path: &Path) -> Result<(GitRepository, Duration)> {
+    let start = Instant::now();
+    let repo = log_performance("bench_open", || GitRepository::open(path))?;
+    Ok((repo, start.elapsed()))
+}
+
+pub fn time_load_commits(repo: &mut GitRepository, limit: usize) -> Result<Duration> {
+    let start = Instant::now();
+    log_performance("bench_load_commits", || repo.load_commits(Some(limit)))?;
+    Ok(start.elapsed())
+}
+
+/// Diffs the most recent loaded commit against its first parent.
+pub fn time_diff(repo: &GitRepository) -> Result<(Duration, usize)> {
+    let Some(commit) = repo.get_commits().first() else {
+        return Ok((Duration::ZERO, 0));
+    };
+
+    let start = Instant::now();
+    let files = log_performance("bench_diff", || repo.diff_commit(&commit.id))?;
+    Ok((start.elapsed(), files.len()))
+}
+
+pub fn run(path: &Path, commit_limit: usize) -> Result<BenchmarkReport> {
+    tracing::info!("Running benchmark on {} ({} commits)", path.display(), commit_limit);
+
+    let (mut repo, open) = time_open(path)?;
+    let load_commits = time_load_commits(&mut repo, commit_limit)?;
+    let (diff, diff_files) = time_diff(&repo)?;
+
+    let report = BenchmarkReport {
+        open,
+        load_commits,
+        commit_count: repo.commit_count(),
+        diff,
+        diff_files,
+    };
+
+    tracing::info!("Benchmark finished: {}", report.summary());
+    Ok(report)
+}
\ No newline at end of file
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use directories::ProjectDirs;
 use crate::error::{Result, TwiggyError};
+use crate::git::types::DiffAlgorithm;
+use crate::util::keys::parse_shortcut;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
@@ -11,7 +14,13 @@ pub struct AppConfig {
     pub ui: UiConfig,
     pub performance: PerformanceConfig,
     pub logging: LoggingConfig,
+    #[serde(default)]
+    pub keybinds: KeybindConfig,
     pub recent_repositories: RecentRepositories,
+    /// Repository open when Twiggy last ran, reopened on start when
+    /// `git.reopen_last_on_start` is set.
+    #[serde(default)]
+    pub last_opened_repository: Option<PathBuf>,
     #[serde(default = "default_version")]
     pub version: u32,
 }
@@ -32,8 +41,118 @@ pub struct ThemeConfig {
     pub font_size: f32,
     pub dark_mode: bool,
     pub accent_color: String,
+    /// Zoom factor for the whole interface, spacing and icons included.
+    #[serde(default = "default_ui_scale")]
+    pub ui_scale: f32,
+    /// Path of a TTF/OTF file used in place of the built-in font.
+    #[serde(default)]
+    pub font_family: Option<String>,
+    #[serde(default = "default_custom_themes")]
+    pub custom_themes: Vec<CustomTheme>,
+    /// Name of the entry in `custom_themes` applied over the base theme.
+    #[serde(default)]
+    pub custom_theme: Option<String>,
 }
 
+pub const DEFAULT_ACCENT_COLOR: &str = "#007ACC";
+
+/// Parses `#RRGGBB` (the `#` is optional).
+pub fn parse_hex_color(hex: &str) -> std::result::Result<egui::Color32, String> {
+    let hex = hex.trim_start_matches('#');
+    if hex.len() != 6 {
+        return Err("Invalid hex color length".to_string());
+    }
+    if !hex.is_ascii() {
+        return Err("Invalid hex digits".to_string());
+    }
+    
+    let r = u8::from_str_radix(&hex[0..2], 16).map_err(|_| "Invalid red component".to_string())?;
+    let g = u8::from_str_radix(&hex[2..4], 16).map_err(|_| "Invalid green component".to_string())?;
+    let b = u8::from_str_radix(&hex[4..6], 16).map_err(|_| "Invalid blue component".to_string())?;
+    
+    Ok(egui::Color32::from_rgb(r, g, b))
+}
+
+impl ThemeConfig {
+    pub fn validate_accent_color(&self) -> Result<()> {
+        parse_hex_color(&self.accent_color)
+            .map(|_| ())
+            .map_err(|message| TwiggyError::Validation {
+                field: "theme.accent_color".to_string(),
+                message: format!("\"{}\" is not a #RRGGBB color: {}", self.accent_color, message),
+            })
+    }
+
+    /// Replaces an unparseable accent colour with the default, logging the
+    /// validation error. Returns whether anything was reset.
+    pub fn reset_invalid_accent_color(&mut self) -> bool {
+        match self.validate_accent_color() {
+            Ok(()) => false,
+            Err(e) => {
+                tracing::warn!("{}; resetting to {}", e, DEFAULT_ACCENT_COLOR);
+                self.accent_color = DEFAULT_ACCENT_COLOR.to_string();
+                true
+            }
+        }
+    }
+
+    pub fn active_custom_theme(&self) -> Option<&CustomTheme> {
+        let name = self.custom_theme.as_ref()?;
+        self.custom_themes.iter().find(|theme| &theme.name == name)
+    }
+}
+
+/// A named set of colour overrides laid over the light or dark base visuals.
+/// Colours are `#RRGGBB` like `accent_color`; unset ones keep the base value.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct CustomTheme {
+    pub name: String,
+    #[serde(default)]
+    pub background: Option<String>,
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub selection: Option<String>,
+    #[serde(default)]
+    pub warning: Option<String>,
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+impl CustomTheme {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "High Contrast".to_string(),
+            background: Some("#000000".to_string()),
+            text: Some("#FFFFFF".to_string()),
+            selection: Some("#1AEBFF".to_string()),
+            warning: Some("#FFFF00".to_string()),
+            error: Some("#FF6E6E".to_string()),
+        }
+    }
+
+    /// The overridable colours with their labels, in display order.
+    pub fn colors_mut(&mut self) -> [(&'static str, &mut Option<String>); 5] {
+        [
+            ("Background", &mut self.background),
+            ("Text", &mut self.text),
+            ("Selection", &mut self.selection),
+            ("Warning", &mut self.warning),
+            ("Error", &mut self.error),
+        ]
+    }
+}
+
+pub const MIN_UI_SCALE: f32 = 0.5;
+pub const MAX_UI_SCALE: f32 = 3.0;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ThemeType {
     Light,
@@ -49,6 +168,20 @@ pub struct GitConfig {
     pub auto_fetch: bool,
     pub show_stashes: bool,
     pub fetch_interval_minutes: u32,
+    #[serde(default = "default_true")]
+    pub confirm_large_fetch: bool,
+    #[serde(default = "default_large_fetch_threshold")]
+    pub large_fetch_threshold: usize,
+    #[serde(default)]
+    pub diff_algorithm: DiffAlgorithm,
+    #[serde(default)]
+    pub refresh_on_focus: bool,
+    /// Offer to open the repository containing the working directory when
+    /// none was given on the command line.
+    #[serde(default = "default_true")]
+    pub auto_open_cwd_repo: bool,
+    #[serde(default = "default_true")]
+    pub reopen_last_on_start: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -56,8 +189,26 @@ pub struct UiConfig {
     pub show_commit_graph: bool,
     pub show_file_tree: bool,
     pub show_diff_viewer: bool,
+    #[serde(default = "default_true")]
+    pub show_commit_details: bool,
     pub panel_sizes: PanelSizes,
     pub menu_preferences: MenuPreferences,
+    #[serde(default)]
+    pub hide_merge_commits: bool,
+    #[serde(default = "default_true")]
+    pub show_repo_in_title: bool,
+    #[serde(default = "default_max_author_length")]
+    pub max_author_length: usize,
+    #[serde(default = "default_true")]
+    pub show_splash: bool,
+    #[serde(default = "default_splash_timeout_ms")]
+    pub splash_timeout_ms: u64,
+    #[serde(default)]
+    pub allow_multiple_expanded: bool,
+    #[serde(default)]
+    pub show_working_status: bool,
+    #[serde(default = "default_true")]
+    pub syntax_highlighting: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -82,6 +233,191 @@ pub struct PerformanceConfig {
     pub enable_background_operations: bool,
     pub max_background_threads: usize,
     pub target_fps: u32,
+    #[serde(default)]
+    pub developer_mode: bool,
+}
+
+impl PerformanceConfig {
+    /// Threads to parse commits on when loading history.
+    pub fn commit_parse_threads(&self) -> usize {
+        if self.enable_background_operations {
+            self.max_background_threads.max(1)
+        } else {
+            1
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeybindAction {
+    OpenRepository,
+    OpenLastRepository,
+    CloseRepository,
+    Refresh,
+    Settings,
+    Quit,
+    ShowShortcuts,
+    ToggleMenuBar,
+    CommandPalette,
+    NavigateBack,
+    NavigateForward,
+    GoToCommit,
+}
+
+impl KeybindAction {
+    pub const ALL: [KeybindAction; 12] = [
+        KeybindAction::OpenRepository,
+        KeybindAction::OpenLastRepository,
+        KeybindAction::CloseRepository,
+        KeybindAction::Refresh,
+        KeybindAction::Settings,
+        KeybindAction::Quit,
+        KeybindAction::ShowShortcuts,
+        KeybindAction::ToggleMenuBar,
+        KeybindAction::CommandPalette,
+        KeybindAction::NavigateBack,
+        KeybindAction::NavigateForward,
+        KeybindAction::GoToCommit,
+    ];
+
+    /// The field name in the `keybinds` config section.
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeybindAction::OpenRepository => "open_repository",
+            KeybindAction::OpenLastRepository => "open_last_repository",
+            KeybindAction::CloseRepository => "close_repository",
+            KeybindAction::Refresh => "refresh",
+            KeybindAction::Settings => "settings",
+            KeybindAction::Quit => "quit",
+            KeybindAction::ShowShortcuts => "show_shortcuts",
+            KeybindAction::ToggleMenuBar => "toggle_menu_bar",
+            KeybindAction::CommandPalette => "command_palette",
+            KeybindAction::NavigateBack => "navigate_back",
+            KeybindAction::NavigateForward => "navigate_forward",
+            KeybindAction::GoToCommit => "go_to_commit",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            KeybindAction::OpenRepository => "Open Repository",
+            KeybindAction::OpenLastRepository => "Open Last Repository",
+            KeybindAction::CloseRepository => "Close Repository",
+            KeybindAction::Refresh => "Refresh Repository",
+            KeybindAction::Settings => "Settings",
+            KeybindAction::Quit => "Exit Application",
+            KeybindAction::ShowShortcuts => "Show Shortcuts",
+            KeybindAction::ToggleMenuBar => "Toggle Menu Bar",
+            KeybindAction::CommandPalette => "Command Palette",
+            KeybindAction::NavigateBack => "Previous Selection",
+            KeybindAction::NavigateForward => "Next Selection",
+            KeybindAction::GoToCommit => "Go to Commit",
+        }
+    }
+}
+
+/// Shortcut per action, written like `Ctrl+Shift+O`; see `util::keys`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct KeybindConfig {
+    pub open_repository: String,
+    pub open_last_repository: String,
+    pub close_repository: String,
+    pub refresh: String,
+    pub settings: String,
+    pub quit: String,
+    pub show_shortcuts: String,
+    pub toggle_menu_bar: String,
+    pub command_palette: String,
+    pub navigate_back: String,
+    pub navigate_forward: String,
+    pub go_to_commit: String,
+}
+
+impl Default for KeybindConfig {
+    fn default() -> Self {
+        Self {
+            open_repository: "Ctrl+O".to_string(),
+            open_last_repository: "Ctrl+Shift+O".to_string(),
+            close_repository: "Ctrl+W".to_string(),
+            refresh: "F5".to_string(),
+            settings: "Ctrl+Alt+S".to_string(),
+            quit: "Ctrl+Q".to_string(),
+            show_shortcuts: "F1".to_string(),
+            toggle_menu_bar: "F10".to_string(),
+            command_palette: "Ctrl+Shift+P".to_string(),
+            navigate_back: "Alt+Left".to_string(),
+            navigate_forward: "Alt+Right".to_string(),
+            go_to_commit: "Ctrl+G".to_string(),
+        }
+    }
+}
+
+impl KeybindConfig {
+    pub fn get(&self, action: KeybindAction) -> &str {
+        match action {
+            KeybindAction::OpenRepository => &self.open_repository,
+            KeybindAction::OpenLastRepository => &self.open_last_repository,
+            KeybindAction::CloseRepository => &self.close_repository,
+            KeybindAction::Refresh => &self.refresh,
+            KeybindAction::Settings => &self.settings,
+            KeybindAction::Quit => &self.quit,
+            KeybindAction::ShowShortcuts => &self.show_shortcuts,
+            KeybindAction::ToggleMenuBar => &self.toggle_menu_bar,
+            KeybindAction::CommandPalette => &self.command_palette,
+            KeybindAction::NavigateBack => &self.navigate_back,
+            KeybindAction::NavigateForward => &self.navigate_forward,
+            KeybindAction::GoToCommit => &self.go_to_commit,
+        }
+    }
+
+    pub fn set(&mut self, action: KeybindAction, binding: String) {
+        let field = match action {
+            KeybindAction::OpenRepository => &mut self.open_repository,
+            KeybindAction::OpenLastRepository => &mut self.open_last_repository,
+            KeybindAction::CloseRepository => &mut self.close_repository,
+            KeybindAction::Refresh => &mut self.refresh,
+            KeybindAction::Settings => &mut self.settings,
+            KeybindAction::Quit => &mut self.quit,
+            KeybindAction::ShowShortcuts => &mut self.show_shortcuts,
+            KeybindAction::ToggleMenuBar => &mut self.toggle_menu_bar,
+            KeybindAction::CommandPalette => &mut self.command_palette,
+            KeybindAction::NavigateBack => &mut self.navigate_back,
+            KeybindAction::NavigateForward => &mut self.navigate_forward,
+            KeybindAction::GoToCommit => &mut self.go_to_commit,
+        };
+        *field = binding;
+    }
+
+    /// The parsed shortcuts; bindings that don't parse are skipped.
+    pub fn shortcuts(&self) -> Vec<(KeybindAction, egui::KeyboardShortcut)> {
+        KeybindAction::ALL
+            .into_iter()
+            .filter_map(|action| parse_shortcut(self.get(action)).map(|shortcut| (action, shortcut)))
+            .collect()
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        let mut seen: Vec<(KeybindAction, egui::KeyboardShortcut)> = Vec::new();
+
+        for action in KeybindAction::ALL {
+            let field = format!("keybinds.{}", action.name());
+            let shortcut = parse_shortcut(self.get(action)).ok_or_else(|| TwiggyError::Validation {
+                field: field.clone(),
+                message: format!("'{}' is not a valid shortcut", self.get(action)),
+            })?;
+
+            if let Some((other, _)) = seen.iter().find(|(_, existing)| *existing == shortcut) {
+                return Err(TwiggyError::Validation {
+                    field,
+                    message: format!("{} is already bound to {}", self.get(action), other.label()),
+                });
+            }
+            seen.push((action, shortcut));
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -116,6 +452,8 @@ impl Default for LoggingConfig {
     }
 }
 
+/// Most recently opened first. Pinned entries sit above the rest and don't
+/// count toward `max_count`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecentRepositories {
     pub repositories: Vec<RecentRepository>,
@@ -127,6 +465,8 @@ pub struct RecentRepository {
     pub path: PathBuf,
     pub name: String,
     pub last_opened: chrono::DateTime<chrono::Utc>,
+    #[serde(default)]
+    pub pinned: bool,
 }
 
 impl Default for RecentRepositories {
@@ -140,36 +480,114 @@ impl Default for RecentRepositories {
 
 impl RecentRepositories {
     pub fn add_repository(&mut self, path: PathBuf, name: String) {
-        self.repositories.retain(|r| r.path != path);
+        let path = Self::canonical_path(&path);
+        let pinned = self.is_pinned(&path);
+        self.repositories.retain(|r| Self::canonical_path(&r.path) != path);
         
         self.repositories.insert(0, RecentRepository {
             path,
             name,
             last_opened: chrono::Utc::now(),
+            pinned,
         });
         
-        if self.repositories.len() > self.max_count {
-            self.repositories.truncate(self.max_count);
+        self.arrange();
+    }
+    
+    pub fn is_pinned(&self, path: &Path) -> bool {
+        let path = Self::canonical_path(path);
+        self.repositories.iter().any(|r| r.pinned && Self::canonical_path(&r.path) == path)
+    }
+    
+    pub fn set_pinned(&mut self, path: &Path, pinned: bool) {
+        let path = Self::canonical_path(path);
+        for repository in &mut self.repositories {
+            if Self::canonical_path(&repository.path) == path {
+                repository.pinned = pinned;
+            }
         }
+        
+        self.arrange();
+    }
+    
+    /// Moves pinned entries to the top, keeping recency order within each
+    /// group, and drops unpinned entries past `max_count`.
+    fn arrange(&mut self) {
+        self.repositories.sort_by_key(|r| !r.pinned);
+        
+        let max_count = self.max_count;
+        let mut unpinned = 0;
+        self.repositories.retain(|r| {
+            if r.pinned {
+                return true;
+            }
+            unpinned += 1;
+            unpinned <= max_count
+        });
     }
     
     pub fn remove_repository(&mut self, path: &Path) {
-        self.repositories.retain(|r| r.path != path);
+        let path = Self::canonical_path(path);
+        self.repositories.retain(|r| Self::canonical_path(&r.path) != path);
+    }
+    
+    pub fn set_max_count(&mut self, max_count: usize) {
+        self.max_count = max_count;
+        self.arrange();
+    }
+    
+    fn canonical_path(path: &Path) -> PathBuf {
+        std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
     }
     
     pub fn clear(&mut self) {
         self.repositories.clear();
     }
     
-    pub fn validate_and_clean(&mut self) {
+    /// Drops entries whose path no longer exists. Returns how many were
+    /// removed.
+    pub fn validate_and_clean(&mut self) -> usize {
+        let before = self.repositories.len();
         self.repositories.retain(|r| r.path.exists());
+        before - self.repositories.len()
     }
 }
 
+static CONFIG_PATH_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Version written by this build. Version 2 added the `keybinds` section;
+/// version 3 moved the settings shortcut from Ctrl+S to Ctrl+Alt+S.
+pub const CONFIG_VERSION: u32 = 3;
+
+/// Files without a `version` field predate versioning and are treated as 1.
 fn default_version() -> u32 {
     1
 }
 
+fn default_true() -> bool {
+    true
+}
+
+fn default_custom_themes() -> Vec<CustomTheme> {
+    vec![CustomTheme::high_contrast()]
+}
+
+fn default_ui_scale() -> f32 {
+    1.0
+}
+
+fn default_max_author_length() -> usize {
+    32
+}
+
+fn default_splash_timeout_ms() -> u64 {
+    1500
+}
+
+fn default_large_fetch_threshold() -> usize {
+    100
+}
+
 impl Default for AppConfig {
     fn default() -> Self {
         Self {
@@ -185,7 +603,11 @@ impl Default for AppConfig {
                 theme_type: ThemeType::System,
                 font_size: 14.0,
                 dark_mode: false,
-                accent_color: "#007ACC".to_string(),
+                accent_color: DEFAULT_ACCENT_COLOR.to_string(),
+                ui_scale: default_ui_scale(),
+                font_family: None,
+                custom_themes: default_custom_themes(),
+                custom_theme: None,
             },
             git: GitConfig {
                 default_clone_path: std::env::var("HOME")
@@ -196,11 +618,18 @@ impl Default for AppConfig {
                 auto_fetch: true,
                 show_stashes: false,
                 fetch_interval_minutes: 15,
+                confirm_large_fetch: true,
+                large_fetch_threshold: default_large_fetch_threshold(),
+                diff_algorithm: DiffAlgorithm::default(),
+                refresh_on_focus: false,
+                auto_open_cwd_repo: true,
+                reopen_last_on_start: true,
             },
             ui: UiConfig {
                 show_commit_graph: true,
                 show_file_tree: true,
                 show_diff_viewer: true,
+                show_commit_details: true,
                 panel_sizes: PanelSizes {
                     left_panel_width: 250.0,
                     right_panel_width: 300.0,
@@ -212,6 +641,14 @@ impl Default for AppConfig {
                     compact_menus: false,
                     show_icons: false,
                 },
+                hide_merge_commits: false,
+                show_repo_in_title: true,
+                max_author_length: default_max_author_length(),
+                show_splash: true,
+                splash_timeout_ms: default_splash_timeout_ms(),
+                allow_multiple_expanded: false,
+                show_working_status: false,
+                syntax_highlighting: true,
             },
             performance: PerformanceConfig {
                 enable_caching: true,
@@ -219,10 +656,13 @@ impl Default for AppConfig {
                 enable_background_operations: true,
                 max_background_threads: 4,
                 target_fps: 60,
+                developer_mode: false,
             },
             logging: LoggingConfig::default(),
+            keybinds: KeybindConfig::default(),
             recent_repositories: RecentRepositories::default(),
-            version: 1,
+            last_opened_repository: None,
+            version: CONFIG_VERSION,
         }
     }
 }
@@ -255,17 +695,20 @@ impl AppConfig {
             }
         })?;
 
-        let mut config: Self = serde_json::from_str(&config_content).map_err(|e| {
+        let (mut config, migrated) = Self::parse_and_migrate(&config_content).map_err(|e| {
             tracing::warn!("Failed to parse config file, using defaults: {}", e);
-            TwiggyError::Config {
-                message: format!("Invalid configuration format: {}", e),
-            }
+            e
         })?;
 
+        // A bad colour shouldn't cost the user the rest of their settings.
+        let accent_reset = config.theme.reset_invalid_accent_color();
+
         config.validate()?;
         
-        if config.migrate_if_needed()? {
-            tracing::info!("Configuration migrated to newer version");
+        if migrated {
+            tracing::info!("Configuration migrated to version {}", CONFIG_VERSION);
+        }
+        if migrated || accent_reset {
             config.save()?;
         }
         
@@ -295,6 +738,39 @@ impl AppConfig {
             })?;
         }
         
+        self.write_json(&config_path)?;
+        
+        tracing::info!("Configuration saved to {}", config_path.display());
+        Ok(())
+    }
+
+    /// Writes the whole configuration to a user-chosen file.
+    pub fn export_to(&self, path: &Path) -> Result<()> {
+        self.write_json(path)?;
+        tracing::info!("Configuration exported to {}", path.display());
+        Ok(())
+    }
+
+    /// Reads a configuration written by `export_to`. The result is migrated
+    /// and validated but not applied or saved.
+    pub fn import_from(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            TwiggyError::FileSystem {
+                path: path.display().to_string(),
+                source: e,
+            }
+        })?;
+
+        let (config, _) = Self::parse_and_migrate(&content)?;
+        config.validate()?;
+
+        tracing::info!("Configuration imported from {}", path.display());
+        Ok(config)
+    }
+
+    /// Writes to `<path>.tmp` and renames it over `path`, so a crash while
+    /// writing never leaves a truncated config behind.
+    fn write_json(&self, path: &Path) -> Result<()> {
         let content = serde_json::to_string_pretty(self).map_err(|e| {
             TwiggyError::Serialization {
                 operation: "config serialization".to_string(),
@@ -302,15 +778,24 @@ impl AppConfig {
             }
         })?;
         
-        std::fs::write(&config_path, content).map_err(|e| {
+        let mut temp_name = path.as_os_str().to_owned();
+        temp_name.push(".tmp");
+        let temp_path = PathBuf::from(temp_name);
+        
+        std::fs::write(&temp_path, content).map_err(|e| {
             TwiggyError::FileSystem {
-                path: config_path.display().to_string(),
+                path: temp_path.display().to_string(),
                 source: e,
             }
         })?;
         
-        tracing::info!("Configuration saved to {}", config_path.display());
-        Ok(())
+        std::fs::rename(&temp_path, path).map_err(|e| {
+            let _ = std::fs::remove_file(&temp_path);
+            TwiggyError::FileSystem {
+                path: path.display().to_string(),
+                source: e,
+            }
+        })
     }
 
     pub fn validate(&self) -> Result<()> {
@@ -335,6 +820,30 @@ impl AppConfig {
             });
         }
 
+        self.theme.validate_accent_color()?;
+
+        for (index, theme) in self.theme.custom_themes.iter().enumerate() {
+            if theme.name.trim().is_empty() {
+                return Err(TwiggyError::Validation {
+                    field: "theme.custom_themes".to_string(),
+                    message: "Custom theme names cannot be empty".to_string(),
+                });
+            }
+            if self.theme.custom_themes[..index].iter().any(|other| other.name == theme.name) {
+                return Err(TwiggyError::Validation {
+                    field: "theme.custom_themes".to_string(),
+                    message: format!("More than one custom theme is named \"{}\"", theme.name),
+                });
+            }
+        }
+
+        if !(MIN_UI_SCALE..=MAX_UI_SCALE).contains(&self.theme.ui_scale) {
+            return Err(TwiggyError::Validation {
+                field: "theme.ui_scale".to_string(),
+                message: format!("UI scale must be between {} and {}", MIN_UI_SCALE, MAX_UI_SCALE),
+            });
+        }
+
         if self.git.max_commits == 0 || self.git.max_commits > 10000 {
             return Err(TwiggyError::Validation {
                 field: "git.max_commits".to_string(),
@@ -342,6 +851,13 @@ impl AppConfig {
             });
         }
 
+        if self.recent_repositories.max_count == 0 || self.recent_repositories.max_count > 50 {
+            return Err(TwiggyError::Validation {
+                field: "recent_repositories.max_count".to_string(),
+                message: "Recent repositories limit must be between 1 and 50".to_string(),
+            });
+        }
+
         if self.git.fetch_interval_minutes == 0 || self.git.fetch_interval_minutes > 1440 {
             return Err(TwiggyError::Validation {
                 field: "git.fetch_interval_minutes".to_string(),
@@ -377,6 +893,8 @@ impl AppConfig {
             });
         }
 
+        self.keybinds.validate()?;
+
         Ok(())
     }
 
@@ -387,15 +905,160 @@ impl AppConfig {
         Ok(())
     }
 
+    /// Every setting whose value differs from `AppConfig::default()`, as
+    /// `(field, current, default)` with dotted field paths and values
+    /// rendered as JSON. State Twiggy keeps on its own, such as the recent
+    /// repository list, is left out.
+    pub fn diff_from_default(&self) -> Vec<(String, String, String)> {
+        let (Ok(current), Ok(default)) = (serde_json::to_value(self), serde_json::to_value(Self::default())) else {
+            return Vec::new();
+        };
+
+        let mut current_fields = Vec::new();
+        flatten_json("", &current, &mut current_fields);
+        let mut default_fields = Vec::new();
+        flatten_json("", &default, &mut default_fields);
+
+        current_fields
+            .into_iter()
+            .filter(|(field, _)| !UNTRACKED_FIELDS.contains(&field.as_str()))
+            .filter_map(|(field, value)| {
+                let default_value = default_fields
+                    .iter()
+                    .find(|(default_field, _)| *default_field == field)
+                    .map(|(_, value)| value.clone())
+                    .unwrap_or(serde_json::Value::Null);
+                (value != default_value).then(|| (field, value.to_string(), default_value.to_string()))
+            })
+            .collect()
+    }
+
+    /// Sets the dotted `field` from `diff_from_default` back to its default.
+    pub fn reset_field(&mut self, field: &str) -> Result<()> {
+        let serialization_error = |e| TwiggyError::Serialization {
+            operation: "config serialization".to_string(),
+            source: e,
+        };
+        let default = serde_json::to_value(Self::default()).map_err(serialization_error)?;
+        let mut current = serde_json::to_value(&*self).map_err(serialization_error)?;
+
+        let pointer = format!("/{}", field.replace('.', "/"));
+        let (Some(default_value), Some(target)) = (default.pointer(&pointer), current.pointer_mut(&pointer)) else {
+            return Err(TwiggyError::Config {
+                message: format!("Unknown setting: {}", field),
+            });
+        };
+        *target = default_value.clone();
+
+        *self = serde_json::from_value(current).map_err(|e| TwiggyError::Config {
+            message: format!("Failed to reset {}: {}", field, e),
+        })?;
+        Ok(())
+    }
+
+    /// A copy of this configuration with the `.twiggy.json` of `repo_root`
+    /// applied, or an unchanged copy if there is none or it is malformed.
+    pub fn with_repo_overrides(&self, repo_root: &Path) -> AppConfig {
+        let overrides = match RepoOverrides::load(repo_root) {
+            Ok(Some(overrides)) => overrides,
+            Ok(None) => return self.clone(),
+            Err(e) => {
+                tracing::warn!("Ignoring repository config: {}", e);
+                return self.clone();
+            }
+        };
+
+        self.with_overrides(&overrides).unwrap_or_else(|e| {
+            tracing::warn!("Ignoring repository config: {}", e);
+            self.clone()
+        })
+    }
+
+    pub fn with_overrides(&self, overrides: &RepoOverrides) -> Result<AppConfig> {
+        let mut merged = serde_json::to_value(self).map_err(|e| TwiggyError::Serialization {
+            operation: "config serialization".to_string(),
+            source: e,
+        })?;
+
+        for section in RepoOverrides::SECTIONS {
+            if let (Some(base), Some(values)) = (merged.get_mut(section), overrides.values.get(section)) {
+                merge_known_fields(base, values);
+            }
+        }
+
+        let config: AppConfig = serde_json::from_value(merged).map_err(|e| TwiggyError::Config {
+            message: format!("Invalid repository config: {}", e),
+        })?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Parses a config file of any version. Files older than
+    /// `CONFIG_VERSION` are laid over the defaults first, so sections and
+    /// fields they don't have yet get default values while everything they
+    /// do have is kept. Returns whether the file was upgraded.
+    pub fn parse_and_migrate(content: &str) -> Result<(Self, bool)> {
+        let invalid_format = |e: serde_json::Error| TwiggyError::Config {
+            message: format!("Invalid configuration format: {}", e),
+        };
+
+        let mut value: serde_json::Value = serde_json::from_str(content).map_err(invalid_format)?;
+        let version = value.get("version").and_then(serde_json::Value::as_u64).unwrap_or(1);
+
+        if version < CONFIG_VERSION as u64 {
+            let mut upgraded = serde_json::to_value(Self::default()).map_err(|e| TwiggyError::Serialization {
+                operation: "config serialization".to_string(),
+                source: e,
+            })?;
+            overlay_json(&mut upgraded, value);
+            // Files without a version would otherwise take the default's.
+            upgraded["version"] = serde_json::Value::from(version);
+            value = upgraded;
+        }
+
+        let mut config: Self = serde_json::from_value(value).map_err(invalid_format)?;
+        let migrated = config.migrate_if_needed()?;
+        Ok((config, migrated))
+    }
+
     pub fn migrate_if_needed(&mut self) -> Result<bool> {
+        let mut migrated = false;
+
         if self.version < 1 {
             self.version = 1;
-            return Ok(true);
+            migrated = true;
+        }
+
+        if self.version < 2 {
+            // Version 1 had no shortcuts section.
+            self.keybinds = KeybindConfig::default();
+            self.version = 2;
+            migrated = true;
+        }
+
+        if self.version < 3 {
+            // Ctrl+S now saves the settings dialog instead of opening it.
+            if self.keybinds.settings == "Ctrl+S" {
+                self.keybinds.settings = KeybindConfig::default().settings;
+            }
+            self.version = 3;
+            migrated = true;
         }
-        Ok(false)
+
+        Ok(migrated)
+    }
+
+    /// Makes `load` and `save` use `path` instead of the per-user config file
+    /// for the rest of the process. Only the first call takes effect.
+    pub fn use_config_file(path: PathBuf) -> bool {
+        CONFIG_PATH_OVERRIDE.set(path).is_ok()
     }
 
     fn config_file_path() -> Result<PathBuf> {
+        if let Some(path) = CONFIG_PATH_OVERRIDE.get() {
+            return Ok(path.clone());
+        }
+
         let project_dirs = ProjectDirs::from("dev", "twiggy", "Twiggy")
             .ok_or_else(|| TwiggyError::Config {
                 message: "Cannot determine config directory".to_string(),
@@ -403,4 +1066,107 @@ impl AppConfig {
         
         Ok(project_dirs.config_dir().join("config.json"))
     }
+}
+
+pub const REPO_CONFIG_FILE_NAME: &str = ".twiggy.json";
+
+/// The `git` and `ui` sections of a repository's `.twiggy.json`.
+#[derive(Debug, Clone, Default)]
+pub struct RepoOverrides {
+    values: serde_json::Value,
+}
+
+impl RepoOverrides {
+    const SECTIONS: [&'static str; 2] = ["git", "ui"];
+
+    /// Reads `<repo_root>/.twiggy.json`; `Ok(None)` if the file doesn't exist.
+    pub fn load(repo_root: &Path) -> Result<Option<Self>> {
+        let path = repo_root.join(REPO_CONFIG_FILE_NAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = std::fs::read_to_string(&path).map_err(|e| TwiggyError::FileSystem {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+
+        Self::from_json(&content).map(Some).map_err(|e| TwiggyError::Config {
+            message: format!("{}: {}", path.display(), e),
+        })
+    }
+
+    pub fn from_json(content: &str) -> Result<Self> {
+        let values: serde_json::Value = serde_json::from_str(content).map_err(|e| TwiggyError::Config {
+            message: format!("Invalid repository config: {}", e),
+        })?;
+
+        if !values.is_object() {
+            return Err(TwiggyError::Config {
+                message: "Repository config must be a JSON object".to_string(),
+            });
+        }
+
+        Ok(Self { values })
+    }
+}
+
+/// Copies the values of `overrides` into `base`, recursing into objects and
+/// skipping keys `base` doesn't have.
+fn merge_known_fields(base: &mut serde_json::Value, overrides: &serde_json::Value) {
+    let (Some(base), Some(overrides)) = (base.as_object_mut(), overrides.as_object()) else {
+        return;
+    };
+
+    for (key, value) in overrides {
+        match base.get_mut(key) {
+            Some(existing) if existing.is_object() && value.is_object() => merge_known_fields(existing, value),
+            Some(existing) => *existing = value.clone(),
+            None => tracing::debug!("Ignoring unknown repository config field: {}", key),
+        }
+    }
+}
+
+/// Fields `diff_from_default` skips because Twiggy updates them as it runs;
+/// the window geometry follows the window itself.
+const UNTRACKED_FIELDS: &[&str] = &[
+    "version",
+    "last_opened_repository",
+    "recent_repositories.repositories",
+    "window.width",
+    "window.height",
+    "window.position_x",
+    "window.position_y",
+    "window.maximized",
+];
+
+/// Collects the leaves of `value` with their dotted paths. Arrays are
+/// leaves, so a list is compared and reset as a whole.
+fn flatten_json(prefix: &str, value: &serde_json::Value, fields: &mut Vec<(String, serde_json::Value)>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map {
+                let path = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                flatten_json(&path, value, fields);
+            }
+        }
+        value => fields.push((prefix.to_string(), value.clone())),
+    }
+}
+
+/// Writes every value of `overlay` into `base`, recursing into objects.
+fn overlay_json(base: &mut serde_json::Value, overlay: serde_json::Value) {
+    match (base, overlay) {
+        (serde_json::Value::Object(base), serde_json::Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => overlay_json(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
 }
\ No newline at end of file
@@ -0,0 +1,91 @@
+use std::io::Write;
+use std::path::Path;
+use crate::config::AppConfig;
+use crate::error::{Result, TwiggyError};
+
+/// Lines of the log file included in a report.
+pub const LOG_TAIL_LINES: usize = 200;
+
+const REDACTED: &str = "<redacted>";
+
+/// The error a report is about, as shown in the error dialog.
+#[derive(Debug, Clone)]
+pub struct ErrorSummary {
+    pub code: u32,
+    pub message: String,
+    pub details: String,
+    pub recoverable: bool,
+}
+
+/// Writes a pretty-printed JSON bug report: the error, app version and
+/// platform, the configuration with anything path-like redacted, and the
+/// last `LOG_TAIL_LINES` lines of `log_file`. A missing or unreadable log is
+/// noted in the report rather than failing it.
+pub fn write_report(mut writer: impl Write, error: &ErrorSummary, config: &AppConfig, log_file: &Path) -> Result<()> {
+    let mut config = serde_json::to_value(config).map_err(|e| TwiggyError::Serialization {
+        operation: "config serialization".to_string(),
+        source: e,
+    })?;
+    redact_paths(&mut config);
+
+    let log = match log_tail(log_file, LOG_TAIL_LINES) {
+        Ok(lines) => serde_json::json!({ "tail": lines }),
+        Err(e) => serde_json::json!({ "unavailable": e.to_string() }),
+    };
+
+    let report = serde_json::json!({
+        "app_version": env!("CARGO_PKG_VERSION"),
+        "generated_at": chrono::Utc::now().to_rfc3339(),
+        "platform": {
+            "os": std::env::consts::OS,
+            "arch": std::env::consts::ARCH,
+        },
+        "error": {
+            "code": error.code,
+            "message": error.message,
+            "details": error.details,
+            "recoverable": error.recoverable,
+        },
+        "config": config,
+        "log": log,
+    });
+
+    serde_json::to_writer_pretty(&mut writer, &report).map_err(|e| TwiggyError::Serialization {
+        operation: "error report".to_string(),
+        source: e,
+    })?;
+
+    writeln!(writer)
+        .and_then(|_| writer.flush())
+        .map_err(|e| TwiggyError::Io {
+            operation: "writing error report".to_string(),
+            source: e,
+        })
+}
+
+/// The last `max_lines` lines of `path`, decoded lossily.
+pub fn log_tail(path: &Path, max_lines: usize) -> std::io::Result<Vec<String>> {
+    let bytes = std::fs::read(path)?;
+    let text = String::from_utf8_lossy(&bytes);
+    let lines: Vec<&str> = text.lines().collect();
+
+    Ok(lines[lines.len().saturating_sub(max_lines)..]
+        .iter()
+        .map(|line| line.to_string())
+        .collect())
+}
+
+/// Replaces every string that looks like a filesystem path, wherever it
+/// sits in `value`, so reports don't leak user names or directory layouts.
+pub fn redact_paths(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::String(text) if looks_like_path(text) => *text = REDACTED.to_string(),
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_paths),
+        serde_json::Value::Object(fields) => fields.values_mut().for_each(redact_paths),
+        _ => {}
+    }
+}
+
+fn looks_like_path(text: &str) -> bool {
+    text.contains('/') || text.contains('\\') || text.starts_with('~')
+}
\ No newline at end of file
@@ -55,6 +55,21 @@ pub enum TwiggyError {
     },
 }
 
+/// How loudly an error is surfaced: critical and ordinary errors open the
+/// error dialog, warnings only raise a toast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Critical,
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn shows_dialog(self) -> bool {
+        !matches!(self, Self::Warning)
+    }
+}
+
 impl TwiggyError {
     pub fn error_code(&self) -> u32 {
         match self {
@@ -102,6 +117,37 @@ impl TwiggyError {
         }
     }
     
+    /// Whether a remote rejected or asked for credentials.
+    pub fn is_auth_error(&self) -> bool {
+        matches!(self, Self::Git { source, .. } if source.code() == git2::ErrorCode::Auth)
+    }
+    
+    /// Whether git reported damaged objects or object storage.
+    pub fn is_corruption(&self) -> bool {
+        matches!(
+            self,
+            Self::Git { source, .. } if matches!(
+                source.class(),
+                git2::ErrorClass::Odb | git2::ErrorClass::Object | git2::ErrorClass::Zlib | git2::ErrorClass::Sha1
+            )
+        )
+    }
+    
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::Git { .. } if self.is_corruption() => Severity::Critical,
+            Self::Git { .. } => Severity::Error,
+            Self::Io { .. } => Severity::Error,
+            Self::Config { .. } => Severity::Warning,
+            Self::Ui { .. } => Severity::Warning,
+            Self::Application { .. } => Severity::Critical,
+            Self::Serialization { .. } => Severity::Error,
+            Self::FileSystem { .. } => Severity::Error,
+            Self::Network { .. } => Severity::Warning,
+            Self::Validation { .. } => Severity::Warning,
+        }
+    }
+    
     pub fn is_recoverable(&self) -> bool {
         match self {
             Self::Git { .. } => false,
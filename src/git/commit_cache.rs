@@ -0,0 +1,132 @@
+use std::collections::{BTreeMap, HashMap};
+use std::mem::size_of;
+use crate::git::types::{Commit, CommitId, Signature};
+
+pub const DEFAULT_CACHE_LIMIT_BYTES: usize = 100 * 1024 * 1024;
+
+/// Least-recently-used cache of parsed commits bounded by an estimate of their
+/// heap size rather than by entry count.
+pub struct CommitCache {
+    entries: HashMap<CommitId, (Commit, u64)>,
+    recency: BTreeMap<u64, CommitId>,
+    next_tick: u64,
+    size_bytes: usize,
+    limit_bytes: usize,
+}
+
+impl Default for CommitCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_LIMIT_BYTES)
+    }
+}
+
+impl CommitCache {
+    pub fn new(limit_bytes: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            next_tick: 0,
+            size_bytes: 0,
+            limit_bytes,
+        }
+    }
+
+    /// Returns a cached commit and marks it as recently used.
+    pub fn get(&mut self, id: &CommitId) -> Option<&Commit> {
+        let tick = self.next_tick;
+        let (_, last_used) = self.entries.get_mut(id)?;
+        self.recency.remove(last_used);
+        self.recency.insert(tick, *id);
+        *last_used = tick;
+        self.next_tick += 1;
+
+        self.entries.get(id).map(|(commit, _)| commit)
+    }
+
+    /// Returns a cached commit without touching its recency.
+    pub fn peek(&self, id: &CommitId) -> Option<&Commit> {
+        self.entries.get(id).map(|(commit, _)| commit)
+    }
+
+    pub fn insert(&mut self, commit: Commit) {
+        if let Some((old, last_used)) = self.entries.remove(&commit.id) {
+            self.recency.remove(&last_used);
+            self.size_bytes -= estimate_commit_size(&old);
+        }
+
+        let tick = self.next_tick;
+        self.next_tick += 1;
+        self.size_bytes += estimate_commit_size(&commit);
+        self.recency.insert(tick, commit.id);
+        self.entries.insert(commit.id, (commit, tick));
+
+        self.evict_to_limit();
+    }
+
+    pub fn set_limit(&mut self, limit_bytes: usize) {
+        self.limit_bytes = limit_bytes;
+        self.evict_to_limit();
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit_bytes
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+        self.size_bytes = 0;
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn size_bytes(&self) -> usize {
+        self.size_bytes
+    }
+
+    fn evict_to_limit(&mut self) {
+        let mut evicted = 0;
+
+        while self.size_bytes > self.limit_bytes {
+            let Some((_, id)) = self.recency.pop_first() else {
+                break;
+            };
+
+            if let Some((commit, _)) = self.entries.remove(&id) {
+                self.size_bytes -= estimate_commit_size(&commit);
+                evicted += 1;
+            }
+        }
+
+        if evicted > 0 {
+            tracing::debug!(
+                "Evicted {} commits from cache ({} bytes, limit {})",
+                evicted,
+                self.size_bytes,
+                self.limit_bytes
+            );
+        }
+    }
+}
+
+/// Rough heap footprint of a cached commit: the struct itself, its strings and
+/// the parents vector, plus the map and recency bookkeeping.
+pub fn estimate_commit_size(commit: &Commit) -> usize {
+    let signature_size = |signature: &Signature| signature.name.len() + signature.email.len();
+
+    size_of::<Commit>()
+        + size_of::<CommitId>() * 2
+        + size_of::<u64>() * 2
+        + commit.message.len()
+        + commit.summary.len()
+        + commit.tree_id.len()
+        + signature_size(&commit.author)
+        + signature_size(&commit.committer)
+        + commit.parents.len() * size_of::<CommitId>()
+}
\ No newline at end of file
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use crate::git::types::{Commit, CommitId};
+
+/// Whether merge commits are kept, dropped or the only ones kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergeFilter {
+    #[default]
+    Any,
+    MergesOnly,
+    NoMerges,
+}
+
+/// Structured commit search. Every field that is set must match; the
+/// substring fields are case-insensitive and an empty filter matches
+/// everything.
+#[derive(Debug, Clone, Default)]
+pub struct CommitFilter {
+    /// Matched against the author name and email.
+    pub author: Option<String>,
+    /// Matched against the full message.
+    pub message: Option<String>,
+    /// Matched against the message, the author, or as a hash prefix.
+    pub text: Option<String>,
+    pub merges: MergeFilter,
+}
+
+impl CommitFilter {
+    pub fn is_empty(&self) -> bool {
+        self.author.is_none()
+            && self.message.is_none()
+            && self.text.is_none()
+            && self.merges == MergeFilter::Any
+    }
+
+    pub fn matches(&self, commit: &Commit) -> bool {
+        let is_merge = commit.parents.len() > 1;
+        match self.merges {
+            MergeFilter::Any => {}
+            MergeFilter::MergesOnly if !is_merge => return false,
+            MergeFilter::NoMerges if is_merge => return false,
+            _ => {}
+        }
+
+        if let Some(author) = &self.author {
+            let author = author.to_lowercase();
+            if !commit.author.name.to_lowercase().contains(&author)
+                && !commit.author.email.to_lowercase().contains(&author)
+            {
+                return false;
+            }
+        }
+
+        if let Some(message) = &self.message {
+            if !commit.message.to_lowercase().contains(&message.to_lowercase()) {
+                return false;
+            }
+        }
+
+        if let Some(text) = &self.text {
+            let text = text.to_lowercase();
+            if !commit.message.to_lowercase().contains(&text)
+                && !commit.summary.to_lowercase().contains(&text)
+                && !commit.author.name.to_lowercase().contains(&text)
+                && !commit.author.email.to_lowercase().contains(&text)
+                && !commit.id.as_str().starts_with(&text)
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Returns the commits without merge commits, like `git log --no-merges`.
+///
+/// Parents that point at a hidden merge are rewritten to the merge's own
+/// (visible) ancestors so the graph stays connected across the gap.
+pub fn hide_merge_commits(commits: &[Commit]) -> Vec<Commit> {
+    let merges: HashMap<CommitId, &Commit> = commits
+        .iter()
+        .filter(|commit| commit.parents.len() > 1)
+        .map(|commit| (commit.id, commit))
+        .collect();
+
+    if merges.is_empty() {
+        return commits.to_vec();
+    }
+
+    let mut resolved: HashMap<CommitId, Vec<CommitId>> = HashMap::new();
+
+    // Commits are topologically sorted (children first), so walking backwards
+    // resolves every merge's parents before the merge itself.
+    for commit in commits.iter().rev() {
+        if merges.contains_key(&commit.id) {
+            let parents = resolve_parents(&commit.parents, &resolved);
+            resolved.insert(commit.id, parents);
+        }
+    }
+
+    commits
+        .iter()
+        .filter(|commit| !merges.contains_key(&commit.id))
+        .map(|commit| {
+            let mut visible = commit.clone();
+            visible.parents = resolve_parents(&commit.parents, &resolved);
+            visible
+        })
+        .collect()
+}
+
+fn resolve_parents(parents: &[CommitId], resolved: &HashMap<CommitId, Vec<CommitId>>) -> Vec<CommitId> {
+    let mut result: Vec<CommitId> = Vec::with_capacity(parents.len());
+
+    for parent in parents {
+        match resolved.get(parent) {
+            Some(ancestors) => {
+                for ancestor in ancestors {
+                    if !result.contains(ancestor) {
+                        result.push(*ancestor);
+                    }
+                }
+            }
+            None => {
+                if !result.contains(parent) {
+                    result.push(*parent);
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Inserts stash commits into the history, each right above the commit it was
+/// made on. Only that first parent is kept; the index and untracked-files
+/// commits git records as extra parents are never part of the history.
+/// Stashes whose base is not loaded go to the top.
+pub fn merge_stashes(commits: &[Commit], stashes: &[Commit]) -> Vec<Commit> {
+    if stashes.is_empty() {
+        return commits.to_vec();
+    }
+
+    let mut by_base: HashMap<CommitId, Vec<Commit>> = HashMap::new();
+    let mut orphaned = Vec::new();
+
+    for stash in stashes {
+        let mut stash = stash.clone();
+        stash.parents.truncate(1);
+
+        match stash.parents.first() {
+            Some(base) if commits.iter().any(|commit| commit.id == *base) => {
+                by_base.entry(*base).or_default().push(stash);
+            }
+            _ => orphaned.push(stash),
+        }
+    }
+
+    let mut result = Vec::with_capacity(commits.len() + stashes.len());
+    result.extend(orphaned);
+
+    for commit in commits {
+        if let Some(stashes) = by_base.remove(&commit.id) {
+            result.extend(stashes);
+        }
+        result.push(commit.clone());
+    }
+
+    result
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+use crate::git::types::{Commit, CommitId};
+
+/// Renders the commit DAG as a GraphViz `digraph`, one node per commit and
+/// one edge per parent link. Parents outside `commits` are left out so the
+/// output doesn't grow dangling nodes at the edge of the loaded history.
+pub fn to_dot(commits: &[Commit]) -> String {
+    let loaded: HashSet<CommitId> = commits.iter().map(|commit| commit.id).collect();
+    let mut dot = String::from("digraph commits {\n");
+    dot.push_str("    rankdir=BT;\n");
+    dot.push_str("    node [shape=box, fontname=\"monospace\"];\n");
+
+    for commit in commits {
+        let _ = writeln!(
+            dot,
+            "    \"{}\" [label=\"{}\", tooltip=\"{}\"];",
+            commit.id,
+            commit.id.short(),
+            escape(&commit.summary)
+        );
+    }
+
+    for commit in commits {
+        for parent in commit.parents.iter().filter(|parent| loaded.contains(parent)) {
+            let _ = writeln!(dot, "    \"{}\" -> \"{}\";", commit.id, parent);
+        }
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
\ No newline at end of file
@@ -1,5 +1,10 @@
+pub mod commit_cache;
+pub mod filter;
+pub mod graph_export;
 pub mod repository;
 pub mod types;
+pub mod web_url;
+pub mod word_diff;
 
 #[allow(unused_imports)]
 pub use repository::*;
@@ -1,8 +1,27 @@
 use git2::{Repository, Branch, BranchType, Direction};
 use std::path::{Path, PathBuf};
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use crate::error::{Result, TwiggyError};
-use crate::git::types::{Commit, CommitId, Signature};
+use crate::git::commit_cache::CommitCache;
+use crate::git::filter::CommitFilter;
+use crate::git::types::{AuthorStat, BlameLine, Commit, CommitId, CommitInfo, CommitMatch, DiffAlgorithm, DiffStats, DiffHunk, DiffInfo, DiffLine, DiffLineType, FetchSummary, RefKind, RemoteInfo, RenameInfo, RenameKind, SignatureStatus, StatusCategory, StatusEntry, SubmoduleInfo, RefLabel, Signature, StashEntry, TagRef, TreeEntry};
+
+/// Minimum similarity, in percent, for a delete+add pair to count as a rename.
+pub const DEFAULT_RENAME_THRESHOLD: u16 = 50;
+
+/// Shortest abbreviated hash `find_commit_by_prefix` will try to resolve.
+pub const MIN_COMMIT_PREFIX_LEN: usize = 7;
+
+/// Most ahead/behind pairs kept before the memo is emptied.
+const AHEAD_BEHIND_CACHE_LIMIT: usize = 64;
+
+thread_local! {
+    /// Handle a commit parsing worker keeps between batches, with the git
+    /// directory it was opened on.
+    static PARSE_REPOSITORY: RefCell<Option<(PathBuf, Repository)>> = const { RefCell::new(None) };
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum RepositoryType {
@@ -28,6 +47,13 @@ pub struct BranchInfo {
     pub state: BranchState,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BranchFilter {
+    Local,
+    Remote,
+    All,
+}
+
 #[derive(Debug, Clone)]
 pub enum BranchState {
     Normal,
@@ -42,7 +68,23 @@ pub struct GitRepository {
     current_branch: Option<String>,
     is_detached: bool,
     commits: Vec<Commit>,
-    commit_cache: HashMap<CommitId, Commit>,
+    commit_cache: CommitCache,
+    diff_cache: HashMap<CommitId, Vec<DiffInfo>>,
+    range_diff_cache: HashMap<(CommitId, CommitId), Vec<DiffInfo>>,
+    file_history_cache: HashMap<(PathBuf, Option<usize>), Vec<Commit>>,
+    stats_cache: HashMap<CommitId, DiffStats>,
+    describe_cache: RefCell<HashMap<CommitId, String>>,
+    /// Tags as of the last open or `refresh`, which `describe_cache` was
+    /// built against.
+    described_tags: Vec<(String, Option<git2::Oid>)>,
+    ahead_behind_cache: RefCell<HashMap<(git2::Oid, git2::Oid), (usize, usize)>>,
+    ahead_behind_computations: Cell<usize>,
+    diff_algorithm: DiffAlgorithm,
+    rename_threshold: u16,
+    ignore_whitespace: bool,
+    /// Workers `load_commits` parses on; `None` parses on the calling thread.
+    parse_pool: Option<rayon::ThreadPool>,
+    has_more_commits: bool,
 }
 
 impl GitRepository {
@@ -65,6 +107,7 @@ impl GitRepository {
         };
         
         let (current_branch, is_detached) = Self::get_current_branch_info(&repo)?;
+        let described_tags = Self::tag_targets(&repo);
         
         tracing::info!("Repository opened successfully: {:?}, branch: {:?}, detached: {}", 
             repo_type, current_branch, is_detached);
@@ -76,7 +119,20 @@ impl GitRepository {
             current_branch,
             is_detached,
             commits: Vec::new(),
-            commit_cache: HashMap::new(),
+            commit_cache: CommitCache::default(),
+            diff_cache: HashMap::new(),
+            range_diff_cache: HashMap::new(),
+            file_history_cache: HashMap::new(),
+            stats_cache: HashMap::new(),
+            describe_cache: RefCell::new(HashMap::new()),
+            described_tags,
+            ahead_behind_cache: RefCell::new(HashMap::new()),
+            ahead_behind_computations: Cell::new(0),
+            diff_algorithm: DiffAlgorithm::default(),
+            rename_threshold: DEFAULT_RENAME_THRESHOLD,
+            ignore_whitespace: false,
+            parse_pool: None,
+            has_more_commits: false,
         })
     }
 
@@ -104,12 +160,7 @@ impl GitRepository {
                 let is_detached = !head.is_branch();
                 
                 if is_detached {
-                    if let Some(oid) = head.target() {
-                        let short_id = format!("{:.7}", oid);
-                        Ok((Some(format!("HEAD detached at {}", short_id)), true))
-                    } else {
-                        Ok((Some("HEAD (detached)".to_string()), true))
-                    }
+                    Ok((Some(Self::detached_head_name(repo, head.target())), true))
                 } else if let Some(branch_name) = head.shorthand() {
                     Ok((Some(branch_name.to_string()), false))
                 } else {
@@ -117,7 +168,7 @@ impl GitRepository {
                 }
             }
             Err(e) if e.code() == git2::ErrorCode::UnbornBranch => {
-                Ok((Some("main".to_string()), false))
+                Ok((Some(Self::unborn_branch_name(repo)), false))
             }
             Err(e) => {
                 tracing::warn!("Failed to get current branch: {}", e);
@@ -126,6 +177,53 @@ impl GitRepository {
         }
     }
     
+    fn unborn_branch_name(repo: &Repository) -> String {
+        repo.find_reference("HEAD")
+            .ok()
+            .and_then(|head| head.symbolic_target().map(|target| target.to_string()))
+            .and_then(|target| target.strip_prefix("refs/heads/").map(|name| name.to_string()))
+            .unwrap_or_else(|| "main".to_string())
+    }
+    
+    pub fn is_unborn(&self) -> bool {
+        matches!(self.inner.head(), Err(ref e) if e.code() == git2::ErrorCode::UnbornBranch)
+    }
+    
+    /// Whether HEAD has commits to show. False for a freshly initialized
+    /// repository and for a branch with no commits yet.
+    pub fn has_history(&self) -> bool {
+        !self.is_unborn() && !self.inner.is_empty().unwrap_or(true)
+    }
+    
+    /// Only branch refs pointing straight at `target` are read, since this
+    /// runs on every branch info refresh while HEAD is detached.
+    fn detached_head_name(repo: &Repository, target: Option<git2::Oid>) -> String {
+        let Some(oid) = target else {
+            return "HEAD (detached)".to_string();
+        };
+        
+        let mut branches = Vec::new();
+        for glob in ["refs/heads/*", "refs/remotes/*"] {
+            let Ok(references) = repo.references_glob(glob) else {
+                continue;
+            };
+            
+            let mut names: Vec<String> = references
+                .flatten()
+                .filter(|reference| reference.target() == Some(oid))
+                .filter_map(|reference| reference.shorthand().map(str::to_string))
+                .collect();
+            names.sort();
+            branches.extend(names);
+        }
+        
+        if branches.is_empty() {
+            format!("HEAD detached at {:.7}", oid)
+        } else {
+            format!("HEAD detached at {:.7} (== {})", oid, branches.join(", "))
+        }
+    }
+    
     pub fn refresh_branch_info(&mut self) -> Result<()> {
         let (current_branch, is_detached) = Self::get_current_branch_info(&self.inner)?;
         self.current_branch = current_branch;
@@ -142,6 +240,27 @@ impl GitRepository {
     }
     
     pub fn get_branch_info(&self) -> Result<BranchInfo> {
+        self.branch_info(false)
+    }
+    
+    /// Like `get_branch_info`, but reuses ahead/behind counts for a local and
+    /// upstream tip pair already seen, so it is cheap enough to call every
+    /// frame. Moving either tip changes the key, so entries never go stale.
+    pub fn branch_info_cached(&self) -> Result<BranchInfo> {
+        self.branch_info(true)
+    }
+    
+    /// Number of memoized ahead/behind pairs.
+    pub fn ahead_behind_cache_len(&self) -> usize {
+        self.ahead_behind_cache.borrow().len()
+    }
+    
+    /// How many times ahead/behind counts were walked rather than memoized.
+    pub fn ahead_behind_computations(&self) -> usize {
+        self.ahead_behind_computations.get()
+    }
+    
+    fn branch_info(&self, cached: bool) -> Result<BranchInfo> {
         match self.inner.head() {
             Ok(head) => {
                 if head.is_branch() {
@@ -162,7 +281,7 @@ impl GitRepository {
                         .ok()
                         .and_then(|u| u.name().ok().flatten().map(|s| s.to_string()));
                     
-                    let (ahead, behind) = self.calculate_ahead_behind(&branch)?;
+                    let (ahead, behind) = self.calculate_ahead_behind(&branch, cached)?;
                     
                     Ok(BranchInfo {
                         name: branch_name,
@@ -172,14 +291,8 @@ impl GitRepository {
                         state: BranchState::Normal,
                     })
                 } else {
-                    let name = if let Some(oid) = head.target() {
-                        format!("HEAD detached at {:.7}", oid)
-                    } else {
-                        "HEAD (detached)".to_string()
-                    };
-                    
                     Ok(BranchInfo {
-                        name,
+                        name: Self::detached_head_name(&self.inner, head.target()),
                         upstream: None,
                         ahead: 0,
                         behind: 0,
@@ -189,7 +302,7 @@ impl GitRepository {
             }
             Err(e) if e.code() == git2::ErrorCode::UnbornBranch => {
                 Ok(BranchInfo {
-                    name: "main".to_string(),
+                    name: Self::unborn_branch_name(&self.inner),
                     upstream: None,
                     ahead: 0,
                     behind: 0,
@@ -203,7 +316,54 @@ impl GitRepository {
         }
     }
     
-    fn calculate_ahead_behind(&self, branch: &Branch) -> Result<(usize, usize)> {
+    pub fn list_branches(&self, kind: BranchFilter) -> Result<Vec<BranchInfo>> {
+        let branch_type = match kind {
+            BranchFilter::Local => Some(BranchType::Local),
+            BranchFilter::Remote => Some(BranchType::Remote),
+            BranchFilter::All => None,
+        };
+        
+        let branches = self.inner.branches(branch_type)
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to list branches".to_string(),
+                source: e,
+            })?;
+        
+        let mut result = Vec::new();
+        for entry in branches {
+            let (branch, branch_type) = entry.map_err(|e| TwiggyError::Git {
+                message: "Failed to read branch".to_string(),
+                source: e,
+            })?;
+            
+            // Symbolic remote HEADs like origin/HEAD are not branches of their own.
+            if branch.get().symbolic_target().is_some() {
+                continue;
+            }
+            
+            let Some(name) = branch.name().ok().flatten().map(|name| name.to_string()) else {
+                continue;
+            };
+            
+            let upstream = branch.upstream()
+                .ok()
+                .and_then(|u| u.name().ok().flatten().map(|s| s.to_string()));
+            let (ahead, behind) = self.calculate_ahead_behind(&branch, false)?;
+            
+            result.push((branch_type == BranchType::Remote, BranchInfo {
+                name,
+                upstream,
+                ahead,
+                behind,
+                state: BranchState::Normal,
+            }));
+        }
+        
+        result.sort_by(|(a_remote, a), (b_remote, b)| a_remote.cmp(b_remote).then_with(|| a.name.cmp(&b.name)));
+        Ok(result.into_iter().map(|(_, info)| info).collect())
+    }
+    
+    fn calculate_ahead_behind(&self, branch: &Branch, cached: bool) -> Result<(usize, usize)> {
         let local_oid = branch.get().target()
             .ok_or_else(|| TwiggyError::Git {
                 message: "Branch has no target".to_string(),
@@ -212,207 +372,904 @@ impl GitRepository {
         
         if let Ok(upstream) = branch.upstream() {
             if let Some(upstream_oid) = upstream.get().target() {
-                match self.inner.graph_ahead_behind(local_oid, upstream_oid) {
-                    Ok((ahead, behind)) => return Ok((ahead, behind)),
-                    Err(_) => return Ok((0, 0)),
+                let key = (local_oid, upstream_oid);
+                if cached {
+                    if let Some(counts) = self.ahead_behind_cache.borrow().get(&key) {
+                        return Ok(*counts);
+                    }
+                }
+                
+                let counts = self.inner.graph_ahead_behind(local_oid, upstream_oid).unwrap_or((0, 0));
+                self.ahead_behind_computations.set(self.ahead_behind_computations.get() + 1);
+                
+                let mut cache = self.ahead_behind_cache.borrow_mut();
+                if cache.len() >= AHEAD_BEHIND_CACHE_LIMIT {
+                    cache.clear();
                 }
+                cache.insert(key, counts);
+                return Ok(counts);
             }
         }
         
         Ok((0, 0))
     }
     
-    pub fn refresh(&mut self) -> Result<()> {
-        self.refresh_branch_info()?;
-        Ok(())
-    }
-    
-    pub fn repository_name(&self) -> String {
-        self.path
-            .file_name()
-            .and_then(|name| name.to_str())
-            .unwrap_or("Unknown")
-            .to_string()
-    }
-    
-    pub fn path(&self) -> &Path {
-        &self.path
-    }
-    
-    pub fn repo_type(&self) -> &RepositoryType {
-        &self.repo_type
-    }
-    
-    pub fn total_commit_count(&self) -> Result<usize> {
-        let mut revwalk = self.inner.revwalk()
+    /// Position of a commit relative to the nearest tag it descends from,
+    /// like `git describe --tags` (`v1.2.0-5-gabcdef0`). Falls back to the
+    /// short hash when no tag is reachable. Results are cached until
+    /// `refresh` or until the tags change.
+    pub fn describe_commit(&self, id: &CommitId) -> Result<String> {
+        if let Some(description) = self.describe_cache.borrow().get(id) {
+            return Ok(description.clone());
+        }
+        
+        let commit = self.inner.find_commit(id.0)
             .map_err(|e| TwiggyError::Git {
-                message: "Failed to create revwalk".to_string(),
+                message: format!("Failed to find commit {}", id),
                 source: e,
             })?;
         
-        match revwalk.push_head() {
-            Ok(_) => Ok(revwalk.count()),
-            Err(e) if e.code() == git2::ErrorCode::UnbornBranch => Ok(0),
-            Err(e) => Err(TwiggyError::Git {
-                message: "Failed to push HEAD".to_string(),
+        let description = commit.as_object()
+            .describe(git2::DescribeOptions::new().describe_tags().show_commit_oid_as_fallback(true))
+            .and_then(|describe| describe.format(Some(git2::DescribeFormatOptions::new().abbreviated_size(7))))
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to describe commit {}", id),
                 source: e,
-            })
-        }
+            })?;
+        
+        self.describe_cache.borrow_mut().insert(*id, description.clone());
+        Ok(description)
     }
     
-    pub fn validate(&self) -> Result<RepositoryHealth> {
-        if self.inner.is_empty()? {
-            return Ok(RepositoryHealth::Healthy);
-        }
+    pub fn commit_signature_status(&self, id: &CommitId) -> Result<SignatureStatus> {
+        const SIGNATURE_HEADERS: [&str; 3] = [
+            "-----BEGIN PGP SIGNATURE-----",
+            "-----BEGIN SSH SIGNATURE-----",
+            "-----BEGIN SIGNED MESSAGE-----",
+        ];
         
-        let state = self.inner.state();
-        match state {
-            git2::RepositoryState::Clean => Ok(RepositoryHealth::Healthy),
-            git2::RepositoryState::Merge => Ok(RepositoryHealth::InOperation("merge".to_string())),
-            git2::RepositoryState::Revert => Ok(RepositoryHealth::InOperation("revert".to_string())),
-            git2::RepositoryState::CherryPick => Ok(RepositoryHealth::InOperation("cherry-pick".to_string())),
-            git2::RepositoryState::Bisect => Ok(RepositoryHealth::InOperation("bisect".to_string())),
-            git2::RepositoryState::Rebase => Ok(RepositoryHealth::InOperation("rebase".to_string())),
-            git2::RepositoryState::RebaseInteractive => Ok(RepositoryHealth::InOperation("interactive rebase".to_string())),
-            git2::RepositoryState::RebaseMerge => Ok(RepositoryHealth::InOperation("rebase merge".to_string())),
-            git2::RepositoryState::ApplyMailbox => Ok(RepositoryHealth::InOperation("apply mailbox".to_string())),
-            git2::RepositoryState::ApplyMailboxOrRebase => Ok(RepositoryHealth::InOperation("apply mailbox or rebase".to_string())),
-            _ => Ok(RepositoryHealth::Unknown),
+        match self.inner.extract_signature(&id.0, None) {
+            Ok((signature, _)) => {
+                let is_well_formed = signature.as_str()
+                    .map(|text| SIGNATURE_HEADERS.iter().any(|header| text.trim_start().starts_with(header)))
+                    .unwrap_or(false);
+                Ok(if is_well_formed { SignatureStatus::Valid } else { SignatureStatus::Invalid })
+            }
+            Err(e) if e.code() == git2::ErrorCode::NotFound => {
+                // Also returned for a missing commit; tell the two apart.
+                self.inner.find_commit(id.0)
+                    .map_err(|e| TwiggyError::Git {
+                        message: format!("Failed to find commit: {}", id),
+                        source: e,
+                    })?;
+                Ok(SignatureStatus::Unsigned)
+            }
+            Err(e) => {
+                tracing::debug!("Could not read signature of {}: {}", id, e);
+                Ok(SignatureStatus::Unknown)
+            }
         }
     }
     
-    pub fn is_accessible(&self) -> bool {
-        self.path.exists() && self.path.is_dir()
+    /// Best common ancestor of two commits, or `None` for unrelated histories.
+    pub fn merge_base(&self, a: &CommitId, b: &CommitId) -> Result<Option<CommitId>> {
+        match self.inner.merge_base(a.0, b.0) {
+            Ok(oid) => Ok(Some(CommitId(oid))),
+            Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(None),
+            Err(e) => Err(TwiggyError::Git {
+                message: format!("Failed to find merge base of {} and {}", a.short(), b.short()),
+                source: e,
+            }),
+        }
     }
     
-    pub fn check_permissions(&self) -> Result<bool> {
-        use std::fs;
+    /// Whether `id` is HEAD or one of its ancestors.
+    pub fn is_reachable_from_head(&self, id: &CommitId) -> bool {
+        let Ok(head) = self.inner.head().and_then(|head| head.peel_to_commit()) else {
+            return false;
+        };
         
-        if !self.path.exists() {
-            return Ok(false);
-        }
+        head.id() == id.0 || self.inner.graph_descendant_of(head.id(), id.0).unwrap_or(false)
+    }
+    
+    /// Whether `id` is in the history of `branch`, or of HEAD when `branch`
+    /// is `None`. Commits a rebase or force-push left behind still exist as
+    /// objects but are not in the history.
+    pub fn is_in_history(&self, id: &CommitId, branch: Option<&str>) -> bool {
+        let Some(branch) = branch else {
+            return self.is_reachable_from_head(id);
+        };
         
-        match fs::metadata(&self.path) {
-            Ok(metadata) => Ok(!metadata.permissions().readonly()),
-            Err(_) => Ok(false),
-        }
+        let Ok(tip) = self.branch_tip(branch) else {
+            return false;
+        };
+        
+        tip == id.0 || self.inner.graph_descendant_of(tip, id.0).unwrap_or(false)
     }
     
-    pub fn is_empty(&self) -> Result<bool> {
-        self.inner.is_empty().map_err(|e| TwiggyError::Git {
-            message: "Failed to check if repository is empty".to_string(),
-            source: e,
-        })
+    /// Resolves a local or remote branch name; tags and plain revisions work
+    /// too, for viewing history from a commit.
+    fn branch_tip(&self, branch_name: &str) -> std::result::Result<git2::Oid, git2::Error> {
+        self.inner.refname_to_id(&format!("refs/heads/{}", branch_name))
+            .or_else(|_| self.inner.refname_to_id(&format!("refs/remotes/{}", branch_name)))
+            .or_else(|_| self.inner.revparse_single(branch_name).and_then(|object| object.peel_to_commit()).map(|commit| commit.id()))
     }
     
-    pub fn workdir(&self) -> Option<&Path> {
-        self.inner.workdir()
+    pub fn refresh(&mut self) -> Result<()> {
+        self.refresh_branch_info()?;
+        self.describe_cache.get_mut().clear();
+        self.described_tags = Self::tag_targets(&self.inner);
+        Ok(())
     }
-
-    pub fn load_commits(&mut self, limit: Option<usize>) -> Result<()> {
-        tracing::info!("Loading commits from repository");
-        let start = std::time::Instant::now();
+    
+    pub fn ref_labels(&self) -> Result<HashMap<CommitId, Vec<RefLabel>>> {
+        Self::collect_ref_labels(&self.inner)
+    }
+    
+    pub fn refs_at(&self, id: &CommitId) -> Result<Vec<RefLabel>> {
+        Ok(Self::collect_ref_labels(&self.inner)?
+            .remove(id)
+            .unwrap_or_default())
+    }
+    
+    fn collect_ref_labels(repo: &Repository) -> Result<HashMap<CommitId, Vec<RefLabel>>> {
+        let mut labels: HashMap<CommitId, Vec<RefLabel>> = HashMap::new();
         
-        if self.inner.is_empty().unwrap_or(true) {
-            tracing::warn!("Repository is empty, no commits to load");
-            return Ok(());
+        if let Ok(head) = repo.head() {
+            if let Ok(commit) = head.peel_to_commit() {
+                labels.entry(CommitId(commit.id())).or_default().push(RefLabel {
+                    name: "HEAD".to_string(),
+                    kind: RefKind::Head,
+                });
+            }
         }
         
-        let mut revwalk = self.inner.revwalk()
+        let references = repo.references()
             .map_err(|e| TwiggyError::Git {
-                message: "Failed to create revwalk".to_string(),
+                message: "Failed to list references".to_string(),
                 source: e,
             })?;
         
-        revwalk.push_head()
-            .map_err(|e| TwiggyError::Git {
-                message: "Failed to push HEAD".to_string(),
-                source: e,
-            })?;
+        for reference in references {
+            let reference = match reference {
+                Ok(reference) => reference,
+                Err(e) => {
+                    tracing::debug!("Skipping unreadable reference: {}", e);
+                    continue;
+                }
+            };
+            
+            if reference.symbolic_target().is_some() {
+                continue;
+            }
+            
+            let kind = if reference.is_branch() {
+                RefKind::LocalBranch
+            } else if reference.is_remote() {
+                RefKind::RemoteBranch
+            } else if reference.is_tag() {
+                RefKind::Tag
+            } else {
+                continue;
+            };
+            
+            let Some(name) = reference.shorthand() else {
+                continue;
+            };
+            
+            if let Ok(commit) = reference.peel_to_commit() {
+                labels.entry(CommitId(commit.id())).or_default().push(RefLabel {
+                    name: name.to_string(),
+                    kind,
+                });
+            }
+        }
         
-        revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)
+        for commit_labels in labels.values_mut() {
+            commit_labels.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.name.cmp(&b.name)));
+        }
+        
+        Ok(labels)
+    }
+    
+    pub fn list_tags(&self) -> Result<Vec<TagRef>> {
+        let names = self.inner.tag_names(None)
             .map_err(|e| TwiggyError::Git {
-                message: "Failed to set sorting".to_string(),
+                message: "Failed to list tags".to_string(),
                 source: e,
             })?;
         
-        let max_commits = limit.unwrap_or(1000);
-        let mut commits = Vec::new();
-        let mut loaded_count = 0;
-        
-        for (index, oid) in revwalk.enumerate() {
-            if index >= max_commits {
-                tracing::debug!("Reached commit limit of {}", max_commits);
-                break;
-            }
-            
-            let oid = oid.map_err(|e| TwiggyError::Git {
-                message: "Failed to get commit OID".to_string(),
-                source: e,
-            })?;
-            
-            match self.parse_commit(oid) {
-                Ok(commit) => {
-                    self.commit_cache.insert(commit.id, commit.clone());
-                    commits.push(commit);
-                    loaded_count += 1;
-                }
+        let mut tags = Vec::with_capacity(names.len());
+        for name in names.iter().flatten() {
+            let reference = match self.inner.find_reference(&format!("refs/tags/{}", name)) {
+                Ok(reference) => reference,
                 Err(e) => {
-                    tracing::warn!("Failed to parse commit {}: {}", oid, e);
+                    tracing::debug!("Skipping unreadable tag {}: {}", name, e);
                     continue;
                 }
-            }
+            };
             
-            if loaded_count % 100 == 0 {
-                tracing::debug!("Loaded {} commits so far", loaded_count);
-            }
+            // Tags pointing at trees or blobs have no commit to show them on.
+            let Ok(commit) = reference.peel_to_commit() else {
+                continue;
+            };
+            
+            let message = reference.peel_to_tag()
+                .ok()
+                .and_then(|tag| tag.message().map(|message| message.trim_end().to_string()));
+            
+            tags.push(TagRef {
+                name: name.to_string(),
+                target: CommitId(commit.id()),
+                message,
+            });
         }
         
-        let elapsed = start.elapsed();
-        tracing::info!("Loaded {} commits in {:?}", commits.len(), elapsed);
+        Ok(tags)
+    }
+    
+    // stash_foreach needs a mutable repository, hence &mut self.
+    pub fn list_stashes(&mut self) -> Result<Vec<StashEntry>> {
+        let mut stashes = Vec::new();
         
-        if commits.is_empty() {
-            tracing::warn!("No commits were loaded from repository");
-        }
+        self.inner.stash_foreach(|index, message, oid| {
+            stashes.push(StashEntry {
+                index,
+                message: message.to_string(),
+                id: CommitId(*oid),
+            });
+            true
+        })
+        .map_err(|e| TwiggyError::Git {
+            message: "Failed to list stashes".to_string(),
+            source: e,
+        })?;
         
-        self.commits = commits;
-        Ok(())
+        tracing::debug!("Found {} stashes", stashes.len());
+        Ok(stashes)
     }
     
-    fn parse_commit(&self, oid: git2::Oid) -> Result<Commit> {
-        let commit = self.inner.find_commit(oid)
+    fn local_config(&self) -> Result<git2::Config> {
+        self.inner.config()
+            .and_then(|config| config.open_level(git2::ConfigLevel::Local))
             .map_err(|e| TwiggyError::Git {
-                message: format!("Failed to find commit {}", oid),
+                message: "Failed to open repository config".to_string(),
+                source: e,
+            })
+    }
+    
+    pub fn get_config_entries(&self, prefix: Option<&str>) -> Result<Vec<(String, String)>> {
+        let config = self.local_config()?;
+        let mut entries = config.entries(None)
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to read repository config".to_string(),
                 source: e,
             })?;
         
-        let message = commit.message().unwrap_or("").to_string();
-        let summary = commit.summary().unwrap_or("").to_string();
-        
-        if message.is_empty() && summary.is_empty() {
-            tracing::debug!("Commit {} has empty message", oid);
+        let mut result: Vec<(String, String)> = Vec::new();
+        while let Some(entry) = entries.next() {
+            let entry = entry.map_err(|e| TwiggyError::Git {
+                message: "Failed to read repository config entry".to_string(),
+                source: e,
+            })?;
+            
+            let (Some(name), Some(value)) = (entry.name(), entry.value()) else {
+                continue;
+            };
+            
+            if prefix.is_none_or(|prefix| name.starts_with(prefix)) {
+                result.push((name.to_string(), value.to_string()));
+            }
         }
         
-        let parents: Vec<CommitId> = commit
-            .parent_ids()
-            .map(|id| CommitId(id))
-            .collect();
+        result.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(result)
+    }
+    
+    pub fn get_effective_config_value(&self, key: &str) -> Option<String> {
+        self.inner.config().ok()?.get_string(key).ok()
+    }
+    
+    /// Sets or, for an empty `value`, removes a single-valued key. Keys with
+    /// several values (such as `remote.origin.fetch`) are refused.
+    pub fn set_config_entry(&self, key: &str, value: &str) -> Result<()> {
+        validate_config_entry(key, value)?;
         
-        let tree_id = commit.tree_id().to_string();
+        let mut config = self.local_config()?;
+        let mut value_count = 0;
+        if let Ok(mut values) = config.multivar(key, None) {
+            while let Some(Ok(_)) = values.next() {
+                value_count += 1;
+            }
+        }
+        if value_count > 1 {
+            return Err(TwiggyError::Validation {
+                field: key.to_string(),
+                message: format!("has {} values and can't be edited as one", value_count),
+            });
+        }
         
-        let author = match commit.author().name() {
-            Some(_) => Signature::from(&commit.author()),
-            None => {
-                tracing::debug!("Commit {} has invalid author signature", oid);
-                Signature {
-                    name: "Unknown".to_string(),
-                    email: "".to_string(),
-                    time: chrono::Utc::now(),
-                }
+        tracing::info!("Setting repository config {} = {}", key, value);
+        
+        let result = if value.is_empty() {
+            match config.remove(key) {
+                Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(()),
+                other => other,
             }
+        } else {
+            config.set_str(key, value)
         };
         
-        let committer = match commit.committer().name() {
+        result.map_err(|e| TwiggyError::Git {
+            message: format!("Failed to set config {}", key),
+            source: e,
+        })
+    }
+    
+    pub fn remote_names(&self) -> Result<Vec<String>> {
+        let remotes = self.inner.remotes()
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to list remotes".to_string(),
+                source: e,
+            })?;
+        
+        Ok(remotes.iter().flatten().map(|name| name.to_string()).collect())
+    }
+    
+    pub fn list_remotes(&self) -> Result<Vec<RemoteInfo>> {
+        self.remote_names()?
+            .into_iter()
+            .map(|name| {
+                let remote = self.inner.find_remote(&name)
+                    .map_err(|e| TwiggyError::Git {
+                        message: format!("Failed to find remote: {}", name),
+                        source: e,
+                    })?;
+                let fetch_url = remote.url().map(|url| url.to_string());
+                let push_url = remote.pushurl().map(|url| url.to_string()).or_else(|| fetch_url.clone());
+                
+                Ok(RemoteInfo {
+                    name,
+                    fetch_url,
+                    push_url,
+                })
+            })
+            .collect()
+    }
+    
+    pub fn list_submodules(&self) -> Result<Vec<SubmoduleInfo>> {
+        let submodules = self.inner.submodules()
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to list submodules".to_string(),
+                source: e,
+            })?;
+        
+        Ok(submodules
+            .iter()
+            .map(|submodule| SubmoduleInfo {
+                name: submodule.name().unwrap_or_default().to_string(),
+                path: submodule.path().to_string_lossy().to_string(),
+                url: submodule.url().map(|url| url.to_string()),
+                head_id: submodule.head_id().map(CommitId),
+                workdir_id: submodule.workdir_id().map(CommitId),
+            })
+            .collect())
+    }
+    
+    /// `origin` if it exists, otherwise the first configured remote.
+    pub fn default_remote(&self) -> Option<String> {
+        let remotes = self.remote_names().ok()?;
+        remotes.iter()
+            .find(|name| *name == "origin")
+            .or_else(|| remotes.first())
+            .cloned()
+    }
+    
+    pub fn remote_url(&self, remote: &str) -> Option<String> {
+        self.inner.find_remote(remote)
+            .ok()
+            .and_then(|remote| remote.url().map(|url| url.to_string()))
+    }
+    
+    pub fn list_remote_refs(&self, remote: &str) -> Result<Vec<String>> {
+        let mut remote_handle = self.inner.find_remote(remote)
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to find remote: {}", remote),
+                source: e,
+            })?;
+        
+        remote_handle.connect(Direction::Fetch)
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to connect to remote: {}", remote),
+                source: e,
+            })?;
+        
+        let refs: Vec<String> = remote_handle.list()
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to list refs on remote: {}", remote),
+                source: e,
+            })?
+            .iter()
+            .map(|head| head.name().to_string())
+            .filter(|name| {
+                (name.starts_with("refs/heads/") || name.starts_with("refs/tags/")) && !name.ends_with("^{}")
+            })
+            .collect();
+        
+        let _ = remote_handle.disconnect();
+        
+        tracing::info!("Remote {} advertises {} refs", remote, refs.len());
+        Ok(refs)
+    }
+    
+    /// Fetches from `remote`. With `refs` set, only those remote ref names
+    /// (as returned by `list_remote_refs`) are fetched; otherwise the
+    /// remote's configured refspecs are used.
+    pub fn fetch(&self, remote: &str, refs: Option<&[String]>) -> Result<FetchSummary> {
+        let mut remote_handle = self.inner.find_remote(remote)
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to find remote: {}", remote),
+                source: e,
+            })?;
+        
+        let mut summary = FetchSummary {
+            remote: remote.to_string(),
+            ..Default::default()
+        };
+        
+        if refs.is_some_and(|refs| refs.is_empty()) {
+            return Ok(summary);
+        }
+        
+        let refspecs: Vec<String> = refs
+            .unwrap_or_default()
+            .iter()
+            .map(|name| match name.strip_prefix("refs/heads/") {
+                Some(branch) => format!("+{}:refs/remotes/{}/{}", name, remote, branch),
+                None => format!("+{}:{}", name, name),
+            })
+            .collect();
+        
+        tracing::info!("Fetching from {} ({} refspecs)", remote, if refs.is_some() { refspecs.len().to_string() } else { "default".to_string() });
+        
+        let updated_refs = std::cell::Cell::new(0);
+        let mut callbacks = git2::RemoteCallbacks::new();
+        callbacks.update_tips(|_, _, _| {
+            updated_refs.set(updated_refs.get() + 1);
+            true
+        });
+        let mut options = git2::FetchOptions::new();
+        options.remote_callbacks(callbacks);
+        
+        remote_handle.fetch(&refspecs, Some(&mut options), None)
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to fetch from {}", remote),
+                source: e,
+            })?;
+        
+        summary.received_objects = remote_handle.stats().received_objects();
+        drop(options);
+        summary.updated_refs = updated_refs.get();
+        tracing::info!("Fetched from {}: {} refs updated, {} objects received", remote, summary.updated_refs, summary.received_objects);
+        Ok(summary)
+    }
+    
+    /// Fetches `remote` using its configured refspecs.
+    pub fn fetch_remote(&self, remote: &str) -> Result<FetchSummary> {
+        self.fetch(remote, None)
+    }
+    
+    pub fn checkout_branch(&mut self, branch_name: &str) -> Result<()> {
+        tracing::info!("Checking out branch: {}", branch_name);
+        
+        let branch = self.inner.find_branch(branch_name, BranchType::Local)
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to find branch: {}", branch_name),
+                source: e,
+            })?;
+        
+        let refname = branch.get().name()
+            .ok_or_else(|| TwiggyError::Git {
+                message: "Invalid branch name".to_string(),
+                source: git2::Error::from_str("Invalid branch name"),
+            })?
+            .to_string();
+        
+        let target = branch.get().peel(git2::ObjectType::Commit)
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to resolve branch {}", branch_name),
+                source: e,
+            })?;
+        
+        self.inner.checkout_tree(&target, Some(git2::build::CheckoutBuilder::new().safe()))
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to check out branch {}", branch_name),
+                source: e,
+            })?;
+        
+        self.inner.set_head(&refname)
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to update HEAD to {}", branch_name),
+                source: e,
+            })?;
+        
+        drop(target);
+        drop(branch);
+        self.refresh_branch_info()
+    }
+    
+    pub fn repository_name(&self) -> String {
+        self.path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("Unknown")
+            .to_string()
+    }
+    
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    
+    pub fn repo_type(&self) -> &RepositoryType {
+        &self.repo_type
+    }
+    
+    pub fn total_commit_count(&self) -> Result<usize> {
+        let mut revwalk = self.inner.revwalk()
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to create revwalk".to_string(),
+                source: e,
+            })?;
+        
+        match revwalk.push_head() {
+            Ok(_) => Ok(revwalk.count()),
+            Err(e) if e.code() == git2::ErrorCode::UnbornBranch => Ok(0),
+            Err(e) => Err(TwiggyError::Git {
+                message: "Failed to push HEAD".to_string(),
+                source: e,
+            })
+        }
+    }
+    
+    pub fn validate(&self) -> Result<RepositoryHealth> {
+        let is_empty = match self.inner.is_empty() {
+            Ok(is_empty) => is_empty,
+            Err(e) => {
+                let error = TwiggyError::from(e);
+                if error.is_corruption() {
+                    return Ok(RepositoryHealth::Corrupted);
+                }
+                return Err(error);
+            }
+        };
+        
+        if is_empty {
+            return Ok(RepositoryHealth::Healthy);
+        }
+        
+        let state = self.inner.state();
+        match state {
+            git2::RepositoryState::Clean => Ok(RepositoryHealth::Healthy),
+            git2::RepositoryState::Merge => Ok(RepositoryHealth::InOperation("merge".to_string())),
+            git2::RepositoryState::Revert => Ok(RepositoryHealth::InOperation("revert".to_string())),
+            git2::RepositoryState::CherryPick => Ok(RepositoryHealth::InOperation("cherry-pick".to_string())),
+            git2::RepositoryState::Bisect => Ok(RepositoryHealth::InOperation("bisect".to_string())),
+            git2::RepositoryState::Rebase => Ok(RepositoryHealth::InOperation("rebase".to_string())),
+            git2::RepositoryState::RebaseInteractive => Ok(RepositoryHealth::InOperation("interactive rebase".to_string())),
+            git2::RepositoryState::RebaseMerge => Ok(RepositoryHealth::InOperation("rebase merge".to_string())),
+            git2::RepositoryState::ApplyMailbox => Ok(RepositoryHealth::InOperation("apply mailbox".to_string())),
+            git2::RepositoryState::ApplyMailboxOrRebase => Ok(RepositoryHealth::InOperation("apply mailbox or rebase".to_string())),
+            _ => Ok(RepositoryHealth::Unknown),
+        }
+    }
+    
+    pub fn is_accessible(&self) -> bool {
+        self.path.exists() && self.path.is_dir()
+    }
+    
+    pub fn check_permissions(&self) -> Result<bool> {
+        use std::fs;
+        
+        if !self.path.exists() {
+            return Ok(false);
+        }
+        
+        match fs::metadata(&self.path) {
+            Ok(metadata) => Ok(!metadata.permissions().readonly()),
+            Err(_) => Ok(false),
+        }
+    }
+    
+    /// Number of changed paths; a partly staged file counts once.
+    pub fn count_uncommitted_changes(&self) -> Result<usize> {
+        let entries = self.working_status()?;
+        let paths: std::collections::HashSet<&str> = entries.iter().map(|entry| entry.path.as_str()).collect();
+        Ok(paths.len())
+    }
+    
+    /// Changed paths in the working directory, sorted by category and path.
+    /// Ignored files are left out; a bare repository has no entries.
+    pub fn working_status(&self) -> Result<Vec<StatusEntry>> {
+        if self.inner.is_bare() {
+            return Ok(Vec::new());
+        }
+        
+        let mut options = git2::StatusOptions::new();
+        options
+            .include_untracked(true)
+            .recurse_untracked_dirs(true)
+            .include_ignored(false)
+            .renames_head_to_index(true);
+        
+        let statuses = self.inner.statuses(Some(&mut options))
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to read repository status".to_string(),
+                source: e,
+            })?;
+        
+        let staged = git2::Status::INDEX_NEW
+            | git2::Status::INDEX_MODIFIED
+            | git2::Status::INDEX_DELETED
+            | git2::Status::INDEX_RENAMED
+            | git2::Status::INDEX_TYPECHANGE;
+        let unstaged = git2::Status::WT_MODIFIED
+            | git2::Status::WT_DELETED
+            | git2::Status::WT_RENAMED
+            | git2::Status::WT_TYPECHANGE;
+        
+        let mut entries = Vec::new();
+        for entry in statuses.iter() {
+            let Some(path) = entry.path() else {
+                continue;
+            };
+            let status = entry.status();
+            let mut push = |category| entries.push(StatusEntry { path: path.to_string(), category });
+            
+            if status.is_conflicted() {
+                push(StatusCategory::Conflicted);
+                continue;
+            }
+            if status.intersects(staged) {
+                push(StatusCategory::Staged);
+            }
+            if status.intersects(unstaged) {
+                push(StatusCategory::Unstaged);
+            }
+            if status.is_wt_new() {
+                push(StatusCategory::Untracked);
+            }
+        }
+        
+        entries.sort_by(|a, b| a.category.cmp(&b.category).then_with(|| a.path.cmp(&b.path)));
+        Ok(entries)
+    }
+    
+    pub fn is_empty(&self) -> Result<bool> {
+        self.inner.is_empty().map_err(|e| TwiggyError::Git {
+            message: "Failed to check if repository is empty".to_string(),
+            source: e,
+        })
+    }
+    
+    pub fn workdir(&self) -> Option<&Path> {
+        self.inner.workdir()
+    }
+
+    pub fn load_commits(&mut self, limit: Option<usize>) -> Result<()> {
+        self.load_commits_with_progress(limit, |_, _| {})
+    }
+    
+    /// Loads commits like `load_commits`, calling `on_progress` every
+    /// `PROGRESS_INTERVAL` commits (and once at the end) with the number loaded
+    /// and the expected total. The total is `None` until the revwalk has
+    /// collected the commit ids, then the number of commits to parse.
+    pub fn load_commits_with_progress(
+        &mut self,
+        limit: Option<usize>,
+        on_progress: impl FnMut(usize, Option<usize>),
+    ) -> Result<()> {
+        self.load_commits_cancellable(limit, &AtomicBool::new(false), on_progress)
+    }
+    
+    /// Like `load_commits_with_progress`, but stops early once `cancel` is
+    /// set, keeping the commits parsed so far. Only fully parsed commits are
+    /// cached, so a cancelled load leaves the cache consistent.
+    pub fn load_commits_cancellable(
+        &mut self,
+        limit: Option<usize>,
+        cancel: &AtomicBool,
+        mut on_progress: impl FnMut(usize, Option<usize>),
+    ) -> Result<()> {
+        const PROGRESS_INTERVAL: usize = 100;
+        
+        tracing::info!("Loading commits from repository");
+        let start = std::time::Instant::now();
+        
+        if self.inner.is_empty().unwrap_or(true) || self.is_unborn() {
+            tracing::warn!("Repository has no commits on the current branch, nothing to load");
+            self.commits.clear();
+            self.has_more_commits = false;
+            return Ok(());
+        }
+        
+        let mut revwalk = self.inner.revwalk()
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to create revwalk".to_string(),
+                source: e,
+            })?;
+        
+        revwalk.push_head()
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to push HEAD".to_string(),
+                source: e,
+            })?;
+        
+        revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to set sorting".to_string(),
+                source: e,
+            })?;
+        
+        let max_commits = limit.unwrap_or(1000);
+        let mut commits = Vec::new();
+        let mut loaded_count = 0;
+        
+        on_progress(0, None);
+        
+        let is_cancelled = || cancel.load(Ordering::Relaxed);
+        
+        let mut oids = Vec::new();
+        for oid in revwalk.take(max_commits) {
+            if oids.len() % PROGRESS_INTERVAL == 0 && is_cancelled() {
+                break;
+            }
+            
+            oids.push(oid.map_err(|e| TwiggyError::Git {
+                message: "Failed to get commit OID".to_string(),
+                source: e,
+            })?);
+        }
+        
+        let expected_total = Some(oids.len());
+        on_progress(0, expected_total);
+        
+        let batch_size = PROGRESS_INTERVAL * self.parse_pool.as_ref().map_or(1, |pool| pool.current_num_threads());
+        
+        for batch in oids.chunks(batch_size) {
+            if is_cancelled() {
+                break;
+            }
+            
+            let parsed = match self.parse_pool {
+                Some(ref pool) => self.parse_commits_parallel(pool, batch),
+                None => batch.iter().map(|oid| (*oid, self.parse_commit(*oid))).collect(),
+            };
+            
+            for (oid, result) in parsed {
+                match result {
+                    Ok(commit) => {
+                        self.commit_cache.insert(commit.clone());
+                        commits.push(commit);
+                        loaded_count += 1;
+                    }
+                    Err(e) => tracing::warn!("Failed to parse commit {}: {}", oid, e),
+                }
+            }
+            
+            tracing::debug!("Loaded {} commits so far", loaded_count);
+            on_progress(loaded_count, expected_total);
+        }
+        
+        if is_cancelled() {
+            tracing::info!("Commit loading cancelled after {} commits", loaded_count);
+        } else if oids.len() == max_commits {
+            tracing::debug!("Reached commit limit of {}", max_commits);
+        }
+        
+        on_progress(loaded_count, expected_total);
+        
+        let elapsed = start.elapsed();
+        tracing::info!("Loaded {} commits in {:?}", commits.len(), elapsed);
+        
+        if commits.is_empty() {
+            tracing::warn!("No commits were loaded from repository");
+        }
+        
+        self.commits = commits;
+        self.has_more_commits = oids.len() == max_commits || is_cancelled();
+        Ok(())
+    }
+    
+    /// Number of threads `load_commits` parses commits on; 1 parses on the
+    /// calling thread.
+    pub fn set_parse_threads(&mut self, threads: usize) {
+        let threads = threads.max(1);
+        let current = self.parse_pool.as_ref().map_or(1, |pool| pool.current_num_threads());
+        if threads == current {
+            return;
+        }
+        
+        self.parse_pool = if threads == 1 {
+            None
+        } else {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .thread_name(|index| format!("twiggy-parse-{}", index))
+                .build()
+                .map_err(|e| tracing::warn!("Falling back to serial commit parsing: {}", e))
+                .ok()
+        };
+    }
+    
+    /// Parses `oids` on `pool`, keeping their order. `git2::Repository` isn't
+    /// `Sync`, so each worker thread opens its own handle on the same
+    /// repository once and reuses it for later batches.
+    fn parse_commits_parallel(&self, pool: &rayon::ThreadPool, oids: &[git2::Oid]) -> Vec<(git2::Oid, Result<Commit>)> {
+        use rayon::prelude::*;
+        
+        let git_dir = self.inner.path();
+        pool.install(|| {
+            oids.par_iter()
+                .map(|oid| (*oid, Self::parse_commit_on_worker(git_dir, *oid)))
+                .collect()
+        })
+    }
+    
+    fn parse_commit_on_worker(git_dir: &Path, oid: git2::Oid) -> Result<Commit> {
+        PARSE_REPOSITORY.with(|slot| {
+            let mut slot = slot.borrow_mut();
+            let repo = match slot.as_mut() {
+                Some((path, repo)) if path == git_dir => repo,
+                _ => {
+                    let repo = Repository::open(git_dir).map_err(|e| TwiggyError::Git {
+                        message: format!("Failed to open repository for parsing: {}", git_dir.display()),
+                        source: e,
+                    })?;
+                    &mut slot.insert((git_dir.to_path_buf(), repo)).1
+                }
+            };
+            Self::parse_commit_in(repo, oid)
+        })
+    }
+    
+    fn parse_commit(&self, oid: git2::Oid) -> Result<Commit> {
+        Self::parse_commit_in(&self.inner, oid)
+    }
+    
+    fn parse_commit_in(repo: &Repository, oid: git2::Oid) -> Result<Commit> {
+        let commit = repo.find_commit(oid)
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to find commit {}", oid),
+                source: e,
+            })?;
+        
+        let message = commit.message().unwrap_or("").to_string();
+        let summary = commit.summary().unwrap_or("").to_string();
+        
+        if message.is_empty() && summary.is_empty() {
+            tracing::debug!("Commit {} has empty message", oid);
+        }
+        
+        let parents: Vec<CommitId> = commit
+            .parent_ids()
+            .map(|id| CommitId(id))
+            .collect();
+        
+        let tree_id = commit.tree_id().to_string();
+        
+        let author = match commit.author().name() {
+            Some(_) => Signature::from(&commit.author()),
+            None => {
+                tracing::debug!("Commit {} has invalid author signature", oid);
+                Signature {
+                    name: "Unknown".to_string(),
+                    email: "".to_string(),
+                    time: chrono::Utc::now(),
+                }
+            }
+        };
+        
+        let committer = match commit.committer().name() {
             Some(_) => Signature::from(&commit.committer()),
             None => {
                 tracing::debug!("Commit {} has invalid committer signature", oid);
@@ -440,80 +1297,630 @@ impl GitRepository {
     }
 
     pub fn get_commit_by_id(&self, id: &CommitId) -> Option<&Commit> {
-        self.commit_cache.get(id)
+        self.commit_cache.peek(id)
+            .or_else(|| self.commits.iter().find(|commit| commit.id == *id))
     }
 
     pub fn commit_count(&self) -> usize {
         self.commits.len()
     }
-
-    pub fn load_commits_lazy(&mut self, start: usize, count: usize) -> Result<Vec<Commit>> {
-        tracing::debug!("Loading commits lazily: start={}, count={}", start, count);
+    
+    /// Commit counts per author over the loaded commits, most active first.
+    /// Emails are compared case-insensitively.
+    pub fn author_stats(&self) -> Result<Vec<AuthorStat>> {
+        let mut by_email: HashMap<String, AuthorStat> = HashMap::new();
+        
+        for commit in &self.commits {
+            let author = &commit.author;
+            let stat = by_email.entry(author.email.to_lowercase()).or_insert_with(|| AuthorStat {
+                name: author.name.clone(),
+                email: author.email.clone(),
+                commit_count: 0,
+                first_commit: author.time,
+                last_commit: author.time,
+            });
+            
+            stat.commit_count += 1;
+            if author.time < stat.first_commit {
+                stat.first_commit = author.time;
+            }
+            if author.time > stat.last_commit {
+                stat.last_commit = author.time;
+                stat.name = author.name.clone();
+            }
+        }
+        
+        let mut stats: Vec<AuthorStat> = by_email.into_values().collect();
+        stats.sort_by(|a, b| b.commit_count.cmp(&a.commit_count).then_with(|| a.name.cmp(&b.name)));
+        Ok(stats)
+    }
+
+    pub fn load_commits_lazy(&mut self, start: usize, count: usize) -> Result<Vec<Commit>> {
+        tracing::debug!("Loading commits lazily: start={}, count={}", start, count);
+        
+        if start < self.commits.len() {
+            let end = (start + count).min(self.commits.len());
+            return Ok(self.commits[start..end].to_vec());
+        }
+        
+        if self.is_unborn() {
+            return Ok(Vec::new());
+        }
+        
+        let mut revwalk = self.inner.revwalk()
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to create revwalk for lazy loading".to_string(),
+                source: e,
+            })?;
+        
+        revwalk.push_head()
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to push HEAD for lazy loading".to_string(),
+                source: e,
+            })?;
+        
+        revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to set sorting for lazy loading".to_string(),
+                source: e,
+            })?;
+        
+        let mut commits = Vec::new();
+        for (index, oid) in revwalk.enumerate() {
+            if index < start {
+                continue;
+            }
+            if index >= start + count {
+                break;
+            }
+            
+            let oid = oid.map_err(|e| TwiggyError::Git {
+                message: "Failed to get commit OID in lazy loading".to_string(),
+                source: e,
+            })?;
+            
+            if let Some(cached_commit) = self.commit_cache.get(&CommitId(oid)) {
+                commits.push(cached_commit.clone());
+            } else {
+                let commit = self.parse_commit(oid)?;
+                self.commit_cache.insert(commit.clone());
+                commits.push(commit);
+            }
+        }
+        
+        Ok(commits)
+    }
+
+    /// Appends the next `count` commits of history to the loaded ones and
+    /// returns how many were added. Commits already in `commit_cache` aren't
+    /// parsed again.
+    pub fn load_more_commits(&mut self, count: usize) -> Result<usize> {
+        if !self.has_more_commits {
+            return Ok(0);
+        }
+        
+        let more = match self.load_commits_lazy(self.commits.len(), count) {
+            Ok(more) => more,
+            Err(e) => {
+                // Don't keep asking for a page that can't be read.
+                self.has_more_commits = false;
+                return Err(e);
+            }
+        };
+        let added = more.len();
+        self.has_more_commits = added == count;
+        self.commits.extend(more);
+        
+        tracing::info!("Loaded {} more commits ({} total)", added, self.commits.len());
+        Ok(added)
+    }
+    
+    /// Whether history continues past the loaded commits, as far as the last
+    /// load could tell.
+    pub fn has_more_commits(&self) -> bool {
+        self.has_more_commits
+    }
+    
+    pub fn refresh_commits(&mut self, limit: Option<usize>) -> Result<()> {
+        tracing::info!("Refreshing commit data");
+        self.commits.clear();
+        self.load_commits(limit)
+    }
+
+    pub fn clear_commit_cache(&mut self) {
+        tracing::debug!("Clearing commit cache");
+        self.commit_cache.clear();
+    }
+
+    pub fn cache_size(&self) -> usize {
+        self.commit_cache.len()
+    }
+    
+    pub fn cache_size_bytes(&self) -> usize {
+        self.commit_cache.size_bytes()
+    }
+    
+    pub fn set_cache_limit_bytes(&mut self, limit_bytes: usize) {
+        tracing::debug!("Commit cache limit set to {} bytes", limit_bytes);
+        self.commit_cache.set_limit(limit_bytes);
+    }
+
+    pub fn diff_algorithm(&self) -> DiffAlgorithm {
+        self.diff_algorithm
+    }
+    
+    pub fn set_diff_algorithm(&mut self, algorithm: DiffAlgorithm) {
+        if self.diff_algorithm != algorithm {
+            tracing::info!("Diff algorithm changed to {}", algorithm);
+            self.diff_algorithm = algorithm;
+            self.diff_cache.clear();
+            self.range_diff_cache.clear();
+            self.stats_cache.clear();
+        }
+    }
+    
+    pub fn rename_threshold(&self) -> u16 {
+        self.rename_threshold
+    }
+    
+    pub fn set_rename_threshold(&mut self, threshold: u16) {
+        let threshold = threshold.min(100);
+        if self.rename_threshold != threshold {
+            self.rename_threshold = threshold;
+            self.diff_cache.clear();
+            self.range_diff_cache.clear();
+            self.file_history_cache.clear();
+            self.stats_cache.clear();
+        }
+    }
+    
+    pub fn ignore_whitespace(&self) -> bool {
+        self.ignore_whitespace
+    }
+    
+    /// When set, diffs and stats leave out whitespace-only changes.
+    pub fn set_ignore_whitespace(&mut self, ignore: bool) {
+        if self.ignore_whitespace != ignore {
+            tracing::info!("Ignore whitespace in diffs: {}", ignore);
+            self.ignore_whitespace = ignore;
+            self.diff_cache.clear();
+            self.range_diff_cache.clear();
+            self.stats_cache.clear();
+        }
+    }
+    
+    fn diff_options(&self) -> git2::DiffOptions {
+        let mut options = git2::DiffOptions::new();
+        self.diff_algorithm.apply(&mut options);
+        options.ignore_whitespace(self.ignore_whitespace);
+        options
+    }
+    
+    fn commit_tree(&self, id: &CommitId) -> Result<git2::Tree<'_>> {
+        let commit = self.inner.find_commit(id.0)
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to find commit: {}", id),
+                source: e,
+            })?;
+        
+        commit.tree()
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to read tree of commit {}", id),
+                source: e,
+            })
+    }
+    
+    fn commit_diff(&self, id: &CommitId) -> Result<git2::Diff<'_>> {
+        let commit = self.inner.find_commit(id.0)
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to find commit: {}", id),
+                source: e,
+            })?;
+        
+        let new_tree = commit.tree()
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to read tree of commit {}", id),
+                source: e,
+            })?;
+        
+        let old_tree = match commit.parent(0) {
+            Ok(parent) => Some(parent.tree().map_err(|e| TwiggyError::Git {
+                message: format!("Failed to read parent tree of commit {}", id),
+                source: e,
+            })?),
+            Err(_) => None,
+        };
+        
+        self.tree_diff(old_tree.as_ref(), &new_tree, &format!("commit {}", id))
+    }
+    
+    /// Tree-to-tree diff with the repository's diff settings and rename
+    /// detection applied; `subject` names what is diffed in error messages.
+    fn tree_diff(&self, old_tree: Option<&git2::Tree<'_>>, new_tree: &git2::Tree<'_>, subject: &str) -> Result<git2::Diff<'_>> {
+        let mut options = self.diff_options();
+        let mut diff = self.inner.diff_tree_to_tree(old_tree, Some(new_tree), Some(&mut options))
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to diff {}", subject),
+                source: e,
+            })?;
+        
+        let mut find_options = git2::DiffFindOptions::new();
+        find_options
+            .renames(true)
+            .copies(true)
+            .rename_threshold(self.rename_threshold)
+            .copy_threshold(self.rename_threshold);
+        diff.find_similar(Some(&mut find_options))
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to detect renames in {}", subject),
+                source: e,
+            })?;
+        
+        Ok(diff)
+    }
+    
+    pub fn diff_commit(&self, id: &CommitId) -> Result<Vec<DiffInfo>> {
+        tracing::debug!("Computing diff for commit: {}", id);
+        
+        let diff = self.commit_diff(id)?;
+        Self::diff_infos(&diff, &format!("commit {}", id))
+    }
+    
+    /// Diff between the trees of two arbitrary commits, from `a` to `b`.
+    pub fn diff_between(&self, a: &CommitId, b: &CommitId) -> Result<Vec<DiffInfo>> {
+        tracing::debug!("Computing diff between {} and {}", a, b);
+        
+        let old_tree = self.commit_tree(a)?;
+        let new_tree = self.commit_tree(b)?;
+        let subject = format!("{}..{}", a.short(), b.short());
+        let diff = self.tree_diff(Some(&old_tree), &new_tree, &subject)?;
+        Self::diff_infos(&diff, &subject)
+    }
+    
+    fn diff_infos(diff: &git2::Diff<'_>, subject: &str) -> Result<Vec<DiffInfo>> {
+        let mut files = Vec::with_capacity(diff.deltas().len());
+        
+        for (index, delta) in diff.deltas().enumerate() {
+            let old_file = delta.old_file().path().map(|path| path.to_string_lossy().to_string());
+            let new_file = delta.new_file().path().map(|path| path.to_string_lossy().to_string());
+            let rename_kind = match delta.status() {
+                git2::Delta::Renamed => Some(RenameKind::Renamed),
+                git2::Delta::Copied => Some(RenameKind::Copied),
+                _ => None,
+            };
+            let rename = match (rename_kind, &old_file, &new_file) {
+                (Some(kind), Some(from), Some(to)) => Some(RenameInfo {
+                    kind,
+                    from: from.clone(),
+                    to: to.clone(),
+                }),
+                _ => None,
+            };
+            
+            let patch = git2::Patch::from_diff(diff, index)
+                .map_err(|e| TwiggyError::Git {
+                    message: format!("Failed to build patch for {}", subject),
+                    source: e,
+                })?;
+            
+            let mut hunks = Vec::new();
+            if let Some(patch) = patch {
+                for hunk_index in 0..patch.num_hunks() {
+                    let (hunk, line_count) = patch.hunk(hunk_index)
+                        .map_err(|e| TwiggyError::Git {
+                            message: "Failed to read diff hunk".to_string(),
+                            source: e,
+                        })?;
+                    
+                    let mut lines = Vec::with_capacity(line_count);
+                    for line_index in 0..line_count {
+                        let line = patch.line_in_hunk(hunk_index, line_index)
+                            .map_err(|e| TwiggyError::Git {
+                                message: "Failed to read diff line".to_string(),
+                                source: e,
+                            })?;
+                        
+                        let line_type = match line.origin() {
+                            '+' => DiffLineType::Addition,
+                            '-' => DiffLineType::Deletion,
+                            _ => DiffLineType::Context,
+                        };
+                        
+                        lines.push(DiffLine {
+                            line_type,
+                            content: String::from_utf8_lossy(line.content()).trim_end_matches('\n').to_string(),
+                        });
+                    }
+                    
+                    hunks.push(DiffHunk {
+                        old_start: hunk.old_start(),
+                        old_lines: hunk.old_lines(),
+                        new_start: hunk.new_start(),
+                        new_lines: hunk.new_lines(),
+                        lines,
+                    });
+                }
+            }
+            
+            files.push(DiffInfo {
+                old_file,
+                new_file,
+                rename,
+                hunks,
+            });
+        }
+        
+        Ok(files)
+    }
+    
+    /// Like `diff_commit`, but keeps the result so rendering a diff every frame
+    /// does not recompute it.
+    pub fn cached_diff(&mut self, id: &CommitId) -> Result<&[DiffInfo]> {
+        if !self.diff_cache.contains_key(id) {
+            let files = self.diff_commit(id)?;
+            self.diff_cache.insert(*id, files);
+        }
+        
+        Ok(self.diff_cache.get(id).map(|files| files.as_slice()).unwrap_or_default())
+    }
+    
+    pub fn cached_diff_between(&mut self, a: &CommitId, b: &CommitId) -> Result<&[DiffInfo]> {
+        let key = (*a, *b);
+        if !self.range_diff_cache.contains_key(&key) {
+            let files = self.diff_between(a, b)?;
+            self.range_diff_cache.insert(key, files);
+        }
+        
+        Ok(self.range_diff_cache.get(&key).map(|files| files.as_slice()).unwrap_or_default())
+    }
+    
+    /// Files changed and lines added/removed against the first parent; root
+    /// commits count their whole tree as additions.
+    pub fn commit_stats(&self, id: &CommitId) -> Result<DiffStats> {
+        let stats = self.commit_diff(id)?
+            .stats()
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to compute stats for commit {}", id),
+                source: e,
+            })?;
+        
+        Ok(DiffStats {
+            files_changed: stats.files_changed(),
+            insertions: stats.insertions(),
+            deletions: stats.deletions(),
+        })
+    }
+    
+    pub fn cached_commit_stats(&mut self, id: &CommitId) -> Result<DiffStats> {
+        if let Some(stats) = self.stats_cache.get(id) {
+            return Ok(*stats);
+        }
+        
+        let stats = self.commit_stats(id)?;
+        self.stats_cache.insert(*id, stats);
+        Ok(stats)
+    }
+    
+    pub fn clear_diff_cache(&mut self) {
+        tracing::debug!("Clearing diff cache");
+        self.diff_cache.clear();
+        self.range_diff_cache.clear();
+        self.stats_cache.clear();
+    }
+    
+    pub fn diff_cache_size(&self) -> usize {
+        self.diff_cache.len()
+    }
+    
+    /// Moves the per-commit caches of `old`, an earlier handle on the same
+    /// repository, into this one. Diffs and stats only depend on the commit
+    /// and the diff settings, ahead/behind counts on the two tips;
+    /// descriptions are kept while the tags are unchanged.
+    pub fn take_caches_from(&mut self, mut old: GitRepository) {
+        let same_diff_settings = old.diff_algorithm == self.diff_algorithm
+            && old.rename_threshold == self.rename_threshold
+            && old.ignore_whitespace == self.ignore_whitespace;
+        if same_diff_settings {
+            self.diff_cache = std::mem::take(&mut old.diff_cache);
+            self.range_diff_cache = std::mem::take(&mut old.range_diff_cache);
+            self.stats_cache = std::mem::take(&mut old.stats_cache);
+        }
+        
+        if old.described_tags == self.described_tags {
+            self.describe_cache = old.describe_cache;
+        }
+        
+        self.ahead_behind_cache = old.ahead_behind_cache;
+    }
+    
+    fn tag_targets(repo: &Repository) -> Vec<(String, Option<git2::Oid>)> {
+        let mut targets: Vec<_> = repo.references_glob("refs/tags/*")
+            .map(|references| {
+                references
+                    .flatten()
+                    .filter_map(|reference| Some((reference.name()?.to_string(), reference.target())))
+                    .collect()
+            })
+            .unwrap_or_default();
+        targets.sort();
+        targets
+    }
+    
+    /// Contents of `path` as of commit `id`.
+    pub fn file_at_commit(&self, id: &CommitId, path: &Path) -> Result<Vec<u8>> {
+        Ok(self.blob_at_commit(id, path)?.content().to_vec())
+    }
+    
+    /// Whether git considers `path` at commit `id` binary.
+    pub fn is_binary_at_commit(&self, id: &CommitId, path: &Path) -> Result<bool> {
+        Ok(self.blob_at_commit(id, path)?.is_binary())
+    }
+    
+    fn blob_at_commit(&self, id: &CommitId, path: &Path) -> Result<git2::Blob<'_>> {
+        let entry = self.commit_tree(id)?
+            .get_path(path)
+            .map_err(|e| TwiggyError::Git {
+                message: format!("File not found in commit {}: {}", id.short(), path.display()),
+                source: e,
+            })?;
+        
+        self.inner.find_blob(entry.id())
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to read {} at {}", path.display(), id.short()),
+                source: e,
+            })
+    }
+    
+    pub fn blame_file(&self, path: &Path, at: Option<CommitId>) -> Result<Vec<BlameLine>> {
+        tracing::info!("Blaming {} at {:?}", path.display(), at);
         
-        if start < self.commits.len() {
-            let end = (start + count).min(self.commits.len());
-            return Ok(self.commits[start..end].to_vec());
+        let commit = match at {
+            Some(id) => self.inner.find_commit(id.0),
+            None => self.inner.head().and_then(|head| head.peel_to_commit()),
         }
+        .map_err(|e| TwiggyError::Git {
+            message: "Failed to find commit to blame at".to_string(),
+            source: e,
+        })?;
         
-        let mut revwalk = self.inner.revwalk()
+        let entry = commit.tree()
+            .and_then(|tree| tree.get_path(path))
             .map_err(|e| TwiggyError::Git {
-                message: "Failed to create revwalk for lazy loading".to_string(),
+                message: format!("File not found in commit {}: {}", commit.id(), path.display()),
                 source: e,
             })?;
         
-        revwalk.push_head()
+        let blob = self.inner.find_blob(entry.id())
             .map_err(|e| TwiggyError::Git {
-                message: "Failed to push HEAD for lazy loading".to_string(),
+                message: format!("Failed to read file: {}", path.display()),
                 source: e,
             })?;
         
-        revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)
+        if blob.is_binary() {
+            return Err(TwiggyError::Validation {
+                field: "path".to_string(),
+                message: format!("Cannot blame binary file {}", path.display()),
+            });
+        }
+        
+        let mut options = git2::BlameOptions::new();
+        options.newest_commit(commit.id());
+        
+        let blame = self.inner.blame_file(path, Some(&mut options))
             .map_err(|e| TwiggyError::Git {
-                message: "Failed to set sorting for lazy loading".to_string(),
+                message: format!("Failed to blame {}", path.display()),
                 source: e,
             })?;
         
-        let mut commits = Vec::new();
-        for (index, oid) in revwalk.enumerate() {
-            if index < start {
+        let content = String::from_utf8_lossy(blob.content());
+        let mut lines = Vec::new();
+        
+        for (index, line) in content.lines().enumerate() {
+            let line_number = index + 1;
+            let Some(hunk) = blame.get_line(line_number) else {
+                continue;
+            };
+            
+            let signature = hunk.final_signature();
+            let seconds = signature.when().seconds();
+            
+            lines.push(BlameLine {
+                line_number,
+                content: line.to_string(),
+                commit: CommitId(hunk.final_commit_id()),
+                author: signature.name().unwrap_or("Unknown").to_string(),
+                time: chrono::DateTime::from_timestamp(seconds, 0).unwrap_or_default(),
+            });
+        }
+        
+        Ok(lines)
+    }
+    
+    /// First parent of every commit a blame attributes lines to, `None` for
+    /// root commits. Used to blame "before" a line's change.
+    pub fn blame_parents(&self, lines: &[BlameLine]) -> Result<HashMap<CommitId, Option<CommitId>>> {
+        let mut parents = HashMap::new();
+        
+        for line in lines {
+            if parents.contains_key(&line.commit) {
                 continue;
-            }
-            if index >= start + count {
-                break;
             }
             
-            let oid = oid.map_err(|e| TwiggyError::Git {
-                message: "Failed to get commit OID in lazy loading".to_string(),
+            let commit = self.inner.find_commit(line.commit.0)
+                .map_err(|e| TwiggyError::Git {
+                    message: format!("Failed to find commit: {}", line.commit),
+                    source: e,
+                })?;
+            parents.insert(line.commit, commit.parent_id(0).ok().map(CommitId));
+        }
+        
+        Ok(parents)
+    }
+    
+    pub fn list_tree(&self, id: &CommitId) -> Result<Vec<TreeEntry>> {
+        tracing::debug!("Listing tree for commit: {}", id);
+        
+        let commit = self.inner.find_commit(id.0)
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to find commit: {}", id),
+                source: e,
+            })?;
+        
+        let tree = commit.tree()
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to read tree of commit {}", id),
                 source: e,
             })?;
+        
+        self.collect_tree_entries(&tree, "")
+    }
+    
+    fn collect_tree_entries(&self, tree: &git2::Tree, prefix: &str) -> Result<Vec<TreeEntry>> {
+        let mut entries = Vec::with_capacity(tree.len());
+        
+        for entry in tree.iter() {
+            let name = String::from_utf8_lossy(entry.name_bytes()).to_string();
+            let path = if prefix.is_empty() {
+                name.clone()
+            } else {
+                format!("{}/{}", prefix, name)
+            };
             
-            if let Some(cached_commit) = self.commit_cache.get(&CommitId(oid)) {
-                commits.push(cached_commit.clone());
+            let is_dir = entry.kind() == Some(git2::ObjectType::Tree);
+            let children = if is_dir {
+                let subtree = self.inner.find_tree(entry.id())
+                    .map_err(|e| TwiggyError::Git {
+                        message: format!("Failed to read tree: {}", path),
+                        source: e,
+                    })?;
+                self.collect_tree_entries(&subtree, &path)?
             } else {
-                let commit = self.parse_commit(oid)?;
-                self.commit_cache.insert(commit.id, commit.clone());
-                commits.push(commit);
-            }
+                Vec::new()
+            };
+            
+            entries.push(TreeEntry {
+                name,
+                path,
+                is_dir,
+                children,
+            });
         }
         
-        Ok(commits)
-    }
-
-    pub fn refresh_commits(&mut self, limit: Option<usize>) -> Result<()> {
-        tracing::info!("Refreshing commit data");
-        self.commits.clear();
-        self.load_commits(limit)
+        entries.sort_by(|a, b| b.is_dir.cmp(&a.is_dir).then_with(|| a.name.cmp(&b.name)));
+        Ok(entries)
     }
-
-    pub fn clear_commit_cache(&mut self) {
-        tracing::debug!("Clearing commit cache");
-        self.commit_cache.clear();
-    }
-
-    pub fn cache_size(&self) -> usize {
-        self.commit_cache.len()
+    
+    pub fn resolve_revspec(&self, spec: &str) -> Result<CommitId> {
+        let commit = self.inner.revparse_single(spec)
+            .and_then(|object| object.peel_to_commit())
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to resolve revision: {}", spec),
+                source: e,
+            })?;
+        
+        Ok(CommitId(commit.id()))
     }
-
+    
     pub fn find_commit_by_hash(&self, hash: &str) -> Result<Option<Commit>> {
         tracing::debug!("Searching for commit by hash: {}", hash);
         
@@ -525,7 +1932,7 @@ impl GitRepository {
         
         let commit_id = CommitId(oid);
         
-        if let Some(cached_commit) = self.commit_cache.get(&commit_id) {
+        if let Some(cached_commit) = self.commit_cache.peek(&commit_id) {
             return Ok(Some(cached_commit.clone()));
         }
         
@@ -542,17 +1949,213 @@ impl GitRepository {
         }
     }
 
+    /// Resolves an abbreviated hash. Prefixes shorter than
+    /// `MIN_COMMIT_PREFIX_LEN` or containing non-hex characters are rejected.
+    pub fn find_commit_by_prefix(&self, prefix: &str) -> Result<CommitMatch> {
+        if prefix.len() < MIN_COMMIT_PREFIX_LEN || !prefix.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(TwiggyError::Validation {
+                field: "commit_hash".to_string(),
+                message: format!("'{}' is not a hash prefix of at least {} hex digits", prefix, MIN_COMMIT_PREFIX_LEN),
+            });
+        }
+        
+        let prefix = prefix.to_ascii_lowercase();
+        match self.inner.find_commit_by_prefix(&prefix) {
+            Ok(commit) => Ok(CommitMatch::Unique(Box::new(self.parse_commit(commit.id())?))),
+            Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(CommitMatch::NotFound),
+            Err(e) if e.code() == git2::ErrorCode::Ambiguous => {
+                let odb = self.inner.odb().map_err(|e| TwiggyError::Git {
+                    message: "Failed to open object database".to_string(),
+                    source: e,
+                })?;
+                
+                let mut candidates = Vec::new();
+                odb.foreach(|oid| {
+                    if oid.to_string().starts_with(&prefix) {
+                        candidates.push(*oid);
+                    }
+                    true
+                }).map_err(|e| TwiggyError::Git {
+                    message: "Failed to list objects".to_string(),
+                    source: e,
+                })?;
+                
+                // The prefix may also match trees or blobs; only commits count.
+                let mut matches: Vec<CommitId> = candidates
+                    .into_iter()
+                    .filter(|oid| self.inner.find_commit(*oid).is_ok())
+                    .map(CommitId)
+                    .collect();
+                matches.sort_by_key(|id| id.0);
+                matches.dedup();
+                
+                match matches.len() {
+                    0 => Ok(CommitMatch::NotFound),
+                    1 => Ok(CommitMatch::Unique(Box::new(self.parse_commit(matches[0].0)?))),
+                    _ => Ok(CommitMatch::Ambiguous(matches)),
+                }
+            }
+            Err(e) => Err(TwiggyError::Git {
+                message: format!("Failed to look up commit {}", prefix),
+                source: e,
+            }),
+        }
+    }
+
+    /// Commits reachable from HEAD that changed `path`, newest first. Merges
+    /// are only listed when the file differs from every parent. Renames are
+    /// followed on a best-effort basis: once a commit is seen renaming the
+    /// file, older commits are matched against the old path. Results are
+    /// cached per path and limit.
+    pub fn file_history(&mut self, path: &Path, limit: Option<usize>) -> Result<Vec<Commit>> {
+        let key = (path.to_path_buf(), limit);
+        if let Some(commits) = self.file_history_cache.get(&key) {
+            return Ok(commits.clone());
+        }
+        
+        tracing::info!("Loading history of {}", path.display());
+        
+        if self.is_unborn() {
+            return Ok(Vec::new());
+        }
+        
+        let mut revwalk = self.inner.revwalk()
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to create revwalk for file history".to_string(),
+                source: e,
+            })?;
+        
+        revwalk.push_head()
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to push HEAD to revwalk".to_string(),
+                source: e,
+            })?;
+        
+        revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to set sorting for file history".to_string(),
+                source: e,
+            })?;
+        
+        let entry_id = |tree: &git2::Tree<'_>, path: &Path| tree.get_path(path).ok().map(|entry| entry.id());
+        let mut current_path = path.to_path_buf();
+        let mut touching = Vec::new();
+        
+        for oid in revwalk {
+            if limit.is_some_and(|limit| touching.len() >= limit) {
+                break;
+            }
+            
+            let oid = oid.map_err(|e| TwiggyError::Git {
+                message: "Failed to get commit OID for file history".to_string(),
+                source: e,
+            })?;
+            let id = CommitId(oid);
+            let tree = self.commit_tree(&id)?;
+            let current = entry_id(&tree, &current_path);
+            
+            let commit = self.inner.find_commit(oid)
+                .map_err(|e| TwiggyError::Git {
+                    message: format!("Failed to find commit: {}", id),
+                    source: e,
+                })?;
+            let parent_trees: Vec<git2::Tree<'_>> = commit.parents()
+                .filter_map(|parent| parent.tree().ok())
+                .collect();
+            
+            let changed = if parent_trees.is_empty() {
+                current.is_some()
+            } else {
+                parent_trees.iter().all(|parent| entry_id(parent, &current_path) != current)
+            };
+            
+            if !changed {
+                continue;
+            }
+            touching.push(id);
+            
+            // The file appearing here may be the new side of a rename.
+            let Some(first_parent) = parent_trees.first() else {
+                continue;
+            };
+            if current.is_some() && entry_id(first_parent, &current_path).is_none() {
+                let diff = self.tree_diff(Some(first_parent), &tree, &format!("commit {}", id))?;
+                let renamed_from = diff.deltas()
+                    .find(|delta| {
+                        delta.status() == git2::Delta::Renamed
+                            && delta.new_file().path() == Some(current_path.as_path())
+                    })
+                    .and_then(|delta| delta.old_file().path().map(Path::to_path_buf));
+                
+                if let Some(old_path) = renamed_from {
+                    tracing::debug!("{} was renamed from {} in {}", current_path.display(), old_path.display(), id.short());
+                    current_path = old_path;
+                }
+            }
+        }
+        
+        let mut commits = Vec::with_capacity(touching.len());
+        for id in touching {
+            if let Some(cached_commit) = self.commit_cache.get(&id) {
+                commits.push(cached_commit.clone());
+            } else {
+                let commit = self.parse_commit(id.0)?;
+                self.commit_cache.insert(commit.clone());
+                commits.push(commit);
+            }
+        }
+        
+        tracing::info!("Found {} commits touching {}", commits.len(), path.display());
+        self.file_history_cache.insert(key, commits.clone());
+        Ok(commits)
+    }
+    
+    /// Resolves what a user typed to go to a commit: a hash prefix, then a
+    /// local branch, remote branch or tag, then any revision `git` accepts.
+    pub fn resolve_commit_reference(&self, reference: &str) -> Result<CommitMatch> {
+        let reference = reference.trim();
+        if reference.is_empty() {
+            return Ok(CommitMatch::NotFound);
+        }
+        
+        if reference.len() >= MIN_COMMIT_PREFIX_LEN && reference.chars().all(|c| c.is_ascii_hexdigit()) {
+            match self.find_commit_by_prefix(reference)? {
+                CommitMatch::NotFound => {}
+                found => return Ok(found),
+            }
+        }
+        
+        for refname in [
+            format!("refs/heads/{}", reference),
+            format!("refs/remotes/{}", reference),
+            format!("refs/tags/{}", reference),
+        ] {
+            if let Ok(commit) = self.inner.find_reference(&refname).and_then(|r| r.peel_to_commit()) {
+                return Ok(CommitMatch::Unique(Box::new(self.parse_commit(commit.id())?)));
+            }
+        }
+        
+        match self.resolve_revspec(reference) {
+            Ok(id) => Ok(CommitMatch::Unique(Box::new(self.parse_commit(id.0)?))),
+            Err(_) => Ok(CommitMatch::NotFound),
+        }
+    }
+
     pub fn load_commits_for_branch(&mut self, branch_name: &str, limit: Option<usize>) -> Result<Vec<Commit>> {
         tracing::info!("Loading commits for branch: {}", branch_name);
         
+        if self.is_unborn() && self.current_branch.as_deref() == Some(branch_name) {
+            tracing::info!("Branch {} has no commits yet", branch_name);
+            return Ok(Vec::new());
+        }
+        
         let mut revwalk = self.inner.revwalk()
             .map_err(|e| TwiggyError::Git {
                 message: "Failed to create revwalk for branch".to_string(),
                 source: e,
             })?;
         
-        let branch_ref = format!("refs/heads/{}", branch_name);
-        let oid = self.inner.refname_to_id(&branch_ref)
+        let oid = self.branch_tip(branch_name)
             .map_err(|e| TwiggyError::Git {
                 message: format!("Failed to find branch: {}", branch_name),
                 source: e,
@@ -587,7 +2190,7 @@ impl GitRepository {
                 commits.push(cached_commit.clone());
             } else {
                 let commit = self.parse_commit(oid)?;
-                self.commit_cache.insert(commit.id, commit.clone());
+                self.commit_cache.insert(commit.clone());
                 commits.push(commit);
             }
         }
@@ -651,7 +2254,7 @@ impl GitRepository {
                 commits.push(cached_commit.clone());
             } else {
                 let commit = self.parse_commit(oid)?;
-                self.commit_cache.insert(commit.id, commit.clone());
+                self.commit_cache.insert(commit.clone());
                 commits.push(commit);
             }
         }
@@ -659,19 +2262,171 @@ impl GitRepository {
         Ok(commits)
     }
 
+    pub fn search_diff_content(&self, needle: &str, limit: Option<usize>) -> Result<Vec<CommitId>> {
+        self.search_diff_content_with_progress(needle, limit, |_| true)
+    }
+    
+    /// Pickaxe search, like `git log -S`: finds commits that change the number
+    /// of occurrences of `needle` in any file. `on_progress` receives the count
+    /// of scanned commits and stops the walk by returning `false`.
+    pub fn search_diff_content_with_progress(
+        &self,
+        needle: &str,
+        limit: Option<usize>,
+        mut on_progress: impl FnMut(usize) -> bool,
+    ) -> Result<Vec<CommitId>> {
+        tracing::info!("Searching diff content for: {}", needle);
+        
+        if needle.is_empty() || self.is_unborn() || self.inner.is_empty().unwrap_or(true) {
+            return Ok(Vec::new());
+        }
+        
+        let mut revwalk = self.inner.revwalk()
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to create revwalk for diff search".to_string(),
+                source: e,
+            })?;
+        
+        revwalk.push_head()
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to push HEAD for diff search".to_string(),
+                source: e,
+            })?;
+        
+        revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)
+            .map_err(|e| TwiggyError::Git {
+                message: "Failed to set sorting".to_string(),
+                source: e,
+            })?;
+        
+        let max_commits = limit.unwrap_or(usize::MAX);
+        let mut matches = Vec::new();
+        
+        for (scanned, oid) in revwalk.enumerate() {
+            if scanned >= max_commits || !on_progress(scanned) {
+                break;
+            }
+            
+            let oid = oid.map_err(|e| TwiggyError::Git {
+                message: "Failed to walk commits".to_string(),
+                source: e,
+            })?;
+            
+            if self.commit_changes_occurrences(oid, needle.as_bytes())? {
+                matches.push(CommitId(oid));
+            }
+        }
+        
+        tracing::info!("Diff search for '{}' found {} commits", needle, matches.len());
+        Ok(matches)
+    }
+    
+    fn commit_changes_occurrences(&self, oid: git2::Oid, needle: &[u8]) -> Result<bool> {
+        let commit = self.inner.find_commit(oid)
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to find commit: {}", oid),
+                source: e,
+            })?;
+        
+        let new_tree = commit.tree().ok();
+        let old_tree = commit.parent(0).ok().and_then(|parent| parent.tree().ok());
+        
+        let mut options = self.diff_options();
+        let diff = self.inner.diff_tree_to_tree(old_tree.as_ref(), new_tree.as_ref(), Some(&mut options))
+            .map_err(|e| TwiggyError::Git {
+                message: format!("Failed to diff commit {}", oid),
+                source: e,
+            })?;
+        
+        for delta in diff.deltas() {
+            let old_count = self.count_in_blob(delta.old_file().id(), needle);
+            let new_count = self.count_in_blob(delta.new_file().id(), needle);
+            if old_count != new_count {
+                return Ok(true);
+            }
+        }
+        
+        Ok(false)
+    }
+    
+    fn count_in_blob(&self, id: git2::Oid, needle: &[u8]) -> usize {
+        if id.is_zero() {
+            return 0;
+        }
+        
+        match self.inner.find_blob(id) {
+            Ok(blob) if !blob.is_binary() => count_occurrences(blob.content(), needle),
+            _ => 0,
+        }
+    }
+    
+    /// Writes the loaded commits, newest first, as a pretty-printed JSON
+    /// array of `CommitInfo`.
+    pub fn export_commits_json(&self, mut writer: impl std::io::Write) -> Result<()> {
+        let commits: Vec<CommitInfo> = self.commits.iter().map(CommitInfo::from).collect();
+        
+        serde_json::to_writer_pretty(&mut writer, &commits)
+            .map_err(|e| TwiggyError::Serialization {
+                operation: "commit export".to_string(),
+                source: e,
+            })?;
+        
+        writeln!(writer)
+            .and_then(|_| writer.flush())
+            .map_err(|e| TwiggyError::Io {
+                operation: "commit export".to_string(),
+                source: e,
+            })
+    }
+    
+    /// Writes the loaded commits, newest first, as CSV with a header row:
+    /// hash, author name, author email, ISO 8601 date, summary and parent
+    /// count. Fields containing commas, quotes or line breaks are quoted.
+    pub fn export_commits_csv(&self, mut writer: impl std::io::Write) -> Result<()> {
+        fn field(value: &str) -> std::borrow::Cow<'_, str> {
+            if value.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", value.replace('"', "\"\"")).into()
+            } else {
+                value.into()
+            }
+        }
+        
+        let mut write = || -> std::io::Result<()> {
+            writeln!(writer, "hash,author_name,author_email,date,summary,parent_count")?;
+            for commit in &self.commits {
+                writeln!(
+                    writer,
+                    "{},{},{},{},{},{}",
+                    commit.id,
+                    field(&commit.author.name),
+                    field(&commit.author.email),
+                    commit.author.time.to_rfc3339(),
+                    field(&commit.summary),
+                    commit.parents.len(),
+                )?;
+            }
+            writer.flush()
+        };
+        
+        write().map_err(|e| TwiggyError::Io {
+            operation: "commit export".to_string(),
+            source: e,
+        })
+    }
+    
     pub fn search_commits(&self, query: &str) -> Vec<&Commit> {
-        tracing::debug!("Searching commits with query: {}", query);
-        let query_lower = query.to_lowercase();
+        self.search_commits_filtered(&CommitFilter {
+            text: Some(query.to_string()),
+            ..Default::default()
+        })
+    }
+    
+    pub fn search_commits_filtered(&self, filter: &CommitFilter) -> Vec<&Commit> {
+        tracing::debug!("Searching commits with filter: {:?}", filter);
         
         self.commits
             .iter()
-            .filter(|commit| {
-                commit.message.to_lowercase().contains(&query_lower) ||
-                commit.summary.to_lowercase().contains(&query_lower) ||
-                commit.author.name.to_lowercase().contains(&query_lower) ||
-                commit.author.email.to_lowercase().contains(&query_lower) ||
-                commit.id.as_str().starts_with(&query_lower)
-            })
+            .filter(|commit| filter.matches(commit))
             .collect()
     }
 }
@@ -691,6 +2446,15 @@ pub fn discover_repository(path: impl AsRef<Path>) -> Result<Option<PathBuf>> {
     }
 }
 
+/// Like `discover_repository`, but returns the working tree root rather than
+/// the `.git` directory (bare repositories return the repository itself).
+pub fn discover_repository_root(path: impl AsRef<Path>) -> Result<Option<PathBuf>> {
+    Ok(discover_repository(path)?.map(|git_dir| match git_dir.file_name() {
+        Some(name) if name == ".git" => git_dir.parent().map(Path::to_path_buf).unwrap_or(git_dir),
+        _ => git_dir,
+    }))
+}
+
 pub fn validate_repository_path(path: impl AsRef<Path>) -> Result<bool> {
     let path = path.as_ref();
     
@@ -710,4 +2474,63 @@ pub fn validate_repository_path(path: impl AsRef<Path>) -> Result<bool> {
             source: e,
         }),
     }
+}
+
+pub fn validate_config_entry(key: &str, value: &str) -> Result<()> {
+    let parts: Vec<&str> = key.split('.').collect();
+    if parts.len() < 2 || parts.iter().any(|part| part.is_empty()) {
+        return Err(TwiggyError::Validation {
+            field: key.to_string(),
+            message: "Config keys must look like section.name".to_string(),
+        });
+    }
+    
+    if value.contains('\n') {
+        return Err(TwiggyError::Validation {
+            field: key.to_string(),
+            message: "Config values cannot span multiple lines".to_string(),
+        });
+    }
+    
+    if key == "user.email" && !value.is_empty() {
+        let valid = match value.split_once('@') {
+            Some((local, domain)) => !local.is_empty() && !domain.is_empty() && !domain.contains('@') && !value.contains(char::is_whitespace),
+            None => false,
+        };
+        
+        if !valid {
+            return Err(TwiggyError::Validation {
+                field: key.to_string(),
+                message: format!("'{}' is not a valid email address", value),
+            });
+        }
+    }
+    
+    if key == "core.autocrlf" && !value.is_empty() && !matches!(value, "true" | "false" | "input") {
+        return Err(TwiggyError::Validation {
+            field: key.to_string(),
+            message: "core.autocrlf must be true, false or input".to_string(),
+        });
+    }
+    
+    Ok(())
+}
+
+fn count_occurrences(haystack: &[u8], needle: &[u8]) -> usize {
+    if needle.is_empty() || haystack.len() < needle.len() {
+        return 0;
+    }
+    
+    let mut count = 0;
+    let mut position = 0;
+    while position + needle.len() <= haystack.len() {
+        if &haystack[position..position + needle.len()] == needle {
+            count += 1;
+            position += needle.len();
+        } else {
+            position += 1;
+        }
+    }
+    
+    count
 }
\ No newline at end of file
@@ -33,6 +33,21 @@ pub struct Commit {
     pub tree_id: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RefKind {
+    Head,
+    LocalBranch,
+    RemoteBranch,
+    Tag,
+    Stash,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefLabel {
+    pub name: String,
+    pub kind: RefKind,
+}
+
 #[derive(Debug, Clone)]
 pub struct Signature {
     pub name: String,
@@ -53,7 +68,7 @@ impl From<&git2::Signature<'_>> for Signature {
     }
 }
 
-#[allow(dead_code)]
+/// Serializable view of a commit, as written by `export_commits_json`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitInfo {
     pub id: String,
@@ -63,7 +78,21 @@ pub struct CommitInfo {
     pub parents: Vec<String>,
 }
 
-#[allow(dead_code)]
+impl From<&Commit> for CommitInfo {
+    fn from(commit: &Commit) -> Self {
+        Self {
+            id: commit.id.as_str(),
+            message: commit.message.clone(),
+            author: AuthorInfo {
+                name: commit.author.name.clone(),
+                email: commit.author.email.clone(),
+            },
+            timestamp: commit.author.time,
+            parents: commit.parents.iter().map(CommitId::as_str).collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthorInfo {
     pub name: String,
@@ -78,14 +107,78 @@ pub struct BranchInfo {
     pub commit_id: String,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum DiffAlgorithm {
+    #[default]
+    Myers,
+    Patience,
+    Minimal,
+}
+
+impl DiffAlgorithm {
+    pub const ALL: [DiffAlgorithm; 3] = [DiffAlgorithm::Myers, DiffAlgorithm::Patience, DiffAlgorithm::Minimal];
+
+    pub fn apply(&self, options: &mut git2::DiffOptions) {
+        match self {
+            DiffAlgorithm::Myers => {}
+            DiffAlgorithm::Patience => {
+                options.patience(true);
+            }
+            DiffAlgorithm::Minimal => {
+                options.minimal(true);
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DiffAlgorithm {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        match name.to_ascii_lowercase().as_str() {
+            "myers" => Ok(DiffAlgorithm::Myers),
+            "patience" => Ok(DiffAlgorithm::Patience),
+            "minimal" => Ok(DiffAlgorithm::Minimal),
+            _ => {
+                tracing::warn!("Unknown diff algorithm '{}', falling back to Myers", name);
+                Ok(DiffAlgorithm::Myers)
+            }
+        }
+    }
+}
+
+impl fmt::Display for DiffAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiffAlgorithm::Myers => write!(f, "Myers"),
+            DiffAlgorithm::Patience => write!(f, "Patience"),
+            DiffAlgorithm::Minimal => write!(f, "Minimal"),
+        }
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct DiffInfo {
     pub old_file: Option<String>,
     pub new_file: Option<String>,
+    /// Set when rename/copy detection paired `old_file` with `new_file`.
+    pub rename: Option<RenameInfo>,
     pub hunks: Vec<DiffHunk>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameKind {
+    Renamed,
+    Copied,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenameInfo {
+    pub kind: RenameKind,
+    pub from: String,
+    pub to: String,
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct DiffHunk {
@@ -109,4 +202,167 @@ pub enum DiffLineType {
     Context,
     Addition,
     Deletion,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiffStats {
+    pub files_changed: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+impl DiffStats {
+    /// Full summary, e.g. "3 files changed, +42 −10".
+    pub fn summary(&self) -> String {
+        let files = if self.files_changed == 1 { "file" } else { "files" };
+        format!("{} {} changed, {}", self.files_changed, files, self.compact())
+    }
+
+    /// Just the line counts, e.g. "+42 −10".
+    pub fn compact(&self) -> String {
+        format!("+{} −{}", self.insertions, self.deletions)
+    }
+}
+
+/// Whether a commit carries a GPG/SSH signature. Signatures aren't checked
+/// against any keys: `Valid` only means a well-formed signature block is
+/// present, `Invalid` that the signature header holds something else.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureStatus {
+    Unsigned,
+    Valid,
+    Invalid,
+    Unknown,
+}
+
+/// One line of `git blame` output; `line_number` is 1-based.
+#[derive(Debug, Clone)]
+pub struct BlameLine {
+    pub line_number: usize,
+    pub content: String,
+    pub commit: CommitId,
+    pub author: String,
+    pub time: DateTime<Utc>,
+}
+
+/// An entry of `git stash list`; `id` is the stash commit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StashEntry {
+    pub index: usize,
+    pub message: String,
+    pub id: CommitId,
+}
+
+impl StashEntry {
+    pub fn reference_name(&self) -> String {
+        format!("stash@{{{}}}", self.index)
+    }
+}
+
+/// A configured remote. `push_url` falls back to the fetch URL when no
+/// separate push URL is set, as git itself does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteInfo {
+    pub name: String,
+    pub fetch_url: Option<String>,
+    pub push_url: Option<String>,
+}
+
+/// Commits by one author, keyed by email; `name` is the most recent name
+/// used with that email.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthorStat {
+    pub name: String,
+    pub email: String,
+    pub commit_count: usize,
+    pub first_commit: DateTime<Utc>,
+    pub last_commit: DateTime<Utc>,
+}
+
+/// A submodule as recorded in the superproject. `head_id` is the commit the
+/// superproject's HEAD points it at, `workdir_id` the one checked out (unset
+/// when the submodule isn't initialized).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmoduleInfo {
+    pub name: String,
+    pub path: String,
+    pub url: Option<String>,
+    pub head_id: Option<CommitId>,
+    pub workdir_id: Option<CommitId>,
+}
+
+impl SubmoduleInfo {
+    pub fn is_in_sync(&self) -> bool {
+        self.head_id == self.workdir_id
+    }
+}
+
+/// Outcome of a fetch: how many local refs moved and how many objects
+/// came over the wire.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FetchSummary {
+    pub remote: String,
+    pub updated_refs: usize,
+    pub received_objects: usize,
+}
+
+/// A lightweight or annotated tag. `message` is only set for annotated tags.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagRef {
+    pub name: String,
+    pub target: CommitId,
+    pub message: Option<String>,
+}
+
+/// A file or directory in a commit's tree. `path` is relative to the
+/// repository root; directories carry their entries in `children`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeEntry {
+    pub name: String,
+    pub path: String,
+    pub is_dir: bool,
+    pub children: Vec<TreeEntry>,
+}
+
+/// Result of resolving an abbreviated commit hash.
+#[derive(Debug, Clone)]
+pub enum CommitMatch {
+    Unique(Box<Commit>),
+    Ambiguous(Vec<CommitId>),
+    NotFound,
+}
+
+/// Which part of `git status` a path shows up in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StatusCategory {
+    Conflicted,
+    Staged,
+    Unstaged,
+    Untracked,
+}
+
+impl StatusCategory {
+    pub const ALL: [StatusCategory; 4] = [
+        StatusCategory::Conflicted,
+        StatusCategory::Staged,
+        StatusCategory::Unstaged,
+        StatusCategory::Untracked,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StatusCategory::Conflicted => "Conflicted",
+            StatusCategory::Staged => "Staged",
+            StatusCategory::Unstaged => "Unstaged",
+            StatusCategory::Untracked => "Untracked",
+        }
+    }
+}
+
+/// A changed path in the working directory. A file with both staged and
+/// unstaged edits appears once in each category.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusEntry {
+    pub path: String,
+    pub category: StatusCategory,
 }
\ No newline at end of file
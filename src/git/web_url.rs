@@ -0,0 +1,75 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum WebHost {
+    GitHub,
+    GitLab,
+    Bitbucket,
+}
+
+impl WebHost {
+    /// Matches the service's own domain or one of its subdomains, so a
+    /// host like `github.evil.example` is not mistaken for GitHub.
+    fn detect(host: &str) -> Option<Self> {
+        let host = host.to_ascii_lowercase();
+        let matches = |domain: &str| host == domain || host.strip_suffix(domain).is_some_and(|rest| rest.ends_with('.'));
+
+        if matches("github.com") {
+            Some(WebHost::GitHub)
+        } else if matches("gitlab.com") {
+            Some(WebHost::GitLab)
+        } else if matches("bitbucket.org") {
+            Some(WebHost::Bitbucket)
+        } else {
+            None
+        }
+    }
+
+    fn commit_path(&self) -> &'static str {
+        match self {
+            WebHost::GitHub => "commit",
+            WebHost::GitLab => "-/commit",
+            WebHost::Bitbucket => "commits",
+        }
+    }
+}
+
+/// Splits a remote URL into `(host, "org/repo")`. Handles scp-like
+/// (`git@host:org/repo.git`) and URL forms (`https://`, `ssh://`, `git://`).
+fn parse_remote(remote_url: &str) -> Option<(String, String)> {
+    let remote_url = remote_url.trim();
+
+    let (host, path) = if let Some((_, rest)) = remote_url.split_once("://") {
+        let (authority, path) = rest.split_once('/')?;
+        let host = authority.rsplit('@').next()?;
+        let host = if remote_url.starts_with("http") {
+            host
+        } else {
+            host.split(':').next()?
+        };
+        (host.to_string(), path)
+    } else {
+        let (user_host, path) = remote_url.split_once(':')?;
+        let host = user_host.rsplit('@').next()?;
+        (host.to_string(), path)
+    };
+
+    let path = path.trim_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+
+    if host.is_empty() || !path.contains('/') {
+        return None;
+    }
+
+    Some((host, path.to_string()))
+}
+
+pub fn repository_url(remote_url: &str) -> Option<String> {
+    let (host, path) = parse_remote(remote_url)?;
+    WebHost::detect(&host)?;
+    Some(format!("https://{}/{}", host, path))
+}
+
+pub fn commit_url(remote_url: &str, sha: &str) -> Option<String> {
+    let (host, path) = parse_remote(remote_url)?;
+    let web_host = WebHost::detect(&host)?;
+    Some(format!("https://{}/{}/{}/{}", host, path, web_host.commit_path(), sha))
+}
\ No newline at end of file
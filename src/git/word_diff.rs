@@ -0,0 +1,103 @@
+use std::ops::Range;
+
+/// Lines with more tokens than this aren't compared word by word; the LCS
+/// table grows with the product of both sides.
+const MAX_TOKENS: usize = 400;
+
+/// Compares a deleted line with the addition that replaced it and returns
+/// the byte ranges of changed words in each. Tokens are whitespace-separated
+/// words; runs of adjacent changed words are merged into one range. Returns
+/// no ranges when the lines share no words at all, since then the whole
+/// line changed anyway.
+pub fn changed_words(old: &str, new: &str) -> (Vec<Range<usize>>, Vec<Range<usize>>) {
+    let old_tokens = tokenize(old);
+    let new_tokens = tokenize(new);
+
+    if old_tokens.len() > MAX_TOKENS || new_tokens.len() > MAX_TOKENS {
+        return (Vec::new(), Vec::new());
+    }
+
+    let (old_common, new_common) = common_tokens(old, &old_tokens, new, &new_tokens);
+    if !old_common.contains(&true) {
+        return (Vec::new(), Vec::new());
+    }
+
+    (changed_ranges(&old_tokens, &old_common), changed_ranges(&new_tokens, &new_common))
+}
+
+fn tokenize(line: &str) -> Vec<Range<usize>> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+
+    for (index, ch) in line.char_indices() {
+        match (ch.is_whitespace(), start) {
+            (true, Some(token_start)) => {
+                tokens.push(token_start..index);
+                start = None;
+            }
+            (false, None) => start = Some(index),
+            _ => {}
+        }
+    }
+
+    if let Some(token_start) = start {
+        tokens.push(token_start..line.len());
+    }
+
+    tokens
+}
+
+/// Marks which tokens on each side belong to the longest common subsequence.
+fn common_tokens(old: &str, old_tokens: &[Range<usize>], new: &str, new_tokens: &[Range<usize>]) -> (Vec<bool>, Vec<bool>) {
+    let n = old_tokens.len();
+    let m = new_tokens.len();
+    let mut lengths = vec![vec![0u16; m + 1]; n + 1];
+
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i][j] = if old[old_tokens[i].clone()] == new[new_tokens[j].clone()] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut old_common = vec![false; n];
+    let mut new_common = vec![false; m];
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old[old_tokens[i].clone()] == new[new_tokens[j].clone()] {
+            old_common[i] = true;
+            new_common[j] = true;
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    (old_common, new_common)
+}
+
+fn changed_ranges(tokens: &[Range<usize>], common: &[bool]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut previous_changed = false;
+
+    for (token, &is_common) in tokens.iter().zip(common) {
+        if is_common {
+            previous_changed = false;
+            continue;
+        }
+
+        match ranges.last_mut() {
+            Some(last) if previous_changed => last.end = token.end,
+            _ => ranges.push(token.clone()),
+        }
+        previous_changed = true;
+    }
+
+    ranges
+}
\ No newline at end of file
@@ -1,6 +1,9 @@
 pub mod app;
+pub mod bench;
 pub mod config;
+pub mod diagnostics;
 pub mod error;
 pub mod git;
 pub mod logging;
-pub mod ui;
\ No newline at end of file
+pub mod ui;
+pub mod util;
\ No newline at end of file
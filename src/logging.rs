@@ -1,104 +1,208 @@
-use std::path::PathBuf;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use tracing_subscriber::{
     fmt::{self, time::LocalTime},
     layer::SubscriberExt,
     util::SubscriberInitExt,
     EnvFilter, Layer,
 };
-use tracing_appender::{non_blocking, rolling};
 use directories::ProjectDirs;
+use crate::config::{LogLevel, LoggingConfig};
 use crate::error::{Result, TwiggyError};
 
-pub struct LoggingConfig {
-    pub log_level: String,
-    pub log_to_file: bool,
-    pub log_to_console: bool,
-    pub log_directory: PathBuf,
-    pub max_log_files: usize,
+pub fn tracing_level(level: &LogLevel) -> tracing::Level {
+    match level {
+        LogLevel::Error => tracing::Level::ERROR,
+        LogLevel::Warn => tracing::Level::WARN,
+        LogLevel::Info => tracing::Level::INFO,
+        LogLevel::Debug => tracing::Level::DEBUG,
+        LogLevel::Trace => tracing::Level::TRACE,
+    }
 }
 
-impl Default for LoggingConfig {
-    fn default() -> Self {
-        let log_directory = ProjectDirs::from("com", "twiggy", "twiggy")
-            .map(|dirs| dirs.data_dir().join("logs"))
-            .unwrap_or_else(|| PathBuf::from("logs"));
-
-        Self {
-            log_level: "info".to_string(),
-            log_to_file: true,
-            log_to_console: true,
-            log_directory,
-            max_log_files: 10,
-        }
+/// The configured log directory, or the platform data directory.
+pub fn log_directory(config: &LoggingConfig) -> PathBuf {
+    if let Some(ref custom_dir) = config.log_directory {
+        return PathBuf::from(custom_dir);
     }
+
+    ProjectDirs::from("dev", "twiggy", "Twiggy")
+        .map(|dirs| dirs.data_dir().join("logs"))
+        .unwrap_or_else(|| PathBuf::from("logs"))
 }
 
-pub fn initialize_logging(config: LoggingConfig) -> Result<()> {
-    let env_filter = EnvFilter::try_from_default_env()
-        .or_else(|_| EnvFilter::try_new(&config.log_level))
-        .map_err(|e| TwiggyError::Config {
-            message: format!("Invalid log level '{}': {}", config.log_level, e),
-        })?;
+pub fn initialize_logging(config: &LoggingConfig) -> Result<()> {
+    let level = tracing_level(&config.level);
+    let env_filter = EnvFilter::from_default_env()
+        .add_directive(level.into());
 
     let mut layers = Vec::new();
 
-    if config.log_to_console {
+    if config.console_enabled {
         let console_layer = fmt::layer()
             .with_target(true)
             .with_thread_ids(false)
             .with_thread_names(false)
             .with_file(true)
             .with_line_number(true)
-            .with_timer(LocalTime::rfc_3339())
-            .with_filter(env_filter.clone());
+            .with_timer(LocalTime::rfc_3339());
         
         layers.push(console_layer.boxed());
     }
 
-    if config.log_to_file {
-        std::fs::create_dir_all(&config.log_directory).map_err(|e| {
+    let log_directory = log_directory(config);
+
+    if config.file_enabled {
+        std::fs::create_dir_all(&log_directory).map_err(|e| {
             TwiggyError::FileSystem {
-                path: config.log_directory.display().to_string(),
+                path: log_directory.display().to_string(),
                 source: e,
             }
         })?;
 
-        let file_appender = rolling::daily(&config.log_directory, "twiggy.log");
-        let (non_blocking_appender, _guard) = non_blocking(file_appender);
+        cleanup_old_logs(&log_directory, config.max_files)?;
+
+        let file_appender = RotatingFileWriter::new(
+            &log_directory,
+            LOG_FILE_NAME,
+            config.max_file_size,
+            config.max_files,
+        )?;
 
         let file_layer = fmt::layer()
-            .with_writer(non_blocking_appender)
+            .with_writer(file_appender.into_make_writer())
+            .with_ansi(false)
             .with_target(true)
             .with_thread_ids(true)
             .with_thread_names(true)
             .with_file(true)
             .with_line_number(true)
             .with_timer(LocalTime::rfc_3339())
-            .json()
-            .with_filter(env_filter.clone());
+            .json();
 
         layers.push(file_layer.boxed());
-
-        cleanup_old_logs(&config.log_directory, config.max_log_files)?;
     }
 
     tracing_subscriber::registry()
+        .with(env_filter)
         .with(layers)
         .init();
 
     tracing::info!(
         version = env!("CARGO_PKG_VERSION"),
-        log_level = %config.log_level,
-        log_to_file = config.log_to_file,
-        log_to_console = config.log_to_console,
-        log_directory = %config.log_directory.display(),
+        log_level = %level,
+        log_to_file = config.file_enabled,
+        log_to_console = config.console_enabled,
+        log_directory = %log_directory.display(),
         "Twiggy logging initialized"
     );
 
     Ok(())
 }
 
-fn cleanup_old_logs(log_dir: &PathBuf, max_files: usize) -> Result<()> {
+pub const LOG_FILE_NAME: &str = "twiggy.log";
+
+/// Appends to `<directory>/<file_name>` and rotates it once it would grow past
+/// `max_size`: the current file becomes `<file_name>.1`, older ones shift up,
+/// and at most `max_files` files are kept in total.
+pub struct RotatingFileWriter {
+    directory: PathBuf,
+    file_name: String,
+    max_size: u64,
+    max_files: usize,
+    file: File,
+    size: u64,
+}
+
+impl RotatingFileWriter {
+    pub fn new(directory: &Path, file_name: &str, max_size: u64, max_files: usize) -> Result<Self> {
+        let path = directory.join(file_name);
+        let file = open_for_append(&path)?;
+        let size = file.metadata().map(|metadata| metadata.len()).unwrap_or(0);
+
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            file_name: file_name.to_string(),
+            max_size,
+            max_files: max_files.max(1),
+            file,
+            size,
+        })
+    }
+
+    /// Wraps the writer so it can be handed to `fmt::layer().with_writer`.
+    pub fn into_make_writer(self) -> Mutex<Self> {
+        Mutex::new(self)
+    }
+
+    fn numbered_path(&self, n: usize) -> PathBuf {
+        self.directory.join(format!("{}.{}", self.file_name, n))
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        let oldest = self.max_files - 1;
+        if oldest > 0 {
+            let _ = fs::remove_file(self.numbered_path(oldest));
+            for n in (1..oldest).rev() {
+                let from = self.numbered_path(n);
+                if from.exists() {
+                    fs::rename(&from, self.numbered_path(n + 1))?;
+                }
+            }
+            fs::rename(self.directory.join(&self.file_name), self.numbered_path(1))?;
+        }
+
+        self.file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(self.directory.join(&self.file_name))?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.size > 0 && self.size + buf.len() as u64 > self.max_size {
+            self.rotate()?;
+        }
+
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn open_for_append(path: &Path) -> Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| TwiggyError::FileSystem {
+            path: path.display().to_string(),
+            source: e,
+        })
+}
+
+/// Matches `twiggy.log` as well as rotated (`twiggy.log.3`) and daily
+/// (`twiggy.log.2024-01-15`) files.
+fn is_log_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.ends_with(".log") || name.contains(".log."))
+}
+
+/// Removes all but the `max_files` most recently modified log files.
+pub fn cleanup_old_logs(log_dir: &Path, max_files: usize) -> Result<()> {
     let entries = std::fs::read_dir(log_dir).map_err(|e| TwiggyError::FileSystem {
         path: log_dir.display().to_string(),
         source: e,
@@ -108,7 +212,7 @@ fn cleanup_old_logs(log_dir: &PathBuf, max_files: usize) -> Result<()> {
         .filter_map(|entry| {
             let entry = entry.ok()?;
             let path = entry.path();
-            if path.extension()? == "log" {
+            if is_log_file(&path) {
                 let metadata = entry.metadata().ok()?;
                 let modified = metadata.modified().ok()?;
                 Some((path, modified))
@@ -1,26 +1,40 @@
 mod app;
+mod bench;
 mod config;
+mod diagnostics;
 mod error;
 mod git;
 mod ui;
 mod logging;
+mod util;
 
 use crate::{app::TwiggyApp, config::AppConfig};
-use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
-use tracing_appender::rolling;
 use std::path::PathBuf;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let startup_args = parse_startup_args(std::env::args().skip(1));
+    
+    if let Some(ref path) = startup_args.config {
+        AppConfig::use_config_file(path.clone());
+    }
+    
     let config = AppConfig::load().unwrap_or_default();
     
-    if let Err(e) = setup_logging(&config.logging) {
+    if let Err(e) = logging::initialize_logging(&config.logging) {
         eprintln!("Failed to initialize logging: {}", e);
-        return Err(e);
+        return Err(Box::new(e));
     }
     
     tracing::info!("Starting Twiggy v{}", env!("CARGO_PKG_VERSION"));
     
-    let app = match TwiggyApp::new() {
+    for warning in &startup_args.warnings {
+        tracing::warn!("{}", warning);
+    }
+    if let Some(ref path) = startup_args.config {
+        tracing::info!("Using configuration file: {}", path.display());
+    }
+    
+    let mut app = match TwiggyApp::new() {
         Ok(app) => app,
         Err(e) => {
             tracing::error!("Failed to initialize application: {}", e);
@@ -28,6 +42,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     };
     
+    if let Some(path) = startup_args.repository {
+        tracing::info!("Opening repository from command line: {}", path.display());
+        app.open_repository_at(path, startup_args.revision);
+    } else if startup_args.revision.is_some() {
+        tracing::warn!("--ref/--commit given without a repository path, ignoring");
+    }
+    
     tracing::info!("Launching GUI application");
     
     let viewport_builder = egui::ViewportBuilder::default()
@@ -57,63 +78,40 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     })
 }
 
-fn setup_logging(config: &config::LoggingConfig) -> Result<(), Box<dyn std::error::Error>> {
-    let level = match config.level {
-        config::LogLevel::Error => tracing::Level::ERROR,
-        config::LogLevel::Warn => tracing::Level::WARN,
-        config::LogLevel::Info => tracing::Level::INFO,
-        config::LogLevel::Debug => tracing::Level::DEBUG,
-        config::LogLevel::Trace => tracing::Level::TRACE,
-    };
-    
-    let env_filter = EnvFilter::from_default_env()
-        .add_directive(level.into());
-    
-    let mut layers = Vec::new();
-    
-    if config.console_enabled {
-        let console_layer = fmt::layer()
-            .with_target(true)
-            .with_thread_ids(true)
-            .with_file(true)
-            .with_line_number(true);
-        layers.push(console_layer.boxed());
-    }
-    
-    if config.file_enabled {
-        let log_dir = get_log_directory(config)?;
-        std::fs::create_dir_all(&log_dir)?;
-        
-        let file_appender = rolling::daily(log_dir, "twiggy.log");
-        let file_layer = fmt::layer()
-            .with_writer(file_appender)
-            .with_ansi(false)
-            .with_target(true)
-            .with_thread_ids(true)
-            .with_file(true)
-            .with_line_number(true);
-        layers.push(file_layer.boxed());
-    }
-    
-    if layers.is_empty() {
-        return Err("No logging outputs enabled".into());
-    }
-    
-    tracing_subscriber::registry()
-        .with(env_filter)
-        .with(layers)
-        .init();
-    
-    Ok(())
+#[derive(Debug, Default)]
+struct StartupArgs {
+    repository: Option<PathBuf>,
+    revision: Option<String>,
+    config: Option<PathBuf>,
+    /// Collected while parsing, since logging isn't set up until the
+    /// configuration has been loaded.
+    warnings: Vec<String>,
 }
 
-fn get_log_directory(config: &config::LoggingConfig) -> Result<PathBuf, Box<dyn std::error::Error>> {
-    if let Some(ref custom_dir) = config.log_directory {
-        return Ok(PathBuf::from(custom_dir));
+fn parse_startup_args(args: impl Iterator<Item = String>) -> StartupArgs {
+    let mut startup_args = StartupArgs::default();
+    let mut args = args;
+    
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--ref" | "--commit" => match args.next() {
+                Some(revision) => startup_args.revision = Some(revision),
+                None => startup_args.warnings.push(format!("{} requires a value", arg)),
+            },
+            _ if arg.starts_with("--ref=") || arg.starts_with("--commit=") => {
+                startup_args.revision = arg.split_once('=').map(|(_, value)| value.to_string());
+            }
+            "--config" => match args.next() {
+                Some(path) => startup_args.config = Some(PathBuf::from(path)),
+                None => startup_args.warnings.push(format!("{} requires a value", arg)),
+            },
+            _ if arg.starts_with("--config=") => {
+                startup_args.config = arg.split_once('=').map(|(_, value)| PathBuf::from(value));
+            }
+            _ if arg.starts_with("--") => startup_args.warnings.push(format!("Unknown argument: {}", arg)),
+            _ => startup_args.repository = Some(PathBuf::from(arg)),
+        }
     }
     
-    let project_dirs = directories::ProjectDirs::from("dev", "twiggy", "Twiggy")
-        .ok_or("Could not determine project directories")?;
-    
-    Ok(project_dirs.data_dir().join("logs"))
-}
+    startup_args
+}
\ No newline at end of file
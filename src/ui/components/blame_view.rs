@@ -0,0 +1,230 @@
+use crate::git::types::{BlameLine, CommitId};
+use crate::util::text::truncate_middle;
+use eframe::egui;
+use std::collections::HashMap;
+
+const AUTHOR_WIDTH: usize = 14;
+
+/// Newer blames kept for stepping forward again.
+const STACK_LIMIT: usize = 20;
+
+/// One blame of a file at a revision.
+struct BlameState {
+    revision: CommitId,
+    lines: Vec<BlameLine>,
+    /// First parent of each commit in `lines`; `None` for root commits.
+    parents: HashMap<CommitId, Option<CommitId>>,
+}
+
+#[derive(Default)]
+pub struct BlameViewResponse {
+    /// Revision the user asked to blame at next, the first parent of the
+    /// clicked line's commit. The caller should blame there and `step_back`.
+    pub step_back: Option<CommitId>,
+}
+
+#[derive(Default)]
+pub struct BlameView {
+    pub open: bool,
+    path: String,
+    current: Option<BlameState>,
+    /// Newer blames left by stepping back, most recent last.
+    newer: Vec<BlameState>,
+}
+
+impl BlameView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a fresh blame of `path` at `revision`, forgetting any steps.
+    pub fn show(&mut self, path: String, revision: CommitId, lines: Vec<BlameLine>, parents: HashMap<CommitId, Option<CommitId>>) {
+        tracing::debug!("Showing blame for {} at {} ({} lines)", path, revision.short(), lines.len());
+        self.path = path;
+        self.current = Some(BlameState { revision, lines, parents });
+        self.newer.clear();
+        self.open = true;
+    }
+
+    /// Shows an older blame of the same file, keeping the current one so
+    /// `step_forward` can return to it.
+    pub fn step_back(&mut self, revision: CommitId, lines: Vec<BlameLine>, parents: HashMap<CommitId, Option<CommitId>>) {
+        tracing::debug!("Stepping blame of {} back to {}", self.path, revision.short());
+        if let Some(current) = self.current.replace(BlameState { revision, lines, parents }) {
+            self.newer.push(current);
+            if self.newer.len() > STACK_LIMIT {
+                self.newer.remove(0);
+            }
+        }
+    }
+
+    pub fn step_forward(&mut self) -> bool {
+        match self.newer.pop() {
+            Some(newer) => {
+                self.current = Some(newer);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn can_step_forward(&self) -> bool {
+        !self.newer.is_empty()
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn revision(&self) -> Option<CommitId> {
+        self.current.as_ref().map(|state| state.revision)
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+        self.path.clear();
+        self.current = None;
+        self.newer.clear();
+    }
+
+    pub fn render(&mut self, ctx: &egui::Context) -> BlameViewResponse {
+        let mut response = BlameViewResponse::default();
+        if !self.open {
+            return response;
+        }
+
+        let mut open = self.open;
+        let mut step_forward = false;
+        egui::Window::new(format!("Blame — {}", self.path))
+            .id(egui::Id::new("blame_view"))
+            .open(&mut open)
+            .default_size([800.0, 500.0])
+            .resizable(true)
+            .show(ctx, |ui| {
+                let Some(ref state) = self.current else {
+                    return;
+                };
+
+                ui.horizontal(|ui| {
+                    ui.label(format!("At {}", state.revision.short()));
+                    let newer = ui.add_enabled(self.can_step_forward(), egui::Button::new("▶ Newer"))
+                        .on_hover_text("Return to the blame before the last step back");
+                    if newer.clicked() {
+                        step_forward = true;
+                    }
+                    ui.weak("Click a line to blame before its change");
+                });
+                ui.separator();
+
+                response.step_back = Self::render_lines(ui, state);
+            });
+
+        if step_forward {
+            self.step_forward();
+        }
+
+        if !open {
+            self.close();
+        }
+
+        response
+    }
+
+    fn render_lines(ui: &mut egui::Ui, state: &BlameState) -> Option<CommitId> {
+        let lines = &state.lines;
+        let mut step_back = None;
+
+        if lines.is_empty() {
+            ui.label("File is empty");
+            return None;
+        }
+
+        let font_id = egui::TextStyle::Monospace.resolve(ui.style());
+        let row_height = ui.fonts(|fonts| fonts.row_height(&font_id)) + 2.0;
+        let char_width = ui.fonts(|fonts| fonts.glyph_width(&font_id, 'M'));
+        let gutter_width = char_width * (8.0 + AUTHOR_WIDTH as f32 + 8.0);
+        let text_color = ui.visuals().text_color();
+        let weak_color = ui.visuals().weak_text_color();
+        let group_fill = ui.visuals().faint_bg_color;
+
+        // Consecutive lines from the same commit form a group; every other group is shaded.
+        let mut shaded = Vec::with_capacity(lines.len());
+        let mut shade = false;
+        for (index, line) in lines.iter().enumerate() {
+            if index > 0 && lines[index - 1].commit != line.commit {
+                shade = !shade;
+            }
+            shaded.push(shade);
+        }
+
+        let longest = lines.iter().map(|line| line.content.chars().count()).max().unwrap_or(0);
+        let content_width = gutter_width + char_width * longest as f32 + 8.0;
+
+        egui::ScrollArea::both()
+            .id_source("blame_view_scroll")
+            .auto_shrink([false, false])
+            .show_rows(ui, row_height, lines.len(), |ui, range| {
+                let width = content_width.max(ui.available_width());
+                for index in range {
+                    let line = &lines[index];
+                    let group_start = index == 0 || lines[index - 1].commit != line.commit;
+
+                    let parent = state.parents.get(&line.commit).copied().flatten();
+                    let sense = if parent.is_some() { egui::Sense::click() } else { egui::Sense::hover() };
+                    let (rect, response) = ui.allocate_exact_size(egui::vec2(width, row_height), sense);
+                    let painter = ui.painter_at(rect);
+                    if shaded[index] {
+                        painter.rect_filled(rect, 0.0, group_fill);
+                    }
+
+                    if group_start {
+                        let gutter = format!(
+                            "{} {:<width$}",
+                            line.commit.short(),
+                            truncate_middle(&line.author, AUTHOR_WIDTH),
+                            width = AUTHOR_WIDTH
+                        );
+                        painter.text(
+                            egui::pos2(rect.left() + 4.0, rect.center().y),
+                            egui::Align2::LEFT_CENTER,
+                            gutter,
+                            font_id.clone(),
+                            weak_color,
+                        );
+                    }
+
+                    painter.text(
+                        egui::pos2(rect.left() + gutter_width - char_width, rect.center().y),
+                        egui::Align2::RIGHT_CENTER,
+                        line.line_number.to_string(),
+                        font_id.clone(),
+                        weak_color,
+                    );
+                    painter.text(
+                        egui::pos2(rect.left() + gutter_width + 4.0, rect.center().y),
+                        egui::Align2::LEFT_CENTER,
+                        &line.content,
+                        font_id.clone(),
+                        text_color,
+                    );
+
+                    let step_hint = match parent {
+                        Some(parent) => format!("Click to blame at {}", parent.short()),
+                        None => "Root commit, nothing earlier to blame".to_string(),
+                    };
+                    let response = response.on_hover_text(format!(
+                        "{} by {} on {}\n{}",
+                        line.commit.short(),
+                        line.author,
+                        line.time.format("%Y-%m-%d %H:%M"),
+                        step_hint
+                    ));
+                    if response.clicked() {
+                        step_back = parent;
+                    }
+                }
+            });
+
+        step_back
+    }
+}
\ No newline at end of file
@@ -0,0 +1,191 @@
+use eframe::egui;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaletteCommand {
+    OpenRepository,
+    OpenRecent(PathBuf),
+    ToggleTheme,
+    OpenSettings,
+    ShowLogViewer,
+    Refresh,
+    GoToCommit,
+    OpenInFileManager,
+    OpenInTerminal,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaletteEntry {
+    pub label: String,
+    pub command: PaletteCommand,
+}
+
+impl PaletteEntry {
+    pub fn new(label: impl Into<String>, command: PaletteCommand) -> Self {
+        Self {
+            label: label.into(),
+            command,
+        }
+    }
+}
+
+/// Scores `label` against `query` as a case-insensitive subsequence match,
+/// or `None` if some query character is missing. Consecutive matches and
+/// matches at the start of a word score higher; an empty query matches
+/// everything equally.
+pub fn fuzzy_score(query: &str, label: &str) -> Option<i32> {
+    let label: Vec<char> = label.chars().flat_map(char::to_lowercase).collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous_match: Option<usize> = None;
+
+    for wanted in query.chars().flat_map(char::to_lowercase).filter(|c| !c.is_whitespace()) {
+        let offset = label[position..].iter().position(|&c| c == wanted)?;
+        let index = position + offset;
+
+        score += 1;
+        if previous_match.is_some_and(|previous| previous + 1 == index) {
+            score += 5;
+        }
+        if index == 0 || !label[index - 1].is_alphanumeric() {
+            score += 3;
+        }
+
+        previous_match = Some(index);
+        position = index + 1;
+    }
+
+    Some(score)
+}
+
+#[derive(Default)]
+pub struct CommandPalette {
+    pub open: bool,
+    query: String,
+    selected: usize,
+    entries: Vec<PaletteEntry>,
+    request_focus: bool,
+}
+
+impl CommandPalette {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn show(&mut self, entries: Vec<PaletteEntry>) {
+        self.entries = entries;
+        self.query.clear();
+        self.selected = 0;
+        self.request_focus = true;
+        self.open = true;
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+        self.query.clear();
+        self.entries.clear();
+    }
+
+    pub fn set_query(&mut self, query: &str) {
+        self.query = query.to_string();
+        self.selected = 0;
+    }
+
+    /// Entries matching the current query, best match first.
+    pub fn matches(&self) -> Vec<&PaletteEntry> {
+        let mut scored: Vec<(i32, usize, &PaletteEntry)> = self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| fuzzy_score(&self.query, &entry.label).map(|score| (score, index, entry)))
+            .collect();
+
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        scored.into_iter().map(|(_, _, entry)| entry).collect()
+    }
+
+    fn handle_keyboard(&mut self, ctx: &egui::Context, match_count: usize) -> bool {
+        let mut confirmed = false;
+
+        ctx.input(|i| {
+            if i.key_pressed(egui::Key::ArrowDown) && self.selected + 1 < match_count {
+                self.selected += 1;
+            }
+
+            if i.key_pressed(egui::Key::ArrowUp) {
+                self.selected = self.selected.saturating_sub(1);
+            }
+
+            if i.key_pressed(egui::Key::Enter) {
+                confirmed = true;
+            }
+        });
+
+        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            self.close();
+        }
+
+        confirmed
+    }
+
+    /// Returns the command picked this frame, closing the palette.
+    pub fn render(&mut self, ctx: &egui::Context) -> Option<PaletteCommand> {
+        if !self.open {
+            return None;
+        }
+
+        let match_count = self.matches().len();
+        let confirmed = self.handle_keyboard(ctx, match_count);
+        if !self.open {
+            return None;
+        }
+
+        let mut picked = None;
+
+        egui::Window::new("Command Palette")
+            .title_bar(false)
+            .collapsible(false)
+            .resizable(false)
+            .fixed_size([420.0, 320.0])
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 80.0])
+            .show(ctx, |ui| {
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut self.query)
+                        .hint_text("Type a command…")
+                        .desired_width(f32::INFINITY)
+                );
+                if self.request_focus {
+                    response.request_focus();
+                    self.request_focus = false;
+                }
+                if response.changed() {
+                    self.selected = 0;
+                }
+
+                ui.separator();
+
+                let matches = self.matches();
+                if matches.is_empty() {
+                    ui.weak("No matching commands");
+                }
+
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    for (index, entry) in matches.iter().enumerate() {
+                        let is_selected = index == self.selected;
+                        let label = ui.selectable_label(is_selected, &entry.label);
+                        if is_selected {
+                            label.scroll_to_me(None);
+                        }
+                        if label.clicked() || (confirmed && is_selected) {
+                            picked = Some(entry.command.clone());
+                        }
+                    }
+                });
+            });
+
+        if picked.is_some() {
+            self.close();
+        }
+
+        picked
+    }
+}
\ No newline at end of file
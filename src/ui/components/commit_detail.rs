@@ -0,0 +1,104 @@
+use crate::git::types::{Commit, CommitId, Signature};
+use eframe::egui;
+
+#[derive(Default)]
+pub struct CommitDetail;
+
+#[derive(Default)]
+pub struct CommitDetailResponse {
+    /// Parent the user clicked; the caller should select it in the list.
+    pub selected_parent: Option<CommitId>,
+}
+
+impl CommitDetail {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// `description` is the commit's `git describe` position, shown beside
+    /// the hash.
+    pub fn render(&mut self, ui: &mut egui::Ui, commit: &Commit, description: &str) -> CommitDetailResponse {
+        let mut response = CommitDetailResponse::default();
+
+        egui::ScrollArea::vertical()
+            .id_source("commit_detail_scroll")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.monospace(commit.id.as_str());
+                    ui.label(egui::RichText::new(description).monospace().weak())
+                        .on_hover_text("Nearest tag (git describe --tags)");
+                    if is_merge(commit) {
+                        ui.label(egui::RichText::new("merge commit").small().color(egui::Color32::from_rgb(200, 150, 50)));
+                    }
+                });
+                ui.strong(&commit.summary);
+                ui.separator();
+
+                egui::Grid::new("commit_detail_grid")
+                    .num_columns(2)
+                    .spacing([8.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label("Author:");
+                        ui.label(format_signature(&commit.author));
+                        ui.end_row();
+
+                        if !same_signature(&commit.author, &commit.committer) {
+                            ui.label("Committer:");
+                            ui.label(format_signature(&commit.committer));
+                            ui.end_row();
+                        }
+
+                        let parents_label = if commit.parents.len() == 1 { "Parent:" } else { "Parents:" };
+                        ui.label(parents_label);
+                        ui.vertical(|ui| {
+                            if commit.parents.is_empty() {
+                                ui.weak("none (root commit)");
+                            }
+                            for parent in &commit.parents {
+                                let link = ui.link(egui::RichText::new(parent.short()).monospace())
+                                    .on_hover_text(parent.as_str());
+                                if link.clicked() {
+                                    response.selected_parent = Some(*parent);
+                                }
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label("Tree:");
+                        ui.monospace(&commit.tree_id);
+                        ui.end_row();
+                    });
+
+                ui.separator();
+
+                let mut message = commit.message.trim_end().to_string();
+                ui.add(
+                    egui::TextEdit::multiline(&mut message)
+                        .font(egui::TextStyle::Monospace)
+                        .desired_width(f32::INFINITY)
+                        .interactive(false),
+                );
+            });
+
+        response
+    }
+}
+
+pub fn is_merge(commit: &Commit) -> bool {
+    commit.parents.len() > 1
+}
+
+/// Formats a signature as "Name <email>, 2024-01-31 14:05".
+pub fn format_signature(signature: &Signature) -> String {
+    let date = signature.time.format("%Y-%m-%d %H:%M");
+    if signature.email.is_empty() {
+        format!("{}, {}", signature.name, date)
+    } else {
+        format!("{} <{}>, {}", signature.name, signature.email, date)
+    }
+}
+
+fn same_signature(a: &Signature, b: &Signature) -> bool {
+    a.name == b.name && a.email == b.email && a.time == b.time
+}
\ No newline at end of file
@@ -0,0 +1,418 @@
+use crate::git::types::{Commit, CommitId, RefKind, RefLabel};
+use eframe::egui;
+use std::collections::HashMap;
+
+const LANE_SPACING: f32 = 14.0;
+const NODE_RADIUS: f32 = 4.0;
+const GRAPH_PADDING: f32 = 6.0;
+const DEFAULT_ROW_HEIGHT: f32 = 60.0;
+const MIN_ZOOM: f32 = 0.5;
+const MAX_ZOOM: f32 = 3.0;
+const LEGEND_LIMIT: usize = 6;
+
+const LANE_COLORS: [egui::Color32; 8] = [
+    egui::Color32::from_rgb(66, 165, 245),
+    egui::Color32::from_rgb(102, 187, 106),
+    egui::Color32::from_rgb(255, 167, 38),
+    egui::Color32::from_rgb(171, 71, 188),
+    egui::Color32::from_rgb(239, 83, 80),
+    egui::Color32::from_rgb(38, 198, 218),
+    egui::Color32::from_rgb(212, 225, 87),
+    egui::Color32::from_rgb(236, 64, 122),
+];
+
+/// Edge from a commit to one of its parents. The edge leaves the child node,
+/// runs down `via_lane` and joins the parent node; parents that are not loaded
+/// have `to_row == rows`, so the edge runs off the bottom of the graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GraphEdge {
+    pub from_row: usize,
+    pub from_lane: usize,
+    pub via_lane: usize,
+    pub to_row: usize,
+    pub to_lane: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GraphLayout {
+    pub lanes: Vec<usize>,
+    pub edges: Vec<GraphEdge>,
+    pub lane_count: usize,
+}
+
+/// Assigns every commit a lane, walking the list top to bottom. Commits must be
+/// in the order they are displayed (children before parents). Lanes are
+/// computed over the whole list, so they do not change while scrolling.
+pub fn compute_layout(commits: &[Commit]) -> GraphLayout {
+    let rows: HashMap<CommitId, usize> = commits
+        .iter()
+        .enumerate()
+        .map(|(row, commit)| (commit.id, row))
+        .collect();
+
+    let mut active: Vec<Option<CommitId>> = Vec::new();
+    let mut lanes = Vec::with_capacity(commits.len());
+    let mut pending = Vec::new();
+    let mut lane_count = 0;
+
+    for (row, commit) in commits.iter().enumerate() {
+        let lane = match active.iter().position(|slot| *slot == Some(commit.id)) {
+            Some(lane) => lane,
+            None => free_lane(&mut active),
+        };
+
+        // Several children may have been waiting for this commit; they all end here.
+        for slot in active.iter_mut() {
+            if *slot == Some(commit.id) {
+                *slot = None;
+            }
+        }
+
+        lanes.push(lane);
+
+        for parent in &commit.parents {
+            let via_lane = match active.iter().position(|slot| *slot == Some(*parent)) {
+                Some(existing) => existing,
+                None if active[lane].is_none() => {
+                    active[lane] = Some(*parent);
+                    lane
+                }
+                None => {
+                    let free = free_lane(&mut active);
+                    active[free] = Some(*parent);
+                    free
+                }
+            };
+            pending.push((row, lane, via_lane, *parent));
+        }
+
+        lane_count = lane_count.max(active.len()).max(lane + 1);
+
+        while active.last() == Some(&None) {
+            active.pop();
+        }
+    }
+
+    let edges = pending
+        .into_iter()
+        .map(|(from_row, from_lane, via_lane, parent)| {
+            let to_row = rows.get(&parent).copied().unwrap_or(commits.len());
+            let to_lane = lanes.get(to_row).copied().unwrap_or(via_lane);
+            GraphEdge {
+                from_row,
+                from_lane,
+                via_lane,
+                to_row,
+                to_lane,
+            }
+        })
+        .collect();
+
+    GraphLayout {
+        lanes,
+        edges,
+        lane_count,
+    }
+}
+
+/// Name a commit's lane is colored after: its first local branch, else its
+/// first remote branch, else its first tag.
+pub fn tip_name(labels: &[RefLabel]) -> Option<&str> {
+    [RefKind::LocalBranch, RefKind::RemoteBranch, RefKind::Tag]
+        .iter()
+        .find_map(|kind| labels.iter().find(|label| label.kind == *kind))
+        .map(|label| label.name.as_str())
+}
+
+/// Palette color for the ref `name`. Uses FNV-1a rather than the std hasher
+/// so a branch keeps its color across refreshes and restarts.
+pub fn ref_color(name: &str) -> egui::Color32 {
+    let hash = name
+        .bytes()
+        .fold(0xcbf29ce484222325u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3));
+    LANE_COLORS[(hash % LANE_COLORS.len() as u64) as usize]
+}
+
+/// Ref each row is colored after. A commit named in `tips` starts a
+/// segment; every other commit takes the ref of the child whose first-parent
+/// edge continues down the same lane into it, or `None` if there is none.
+pub fn row_refs(layout: &GraphLayout, commits: &[Commit], tips: &HashMap<CommitId, String>) -> Vec<Option<String>> {
+    let mut refs: Vec<Option<String>> = commits.iter().map(|commit| tips.get(&commit.id).cloned()).collect();
+
+    // Edges are ordered by child row, then by parent order, so the first edge
+    // seen for a row is its first parent and its own ref is already final.
+    let mut previous_row = None;
+    for edge in &layout.edges {
+        let first_parent = previous_row != Some(edge.from_row);
+        previous_row = Some(edge.from_row);
+
+        if !first_parent || edge.to_row >= refs.len() || refs[edge.to_row].is_some() {
+            continue;
+        }
+        if edge.from_lane == edge.via_lane && edge.via_lane == edge.to_lane {
+            refs[edge.to_row] = refs[edge.from_row].clone();
+        }
+    }
+
+    refs
+}
+
+fn free_lane(active: &mut Vec<Option<CommitId>>) -> usize {
+    match active.iter().position(|slot| slot.is_none()) {
+        Some(lane) => lane,
+        None => {
+            active.push(None);
+            active.len() - 1
+        }
+    }
+}
+
+pub struct CommitGraph {
+    pub zoom_level: f32,
+    pub scroll_offset: (f32, f32),
+    layout: GraphLayout,
+    layout_key: Option<(usize, CommitId, CommitId)>,
+    row_centers: Vec<f32>,
+    tips: HashMap<CommitId, String>,
+    row_refs: Vec<Option<String>>,
+    refs_stale: bool,
+}
+
+impl Default for CommitGraph {
+    fn default() -> Self {
+        Self {
+            zoom_level: 1.0,
+            scroll_offset: (0.0, 0.0),
+            layout: GraphLayout::default(),
+            layout_key: None,
+            row_centers: Vec::new(),
+            tips: HashMap::new(),
+            row_refs: Vec::new(),
+            refs_stale: true,
+        }
+    }
+}
+
+impl CommitGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn layout(&self) -> &GraphLayout {
+        &self.layout
+    }
+
+    /// Aligns the graph with the commit list: `row_centers` are the vertical
+    /// centers of the list rows in content coordinates and `scroll_y` is the
+    /// list's current scroll offset.
+    pub fn sync_rows(&mut self, row_centers: &[f32], scroll_y: f32) {
+        self.row_centers.clear();
+        self.row_centers.extend_from_slice(row_centers);
+        self.scroll_offset.1 = scroll_y;
+    }
+
+    /// Takes the branch and tag tips used to color lanes.
+    pub fn set_ref_labels(&mut self, labels: &HashMap<CommitId, Vec<RefLabel>>) {
+        self.tips = labels
+            .iter()
+            .filter_map(|(id, labels)| Some((*id, tip_name(labels)?.to_string())))
+            .collect();
+        self.refs_stale = true;
+    }
+
+    /// Color of `row`: its ref's color, or the lane's when it has none.
+    pub fn row_color(&self, row: usize) -> egui::Color32 {
+        match self.row_refs.get(row).and_then(Option::as_deref) {
+            Some(name) => ref_color(name),
+            None => LANE_COLORS[self.layout.lanes.get(row).copied().unwrap_or(0) % LANE_COLORS.len()],
+        }
+    }
+
+    pub fn desired_width(&mut self, commits: &[Commit]) -> f32 {
+        self.update_layout(commits);
+        self.layout.lane_count as f32 * self.lane_spacing() + GRAPH_PADDING * 2.0
+    }
+
+    fn update_layout(&mut self, commits: &[Commit]) {
+        let key = match (commits.first(), commits.last()) {
+            (Some(first), Some(last)) => Some((commits.len(), first.id, last.id)),
+            _ => None,
+        };
+
+        if key != self.layout_key {
+            self.layout = compute_layout(commits);
+            self.layout_key = key;
+            self.refs_stale = true;
+            tracing::debug!(
+                "Computed commit graph layout: {} rows, {} lanes",
+                self.layout.lanes.len(),
+                self.layout.lane_count
+            );
+        }
+
+        if self.refs_stale {
+            self.row_refs = row_refs(&self.layout, commits, &self.tips);
+            self.refs_stale = false;
+        }
+    }
+
+    fn lane_spacing(&self) -> f32 {
+        LANE_SPACING * self.zoom_level
+    }
+
+    /// Vertical center of `row` in content coordinates. Once synced this is
+    /// exactly the list's row center, whatever the zoom level.
+    pub fn row_center(&self, row: usize) -> f32 {
+        if self.row_centers.len() == self.layout.lanes.len() && !self.row_centers.is_empty() {
+            match self.row_centers.get(row) {
+                Some(center) => *center,
+                None => {
+                    let last = self.row_centers.len() - 1;
+                    let step = if last > 0 {
+                        self.row_centers[last] - self.row_centers[last - 1]
+                    } else {
+                        DEFAULT_ROW_HEIGHT
+                    };
+                    self.row_centers[last] + step * (row - last) as f32
+                }
+            }
+        } else {
+            let row_height = DEFAULT_ROW_HEIGHT * self.zoom_level;
+            row as f32 * row_height + row_height / 2.0
+        }
+    }
+
+    /// Draws the graph beside the commit list. `row_range` is the list's
+    /// `get_visible_range()`; once synced only nodes in it, and edges
+    /// crossing it, are drawn.
+    pub fn render(&mut self, ui: &mut egui::Ui, commits: &[Commit], row_range: (usize, usize)) {
+        self.update_layout(commits);
+
+        let rect = ui.available_rect_before_wrap();
+        let response = ui.allocate_rect(rect, egui::Sense::drag());
+        let synced = self.row_centers.len() == self.layout.lanes.len();
+        let (first_row, last_row) = if synced {
+            (row_range.0.min(self.layout.lanes.len()), row_range.1.min(self.layout.lanes.len()))
+        } else {
+            (0, self.layout.lanes.len())
+        };
+
+        if response.hovered() {
+            let zoom_delta = ui.input(|i| i.zoom_delta());
+            if zoom_delta != 1.0 {
+                self.zoom_level = (self.zoom_level * zoom_delta).clamp(MIN_ZOOM, MAX_ZOOM);
+            }
+        }
+
+        if response.dragged() {
+            let delta = response.drag_delta();
+            self.scroll_offset.0 -= delta.x;
+            if !synced {
+                self.scroll_offset.1 -= delta.y;
+            }
+        }
+
+        let content_width = self.layout.lane_count as f32 * self.lane_spacing() + GRAPH_PADDING * 2.0;
+        self.scroll_offset.0 = self.scroll_offset.0.clamp(0.0, (content_width - rect.width()).max(0.0));
+        if !synced {
+            self.scroll_offset.1 = self.scroll_offset.1.max(0.0);
+        }
+
+        let painter = ui.painter_at(rect);
+        let x = |lane: usize| {
+            rect.left() + GRAPH_PADDING + (lane as f32 + 0.5) * self.lane_spacing() - self.scroll_offset.0
+        };
+        let y = |row: usize| rect.top() + self.row_center(row) - self.scroll_offset.1;
+        let stroke_width = (1.5 * self.zoom_level).max(1.0);
+
+        for edge in &self.layout.edges {
+            if edge.to_row < first_row || edge.from_row >= last_row {
+                continue;
+            }
+
+            let top = y(edge.from_row);
+            let bottom = y(edge.to_row);
+            if bottom < rect.top() || top > rect.bottom() {
+                continue;
+            }
+
+            // Merge edges take the color of the parent they lead to.
+            let color = if edge.to_row < self.layout.lanes.len() {
+                self.row_color(edge.to_row)
+            } else if edge.via_lane == edge.from_lane {
+                self.row_color(edge.from_row)
+            } else {
+                LANE_COLORS[edge.via_lane % LANE_COLORS.len()]
+            };
+            let stroke = egui::Stroke::new(stroke_width, color);
+            let start = egui::pos2(x(edge.from_lane), top);
+            let end = egui::pos2(x(edge.to_lane), bottom);
+
+            if edge.to_row <= edge.from_row + 1 {
+                painter.line_segment([start, end], stroke);
+                continue;
+            }
+
+            let bend_top = egui::pos2(x(edge.via_lane), y(edge.from_row + 1));
+            let bend_bottom = egui::pos2(x(edge.via_lane), y(edge.to_row - 1));
+            painter.line_segment([start, bend_top], stroke);
+            painter.line_segment([bend_top, bend_bottom], stroke);
+            painter.line_segment([bend_bottom, end], stroke);
+        }
+
+        let radius = NODE_RADIUS * self.zoom_level;
+        let background = ui.visuals().extreme_bg_color;
+        for (row, lane) in self.layout.lanes.iter().enumerate().take(last_row).skip(first_row) {
+            let center = egui::pos2(x(*lane), y(row));
+            if center.y + radius < rect.top() || center.y - radius > rect.bottom() {
+                continue;
+            }
+
+            let color = self.row_color(row);
+            if commits.get(row).is_some_and(|commit| commit.parents.len() > 1) {
+                painter.circle(center, radius, background, egui::Stroke::new(stroke_width, color));
+            } else {
+                painter.circle_filled(center, radius, color);
+            }
+        }
+
+        self.render_legend(ui, rect, first_row, last_row);
+    }
+
+    /// Swatches for the refs coloring the visible rows, pinned to the
+    /// bottom-left corner of the graph.
+    fn render_legend(&self, ui: &egui::Ui, rect: egui::Rect, first_row: usize, last_row: usize) {
+        let mut names: Vec<&str> = Vec::new();
+        for name in self.row_refs[first_row.min(self.row_refs.len())..last_row.min(self.row_refs.len())]
+            .iter()
+            .flatten()
+        {
+            if !names.contains(&name.as_str()) {
+                names.push(name);
+            }
+        }
+
+        if names.is_empty() {
+            return;
+        }
+
+        egui::Area::new(ui.id().with("graph_legend"))
+            .fixed_pos(rect.left_bottom())
+            .pivot(egui::Align2::LEFT_BOTTOM)
+            .interactable(false)
+            .show(ui.ctx(), |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    for name in names.iter().take(LEGEND_LIMIT) {
+                        ui.horizontal(|ui| {
+                            let (swatch, _) = ui.allocate_exact_size(egui::vec2(10.0, 10.0), egui::Sense::hover());
+                            ui.painter().circle_filled(swatch.center(), 4.0, ref_color(name));
+                            ui.small(*name);
+                        });
+                    }
+                    if names.len() > LEGEND_LIMIT {
+                        ui.small(format!("+{} more", names.len() - LEGEND_LIMIT));
+                    }
+                });
+            });
+    }
+}
\ No newline at end of file
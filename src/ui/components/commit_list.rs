@@ -1,22 +1,75 @@
-use crate::git::types::{Commit, CommitId};
+use crate::git::filter::CommitFilter;
+use crate::git::types::{Commit, CommitId, DiffStats, RefKind, RefLabel, SignatureStatus};
+use crate::util::text::{find_ignore_case, truncate_middle};
 use eframe::egui;
+use std::collections::{HashMap, HashSet};
+
+/// Rows from the end of the list at which the next page is requested.
+const LOAD_MORE_THRESHOLD: usize = 5;
+const LOADING_ROW_HEIGHT: f32 = 28.0;
+
+/// Indices of the commits matching `query`, kept until the query or the
+/// list changes.
+struct FilterRows {
+    query: String,
+    len: usize,
+    first: Option<CommitId>,
+    rows: Vec<usize>,
+}
 
 pub struct CommitListComponent {
     selected_commit: Option<CommitId>,
+    /// Set by shift-clicking a second commit: the older and newer end of the
+    /// range being compared.
+    compare_range: Option<(CommitId, CommitId)>,
     scroll_offset: f32,
     hover_commit: Option<CommitId>,
     item_height: f32,
     visible_range: (usize, usize),
+    ref_labels: HashMap<CommitId, Vec<RefLabel>>,
+    max_author_length: usize,
+    pending_scroll: Option<CommitId>,
+    /// Row moved to by the keyboard, scrolled to only if it is off screen.
+    pending_reveal: Option<CommitId>,
+    expanded: HashSet<CommitId>,
+    expanded_heights: HashMap<CommitId, f32>,
+    allow_multiple_expanded: bool,
+    open_on_web_enabled: bool,
+    row_centers: Vec<f32>,
+    tag_messages: HashMap<String, String>,
+    commit_stats: HashMap<CommitId, DiffStats>,
+    signature_statuses: HashMap<CommitId, SignatureStatus>,
+    has_more_commits: bool,
+    loading_more: bool,
+    filter: String,
+    filter_rows: Option<FilterRows>,
 }
 
 impl Default for CommitListComponent {
     fn default() -> Self {
         Self {
             selected_commit: None,
+            compare_range: None,
             scroll_offset: 0.0,
             hover_commit: None,
             item_height: 60.0,
             visible_range: (0, 0),
+            ref_labels: HashMap::new(),
+            max_author_length: 32,
+            pending_scroll: None,
+            pending_reveal: None,
+            expanded: HashSet::new(),
+            expanded_heights: HashMap::new(),
+            allow_multiple_expanded: false,
+            open_on_web_enabled: false,
+            row_centers: Vec::new(),
+            tag_messages: HashMap::new(),
+            commit_stats: HashMap::new(),
+            signature_statuses: HashMap::new(),
+            has_more_commits: false,
+            loading_more: false,
+            filter: String::new(),
+            filter_rows: None,
         }
     }
 }
@@ -31,41 +84,125 @@ impl CommitListComponent {
             selected: self.selected_commit,
             clicked: None,
             double_clicked: None,
+            ref_clicked: None,
+            open_on_web: None,
+            copied: None,
+            compared: None,
+            load_more: false,
         };
 
         if commits.is_empty() {
+            self.row_centers.clear();
             self.render_empty_state(ui);
             return response;
         }
 
+        let rows = self.matching_rows(commits);
+        if rows.is_empty() {
+            self.row_centers.clear();
+            ui.vertical_centered(|ui| {
+                ui.add_space(50.0);
+                ui.heading("No commits match the filter");
+                ui.add_space(10.0);
+                ui.label("Press Escape in the filter field to clear it");
+            });
+            return response;
+        }
+
         let available_rect = ui.available_rect_before_wrap();
-        let _visible_items = (available_rect.height() / self.item_height).ceil() as usize + 2;
+        let spacing = ui.spacing().item_spacing.y;
+        
+        let heights: Vec<f32> = rows.iter().map(|&index| self.row_height(&commits[index].id)).collect();
+        let mut offsets = Vec::with_capacity(rows.len() + 1);
+        let mut total_height = 0.0;
+        for height in &heights {
+            offsets.push(total_height);
+            total_height += height + spacing;
+        }
+        offsets.push(total_height);
+        
+        let footer_height = if self.has_more_commits { LOADING_ROW_HEIGHT } else { 0.0 };
+        
+        self.row_centers.clear();
+        self.row_centers.extend(offsets.iter().zip(&heights).map(|(offset, height)| offset + height / 2.0));
         
-        egui::ScrollArea::vertical()
+        let mut scroll_area = egui::ScrollArea::vertical()
             .auto_shrink([false, false])
-            .max_height(available_rect.height())
-            .show_rows(ui, self.item_height, commits.len(), |ui, row_range| {
-                self.visible_range = (row_range.start, row_range.end);
+            .max_height(available_rect.height());
+        
+        if let Some(target) = self.pending_scroll.take() {
+            if let Some(row) = rows.iter().position(|&index| commits[index].id == target) {
+                let offset = offsets[row] - (available_rect.height() - heights[row]) / 2.0;
+                scroll_area = scroll_area.vertical_scroll_offset(offset.max(0.0));
+            }
+        } else if let Some(target) = self.pending_reveal.take() {
+            if let Some(row) = rows.iter().position(|&index| commits[index].id == target) {
+                let offset = scroll_offset_to_reveal(
+                    self.scroll_offset,
+                    available_rect.height(),
+                    offsets[row],
+                    heights[row],
+                    (total_height - spacing).max(0.0) + footer_height,
+                );
+                if offset != self.scroll_offset {
+                    scroll_area = scroll_area.vertical_scroll_offset(offset);
+                }
+            }
+        }
+        
+        let mut toggled = None;
+        let mut remeasured = false;
+        
+        let range_rows = self.compare_range.and_then(|(older, newer)| {
+            let older = commits.iter().position(|c| c.id == older)?;
+            let newer = commits.iter().position(|c| c.id == newer)?;
+            Some((newer, older))
+        });
+        
+        scroll_area
+            .show_viewport(ui, |ui, viewport| {
+                ui.set_height((total_height - spacing).max(0.0) + footer_height);
                 
-                for index in row_range {
-                    if index >= commits.len() {
+                let top = ui.max_rect().top();
+                let first_row = offsets.partition_point(|&offset| offset <= viewport.min.y).saturating_sub(1);
+                let mut last_row = first_row;
+                
+                for row in first_row..rows.len() {
+                    if offsets[row] > viewport.max.y {
                         break;
                     }
+                    last_row = row + 1;
                     
+                    let index = rows[row];
                     let commit = &commits[index];
-                    let is_selected = self.selected_commit == Some(commit.id);
+                    let in_range = range_rows.is_some_and(|(first, last)| (first..=last).contains(&index));
+                    let is_selected = self.selected_commit == Some(commit.id)
+                        || self.compare_range.is_some_and(|(older, newer)| commit.id == older || commit.id == newer);
                     let is_hovered = self.hover_commit == Some(commit.id);
-                    let is_even = index % 2 == 0;
-
-                    let item_response = self.render_commit_item(
-                        ui,
-                        commit,
-                        is_selected,
-                        is_hovered,
-                        is_even,
-                        index,
+                    let is_even = row % 2 == 0;
+                    
+                    let row_rect = egui::Rect::from_min_size(
+                        egui::pos2(ui.max_rect().left(), top + offsets[row]),
+                        egui::vec2(ui.max_rect().width(), heights[row]),
                     );
 
+                    let (item_response, chip_rects, toggle_rect) = ui
+                        .allocate_ui_at_rect(row_rect, |ui| {
+                            ui.push_id(commit.id.0, |ui| {
+                                self.render_commit_item(ui, commit, is_selected, in_range, is_hovered, is_even)
+                            })
+                            .inner
+                        })
+                        .inner;
+
+                    if self.expanded.contains(&commit.id) {
+                        let measured = item_response.rect.height();
+                        if (measured - heights[row]).abs() > 0.5 {
+                            self.expanded_heights.insert(commit.id, measured);
+                            remeasured = true;
+                        }
+                    }
+
                     if item_response.hovered() {
                         self.hover_commit = Some(commit.id);
                     } else if self.hover_commit == Some(commit.id) {
@@ -73,19 +210,200 @@ impl CommitListComponent {
                     }
 
                     if item_response.clicked() {
-                        self.selected_commit = Some(commit.id);
-                        response.clicked = Some(commit.id);
+                        let pointer = item_response.interact_pointer_pos();
+                        let clicked_chip = pointer.and_then(|pos| {
+                            chip_rects
+                                .iter()
+                                .find(|(rect, _)| rect.contains(pos))
+                                .map(|(_, label)| label.clone())
+                        });
+                        let clicked_toggle = matches!((pointer, toggle_rect), (Some(pos), Some(rect)) if rect.contains(pos));
+
+                        if clicked_toggle {
+                            toggled = Some(commit.id);
+                        } else if let Some(label) = clicked_chip {
+                            response.ref_clicked = Some((commit.id, label));
+                        } else if let Some(anchor) = self.selected_commit.filter(|&id| {
+                            id != commit.id && ui.input(|i| i.modifiers.shift)
+                        }) {
+                            let anchor_index = commits.iter().position(|c| c.id == anchor);
+                            let range = match anchor_index {
+                                Some(anchor_index) if anchor_index < index => (commit.id, anchor),
+                                _ => (anchor, commit.id),
+                            };
+                            self.compare_range = Some(range);
+                            response.compared = Some(range);
+                        } else {
+                            self.selected_commit = Some(commit.id);
+                            self.compare_range = None;
+                            response.clicked = Some(commit.id);
+                        }
                     }
 
                     if item_response.double_clicked() {
                         response.double_clicked = Some(commit.id);
                     }
+
+                    item_response.context_menu(|ui| {
+                        for target in CopyTarget::ALL {
+                            if ui.button(target.label()).clicked() {
+                                ui.output_mut(|o| o.copied_text = target.text(commit));
+                                response.copied = Some((commit.id, target));
+                                ui.close_menu();
+                            }
+                        }
+
+                        if self.open_on_web_enabled {
+                            ui.separator();
+                            if ui.button("🌐 Open on Web").clicked() {
+                                response.open_on_web = Some(commit.id);
+                                ui.close_menu();
+                            }
+                        }
+                    });
                 }
+                
+                if self.loading_more && total_height <= viewport.max.y {
+                    let footer_rect = egui::Rect::from_min_size(
+                        egui::pos2(ui.max_rect().left() + 12.0, top + total_height),
+                        egui::vec2(ui.max_rect().width(), footer_height),
+                    );
+                    ui.allocate_ui_at_rect(footer_rect, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.weak("Loading more…");
+                        });
+                    });
+                }
+                
+                // Reported in commit indices so callers can slice `commits`.
+                self.visible_range = if last_row > first_row {
+                    (rows[first_row], rows[last_row - 1] + 1)
+                } else {
+                    let at = rows[first_row.min(rows.len() - 1)];
+                    (at, at)
+                };
+                self.scroll_offset = viewport.min.y;
             });
+        
+        let (_, last_visible) = self.visible_range;
+        if self.has_more_commits && !self.loading_more && last_visible + LOAD_MORE_THRESHOLD >= commits.len() {
+            response.load_more = true;
+        }
+
+        if let Some(commit_id) = toggled {
+            self.toggle_expanded(commit_id);
+            ui.ctx().request_repaint();
+        } else if remeasured {
+            ui.ctx().request_repaint();
+        }
 
         response
     }
 
+    /// The summary, with the part matching the filter highlighted.
+    fn summary_text(&self, ui: &egui::Ui, summary: &str, text_color: egui::Color32) -> egui::text::LayoutJob {
+        let font_id = egui::FontId::proportional(14.0);
+        let plain = egui::TextFormat::simple(font_id.clone(), text_color);
+        let mut job = egui::text::LayoutJob::default();
+
+        match find_ignore_case(summary, self.filter.trim()) {
+            Some((start, end)) => {
+                let highlight = egui::TextFormat {
+                    background: ui.visuals().selection.bg_fill.gamma_multiply(0.6),
+                    ..egui::TextFormat::simple(font_id, ui.visuals().strong_text_color())
+                };
+                job.append(&summary[..start], 0.0, plain.clone());
+                job.append(&summary[start..end], 0.0, highlight);
+                job.append(&summary[end..], 0.0, plain);
+            }
+            None => job.append(summary, 0.0, plain),
+        }
+
+        job
+    }
+
+    /// Renders the filter field. Typing narrows the list to matching
+    /// commits, Enter selects the next match and Escape clears the filter.
+    pub fn render_filter(&mut self, ui: &mut egui::Ui, commits: &[Commit]) {
+        ui.horizontal(|ui| {
+            ui.label("🔍");
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.filter)
+                    .hint_text("Filter commits by message, author or hash")
+                    .desired_width(f32::INFINITY),
+            );
+
+            if response.lost_focus() {
+                if ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                    self.filter.clear();
+                } else if ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                    self.select_next_match(commits);
+                    response.request_focus();
+                }
+            }
+        });
+    }
+
+    fn select_next_match(&mut self, commits: &[Commit]) {
+        let rows = self.matching_rows(commits);
+        if rows.is_empty() {
+            return;
+        }
+
+        let current = self.selected_commit
+            .and_then(|id| rows.iter().position(|&index| commits[index].id == id));
+        let next = match current {
+            Some(row) => (row + 1) % rows.len(),
+            None => 0,
+        };
+        self.scroll_to_commit(commits[rows[next]].id);
+    }
+
+    /// Indices into `commits` of the rows to show: all of them, or the ones
+    /// matching the filter.
+    fn matching_rows(&mut self, commits: &[Commit]) -> Vec<usize> {
+        let query = self.filter.trim();
+        if query.is_empty() {
+            return (0..commits.len()).collect();
+        }
+
+        let first = commits.first().map(|commit| commit.id);
+        let cached = self.filter_rows.as_ref().filter(|cached| {
+            cached.query == query && cached.len == commits.len() && cached.first == first
+        });
+        if let Some(cached) = cached {
+            return cached.rows.clone();
+        }
+
+        let filter = CommitFilter {
+            text: Some(query.to_string()),
+            ..Default::default()
+        };
+        let rows: Vec<usize> = commits.iter()
+            .enumerate()
+            .filter(|(_, commit)| filter.matches(commit))
+            .map(|(index, _)| index)
+            .collect();
+
+        self.filter_rows = Some(FilterRows {
+            query: query.to_string(),
+            len: commits.len(),
+            first,
+            rows: rows.clone(),
+        });
+        rows
+    }
+
+    pub fn is_filtering(&self) -> bool {
+        !self.filter.trim().is_empty()
+    }
+
+    pub fn clear_filter(&mut self) {
+        self.filter.clear();
+        self.filter_rows = None;
+    }
+
     fn render_empty_state(&self, ui: &mut egui::Ui) {
         ui.centered_and_justified(|ui| {
             ui.vertical_centered(|ui| {
@@ -103,12 +421,19 @@ impl CommitListComponent {
         ui: &mut egui::Ui,
         commit: &Commit,
         is_selected: bool,
+        in_range: bool,
         is_hovered: bool,
         is_even: bool,
-        _index: usize,
-    ) -> egui::Response {
+    ) -> (egui::Response, Vec<(egui::Rect, RefLabel)>, Option<egui::Rect>) {
+        let mut chip_rects = Vec::new();
+        let mut toggle_rect = None;
+        let body = Self::commit_body(commit);
+        let is_expanded = self.expanded.contains(&commit.id);
+
         let bg_color = if is_selected {
             ui.visuals().selection.bg_fill
+        } else if in_range {
+            ui.visuals().selection.bg_fill.gamma_multiply(0.35)
         } else if is_hovered {
             ui.visuals().widgets.hovered.bg_fill
         } else if is_even {
@@ -137,6 +462,18 @@ impl CommitListComponent {
             
             ui.vertical(|ui| {
                 ui.horizontal(|ui| {
+                    if !body.is_empty() {
+                        let triangle = ui.add(
+                            egui::Label::new(
+                                egui::RichText::new(if is_expanded { "▼" } else { "▶" })
+                                    .color(if is_selected { text_color } else { ui.visuals().weak_text_color() })
+                                    .size(10.0)
+                            )
+                        )
+                        .on_hover_text(if is_expanded { "Collapse message" } else { "Expand message" });
+                        toggle_rect = Some(triangle.rect.expand(4.0));
+                    }
+                    
                     ui.add(
                         egui::Label::new(
                             egui::RichText::new(commit.id.short())
@@ -148,6 +485,14 @@ impl CommitListComponent {
                     
                     ui.add_space(8.0);
                     
+                    if let Some(labels) = self.ref_labels.get(&commit.id) {
+                        for label in labels {
+                            let chip = Self::render_ref_chip(ui, label, self.tag_messages.get(&label.name));
+                            chip_rects.push((chip.rect, label.clone()));
+                        }
+                        ui.add_space(4.0);
+                    }
+                    
                     if commit.parents.len() > 1 {
                         ui.add(
                             egui::Label::new(
@@ -159,14 +504,21 @@ impl CommitListComponent {
                         ui.add_space(4.0);
                     }
                     
+                    let signature_icon = match self.signature_statuses.get(&commit.id) {
+                        Some(SignatureStatus::Valid) => Some(("🔒", egui::Color32::from_rgb(100, 190, 100), "Signed (signature not verified)")),
+                        Some(SignatureStatus::Invalid) => Some(("🔓", egui::Color32::from_rgb(220, 120, 80), "Malformed signature")),
+                        _ => None,
+                    };
+                    if let Some((icon, color, hover)) = signature_icon {
+                        ui.add(egui::Label::new(egui::RichText::new(icon).color(color).size(12.0)))
+                            .on_hover_text(hover);
+                        ui.add_space(4.0);
+                    }
+                    
                     ui.add(
-                        egui::Label::new(
-                            egui::RichText::new(&commit.summary)
-                                .color(text_color)
-                                .size(14.0)
-                        )
-                        .wrap(false)
-                        .truncate(true)
+                        egui::Label::new(self.summary_text(ui, &commit.summary, text_color))
+                            .wrap(false)
+                            .truncate(true)
                     );
                 });
                 
@@ -175,11 +527,37 @@ impl CommitListComponent {
                 ui.horizontal(|ui| {
                     ui.add(
                         egui::Label::new(
-                            egui::RichText::new(&commit.author.name)
+                            egui::RichText::new(truncate_middle(&commit.author.name, self.max_author_length))
                                 .color(if is_selected { text_color } else { ui.visuals().weak_text_color() })
                                 .size(11.0)
                         )
-                    );
+                        .wrap(false)
+                    )
+                    .on_hover_text(format!("{} <{}>", commit.author.name, commit.author.email));
+                    
+                    if let Some(stats) = self.commit_stats.get(&commit.id) {
+                        ui.add_space(8.0);
+                        ui.add(
+                            egui::Label::new(
+                                egui::RichText::new(format!("+{}", stats.insertions))
+                                    .color(egui::Color32::from_rgb(90, 180, 90))
+                                    .size(11.0)
+                                    .monospace()
+                            )
+                            .wrap(false)
+                        )
+                        .on_hover_text(stats.summary());
+                        ui.add(
+                            egui::Label::new(
+                                egui::RichText::new(format!("−{}", stats.deletions))
+                                    .color(egui::Color32::from_rgb(210, 90, 90))
+                                    .size(11.0)
+                                    .monospace()
+                            )
+                            .wrap(false)
+                        )
+                        .on_hover_text(stats.summary());
+                    }
                     
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                         ui.add(
@@ -206,13 +584,118 @@ impl CommitListComponent {
                         }
                     });
                 });
+                
+                if is_expanded {
+                    ui.add_space(6.0);
+                    ui.add(
+                        egui::Label::new(
+                            egui::RichText::new(body)
+                                .color(text_color)
+                                .size(12.0)
+                        )
+                        .wrap(true)
+                    );
+                }
             });
         }).response;
 
-        response.interact(egui::Sense::click())
+        (response.interact(egui::Sense::click()), chip_rects, toggle_rect)
+    }
+
+    fn commit_body(commit: &Commit) -> &str {
+        commit.message
+            .split_once('\n')
+            .map(|(_, rest)| rest.trim())
+            .unwrap_or("")
+    }
+
+    fn row_height(&self, commit_id: &CommitId) -> f32 {
+        if self.expanded.contains(commit_id) {
+            self.expanded_heights
+                .get(commit_id)
+                .copied()
+                .unwrap_or(self.item_height * 2.0)
+        } else {
+            self.item_height
+        }
+    }
+
+    pub fn toggle_expanded(&mut self, commit_id: CommitId) {
+        if self.expanded.remove(&commit_id) {
+            self.expanded_heights.remove(&commit_id);
+            return;
+        }
+
+        if !self.allow_multiple_expanded {
+            self.expanded.clear();
+            self.expanded_heights.clear();
+        }
+        self.expanded.insert(commit_id);
+    }
+
+    pub fn collapse_all(&mut self) {
+        self.expanded.clear();
+        self.expanded_heights.clear();
+    }
+
+    pub fn set_allow_multiple_expanded(&mut self, allow: bool) {
+        if !allow && self.expanded.len() > 1 {
+            self.collapse_all();
+        }
+        self.allow_multiple_expanded = allow;
+    }
+
+    fn render_ref_chip(ui: &mut egui::Ui, label: &RefLabel, tag_message: Option<&String>) -> egui::Response {
+        let (fill, text_color) = match label.kind {
+            RefKind::Head => (egui::Color32::from_rgb(220, 160, 40), egui::Color32::BLACK),
+            RefKind::LocalBranch => (egui::Color32::from_rgb(60, 130, 80), egui::Color32::WHITE),
+            RefKind::RemoteBranch => (egui::Color32::from_rgb(70, 100, 160), egui::Color32::WHITE),
+            RefKind::Tag => (egui::Color32::from_rgb(130, 90, 150), egui::Color32::WHITE),
+            RefKind::Stash => (egui::Color32::from_rgb(120, 120, 120), egui::Color32::WHITE),
+        };
+
+        let mut text = egui::RichText::new(&label.name)
+            .color(text_color)
+            .size(11.0);
+        if label.kind == RefKind::Head {
+            text = text.strong();
+        }
+
+        let hover_text = match label.kind {
+            RefKind::LocalBranch => format!("Branch {}\nClick to check it out", label.name),
+            RefKind::RemoteBranch => format!("Remote branch {}", label.name),
+            RefKind::Tag => match tag_message {
+                Some(message) => format!("Tag {}\n\n{}", label.name, message),
+                None => format!("Tag {}", label.name),
+            },
+            RefKind::Head => "Current HEAD".to_string(),
+            RefKind::Stash => format!("Stashed changes {}", label.name),
+        };
+
+        egui::Frame::none()
+            .fill(fill)
+            .rounding(egui::Rounding::same(6.0))
+            .inner_margin(egui::Margin::symmetric(5.0, 1.0))
+            .show(ui, |ui| {
+                ui.add(egui::Label::new(text).wrap(false));
+            })
+            .response
+            .on_hover_text(hover_text)
     }
 
     pub fn handle_keyboard(&mut self, ctx: &egui::Context, commits: &[Commit]) -> bool {
+        if self.is_filtering() {
+            let filtered: Vec<Commit> = self.matching_rows(commits)
+                .into_iter()
+                .map(|index| commits[index].clone())
+                .collect();
+            return self.handle_keyboard_in(ctx, &filtered);
+        }
+
+        self.handle_keyboard_in(ctx, commits)
+    }
+
+    fn handle_keyboard_in(&mut self, ctx: &egui::Context, commits: &[Commit]) -> bool {
         if commits.is_empty() {
             return false;
         }
@@ -251,6 +734,10 @@ impl CommitListComponent {
             }
         });
 
+        if selection_changed {
+            self.pending_reveal = self.selected_commit;
+        }
+
         selection_changed
     }
 
@@ -322,14 +809,91 @@ impl CommitListComponent {
         self.selected_commit = commit_id;
     }
 
+    pub fn scroll_to_commit(&mut self, commit_id: CommitId) {
+        self.selected_commit = Some(commit_id);
+        self.pending_scroll = Some(commit_id);
+    }
+
+    /// The `(older, newer)` pair marked by shift-clicking, if any.
+    pub fn compare_range(&self) -> Option<(CommitId, CommitId)> {
+        self.compare_range
+    }
+
+    pub fn clear_compare_range(&mut self) {
+        self.compare_range = None;
+    }
+
     pub fn clear_selection(&mut self) {
         self.selected_commit = None;
+        self.compare_range = None;
         self.hover_commit = None;
     }
 
+    pub fn row_centers(&self) -> &[f32] {
+        &self.row_centers
+    }
+
+    pub fn scroll_offset(&self) -> f32 {
+        self.scroll_offset
+    }
+
     pub fn get_visible_range(&self) -> (usize, usize) {
         self.visible_range
     }
+
+    pub fn set_max_author_length(&mut self, max_author_length: usize) {
+        self.max_author_length = max_author_length;
+    }
+
+    pub fn set_open_on_web_enabled(&mut self, enabled: bool) {
+        self.open_on_web_enabled = enabled;
+    }
+
+    pub fn has_commit_stats(&self, commit_id: &CommitId) -> bool {
+        self.commit_stats.contains_key(commit_id)
+    }
+
+    pub fn insert_commit_stats(&mut self, commit_id: CommitId, stats: DiffStats) {
+        self.commit_stats.insert(commit_id, stats);
+    }
+
+    pub fn clear_commit_stats(&mut self) {
+        self.commit_stats.clear();
+    }
+
+    /// Whether more history can be paged in once the list is scrolled to
+    /// its end.
+    pub fn set_has_more_commits(&mut self, has_more: bool) {
+        self.has_more_commits = has_more;
+    }
+
+    pub fn is_loading_more(&self) -> bool {
+        self.loading_more
+    }
+
+    pub fn set_loading_more(&mut self, loading: bool) {
+        self.loading_more = loading;
+    }
+
+    pub fn has_signature_status(&self, commit_id: &CommitId) -> bool {
+        self.signature_statuses.contains_key(commit_id)
+    }
+
+    pub fn insert_signature_status(&mut self, commit_id: CommitId, status: SignatureStatus) {
+        self.signature_statuses.insert(commit_id, status);
+    }
+
+    pub fn clear_signature_statuses(&mut self) {
+        self.signature_statuses.clear();
+    }
+
+    pub fn set_tag_messages(&mut self, tag_messages: HashMap<String, String>) {
+        self.tag_messages = tag_messages;
+    }
+
+    pub fn set_ref_labels(&mut self, ref_labels: HashMap<CommitId, Vec<RefLabel>>) {
+        self.ref_labels = ref_labels;
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -337,6 +901,41 @@ pub struct CommitListResponse {
     pub selected: Option<CommitId>,
     pub clicked: Option<CommitId>,
     pub double_clicked: Option<CommitId>,
+    pub ref_clicked: Option<(CommitId, RefLabel)>,
+    pub open_on_web: Option<CommitId>,
+    pub copied: Option<(CommitId, CopyTarget)>,
+    /// A second commit was shift-clicked; `(older, newer)`.
+    pub compared: Option<(CommitId, CommitId)>,
+    /// The list was scrolled near its end and more commits are available.
+    pub load_more: bool,
+}
+
+/// What the commit context menu can put on the clipboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyTarget {
+    FullHash,
+    ShortHash,
+    Summary,
+}
+
+impl CopyTarget {
+    pub const ALL: [CopyTarget; 3] = [CopyTarget::FullHash, CopyTarget::ShortHash, CopyTarget::Summary];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CopyTarget::FullHash => "Copy full hash",
+            CopyTarget::ShortHash => "Copy short hash",
+            CopyTarget::Summary => "Copy summary",
+        }
+    }
+
+    pub fn text(&self, commit: &Commit) -> String {
+        match self {
+            CopyTarget::FullHash => commit.id.as_str(),
+            CopyTarget::ShortHash => commit.id.short(),
+            CopyTarget::Summary => commit.summary.clone(),
+        }
+    }
 }
 
 impl CommitListResponse {
@@ -351,4 +950,20 @@ impl CommitListResponse {
     pub fn was_double_clicked(&self) -> bool {
         self.double_clicked.is_some()
     }
+}
+
+/// Scroll offset that brings the row spanning `row_top..row_top + row_height`
+/// fully into a viewport of `viewport_height` currently scrolled to
+/// `current`, moving as little as possible. Stays within the scrollable range
+/// of `content_height`.
+pub fn scroll_offset_to_reveal(current: f32, viewport_height: f32, row_top: f32, row_height: f32, content_height: f32) -> f32 {
+    let max_offset = (content_height - viewport_height).max(0.0);
+    let offset = if row_top < current {
+        row_top
+    } else if row_top + row_height > current + viewport_height {
+        row_top + row_height - viewport_height
+    } else {
+        current
+    };
+    offset.clamp(0.0, max_offset)
 }
\ No newline at end of file
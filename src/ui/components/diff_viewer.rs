@@ -0,0 +1,345 @@
+use crate::git::types::{DiffInfo, DiffLineType, RenameKind};
+use crate::git::word_diff::changed_words;
+use crate::ui::highlight::{paint_line, syntax_for_path, HighlightCache};
+use eframe::egui;
+use syntect::parsing::SyntaxReference;
+
+const ADDITION_BG: egui::Color32 = egui::Color32::from_rgba_premultiplied(40, 90, 40, 90);
+const DELETION_BG: egui::Color32 = egui::Color32::from_rgba_premultiplied(100, 35, 35, 90);
+const HEADER_BG: egui::Color32 = egui::Color32::from_rgba_premultiplied(60, 60, 80, 90);
+const ADDITION_FG: egui::Color32 = egui::Color32::from_rgb(120, 200, 120);
+const DELETION_FG: egui::Color32 = egui::Color32::from_rgb(230, 110, 110);
+const ADDITION_WORD_BG: egui::Color32 = egui::Color32::from_rgba_premultiplied(60, 150, 60, 160);
+const DELETION_WORD_BG: egui::Color32 = egui::Color32::from_rgba_premultiplied(170, 50, 50, 160);
+const RENAME_FG: egui::Color32 = egui::Color32::from_rgb(130, 170, 240);
+
+enum DiffRow<'a> {
+    File(&'a DiffInfo),
+    Hunk(String),
+    Note(&'static str),
+    Line {
+        line_type: &'a DiffLineType,
+        content: &'a str,
+        old_line: Option<u32>,
+        new_line: Option<u32>,
+        /// Row of the line this one replaced or was replaced by, used for
+        /// word-level highlighting.
+        partner: Option<usize>,
+        syntax: Option<&'static SyntaxReference>,
+    },
+}
+
+pub struct DiffViewer {
+    pub show_line_numbers: bool,
+    pub syntax_highlighting: bool,
+    /// Mirrors `GitRepository::ignore_whitespace`; files left without hunks
+    /// get a "whitespace-only changes" note.
+    pub ignore_whitespace: bool,
+    /// Highlights the changed words within paired deleted/added lines.
+    pub word_diff: bool,
+    pending_file: Option<String>,
+    highlight_cache: HighlightCache,
+}
+
+impl Default for DiffViewer {
+    fn default() -> Self {
+        Self {
+            show_line_numbers: true,
+            syntax_highlighting: true,
+            ignore_whitespace: false,
+            word_diff: true,
+            pending_file: None,
+            highlight_cache: HighlightCache::default(),
+        }
+    }
+}
+
+impl DiffViewer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn scroll_to_file(&mut self, path: &str) {
+        self.pending_file = Some(path.to_string());
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui, files: &[DiffInfo]) {
+        if files.is_empty() {
+            ui.centered_and_justified(|ui| {
+                ui.label("No changes in this commit");
+            });
+            return;
+        }
+
+        let rows = Self::flatten(files, self.ignore_whitespace);
+        let font_id = egui::TextStyle::Monospace.resolve(ui.style());
+        let row_height = ui.fonts(|fonts| fonts.row_height(&font_id)) + 2.0;
+        let char_width = ui.fonts(|fonts| fonts.glyph_width(&font_id, 'M'));
+        let gutter_width = if self.show_line_numbers { char_width * 12.0 } else { 0.0 };
+        let longest = rows
+            .iter()
+            .map(|row| match row {
+                DiffRow::File(file) => Self::file_title(file).chars().count(),
+                DiffRow::Hunk(header) => header.chars().count(),
+                DiffRow::Note(note) => note.chars().count(),
+                DiffRow::Line { content, .. } => content.chars().count() + 2,
+            })
+            .max()
+            .unwrap_or(0);
+        let content_width = gutter_width + char_width * longest as f32 + 8.0;
+        let text_color = ui.visuals().text_color();
+        let weak_color = ui.visuals().weak_text_color();
+        let dark_mode = ui.visuals().dark_mode;
+
+        let mut scroll_area = egui::ScrollArea::both()
+            .id_source("diff_viewer_scroll")
+            .auto_shrink([false, false]);
+
+        if let Some(path) = self.pending_file.take() {
+            let target = rows.iter().position(|row| match row {
+                DiffRow::File(file) => file.new_file.as_deref() == Some(path.as_str())
+                    || file.old_file.as_deref() == Some(path.as_str()),
+                _ => false,
+            });
+            if let Some(index) = target {
+                let spacing = ui.spacing().item_spacing.y;
+                scroll_area = scroll_area.vertical_scroll_offset(index as f32 * (row_height + spacing));
+            }
+        }
+
+        scroll_area
+            .show_rows(ui, row_height, rows.len(), |ui, range| {
+                let width = content_width.max(ui.available_width());
+                for row in &rows[range] {
+                    let (rect, _) = ui.allocate_exact_size(egui::vec2(width, row_height), egui::Sense::hover());
+                    let painter = ui.painter_at(rect);
+                    let text_pos = |x: f32| egui::pos2(rect.left() + x, rect.center().y);
+
+                    match row {
+                        DiffRow::File(file) => {
+                            painter.rect_filled(rect, 0.0, HEADER_BG);
+                            let title_color = if file.rename.is_some() { RENAME_FG } else { text_color };
+                            painter.text(
+                                text_pos(4.0),
+                                egui::Align2::LEFT_CENTER,
+                                Self::file_title(file),
+                                font_id.clone(),
+                                title_color,
+                            );
+                        }
+                        DiffRow::Note(note) => {
+                            painter.text(
+                                text_pos(gutter_width + 4.0),
+                                egui::Align2::LEFT_CENTER,
+                                *note,
+                                egui::FontId::proportional(font_id.size),
+                                weak_color,
+                            );
+                        }
+                        DiffRow::Hunk(header) => {
+                            painter.text(
+                                text_pos(gutter_width + 4.0),
+                                egui::Align2::LEFT_CENTER,
+                                header,
+                                font_id.clone(),
+                                weak_color,
+                            );
+                        }
+                        DiffRow::Line { line_type, content, old_line, new_line, partner, syntax } => {
+                            let (background, marker, marker_color) = match line_type {
+                                DiffLineType::Addition => (Some(ADDITION_BG), "+", ADDITION_FG),
+                                DiffLineType::Deletion => (Some(DELETION_BG), "-", DELETION_FG),
+                                DiffLineType::Context => (None, " ", weak_color),
+                            };
+
+                            if let Some(background) = background {
+                                painter.rect_filled(rect, 0.0, background);
+                            }
+
+                            let partner_content = match partner.map(|index| &rows[index]) {
+                                Some(DiffRow::Line { content, .. }) if self.word_diff => Some(*content),
+                                _ => None,
+                            };
+                            if let Some(partner_content) = partner_content {
+                                let (ranges, word_background) = match line_type {
+                                    DiffLineType::Deletion => (changed_words(content, partner_content).0, DELETION_WORD_BG),
+                                    _ => (changed_words(partner_content, content).1, ADDITION_WORD_BG),
+                                };
+                                let text_left = rect.left() + gutter_width + 4.0 + char_width * 2.0;
+                                for range in ranges {
+                                    let start = content[..range.start].chars().count() as f32;
+                                    let end = content[..range.end].chars().count() as f32;
+                                    let word_rect = egui::Rect::from_x_y_ranges(
+                                        text_left + char_width * start..=text_left + char_width * end,
+                                        rect.y_range(),
+                                    );
+                                    painter.rect_filled(word_rect, 2.0, word_background);
+                                }
+                            }
+
+                            if self.show_line_numbers {
+                                let format_number = |number: &Option<u32>| {
+                                    number.map(|n| n.to_string()).unwrap_or_default()
+                                };
+                                painter.text(
+                                    text_pos(char_width * 5.0),
+                                    egui::Align2::RIGHT_CENTER,
+                                    format_number(old_line),
+                                    font_id.clone(),
+                                    weak_color,
+                                );
+                                painter.text(
+                                    text_pos(char_width * 11.0),
+                                    egui::Align2::RIGHT_CENTER,
+                                    format_number(new_line),
+                                    font_id.clone(),
+                                    weak_color,
+                                );
+                            }
+
+                            let marker_color = if self.syntax_highlighting { marker_color } else { text_color };
+                            painter.text(
+                                text_pos(gutter_width + 4.0),
+                                egui::Align2::LEFT_CENTER,
+                                marker,
+                                font_id.clone(),
+                                marker_color,
+                            );
+                            let content_pos = text_pos(gutter_width + 4.0 + char_width * 2.0);
+                            let spans = match syntax {
+                                Some(syntax) if self.syntax_highlighting => {
+                                    Some(self.highlight_cache.get(syntax, content, dark_mode))
+                                }
+                                _ => None,
+                            };
+                            match spans {
+                                Some(spans) if !spans.is_empty() => {
+                                    paint_line(&painter, content_pos, content, spans, &font_id, char_width);
+                                }
+                                _ => {
+                                    painter.text(
+                                        content_pos,
+                                        egui::Align2::LEFT_CENTER,
+                                        *content,
+                                        font_id.clone(),
+                                        text_color,
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+            });
+    }
+
+    fn file_title(file: &DiffInfo) -> String {
+        if let Some(ref rename) = file.rename {
+            let verb = match rename.kind {
+                RenameKind::Renamed => "renamed",
+                RenameKind::Copied => "copied",
+            };
+            return format!("{} {} → {}", verb, rename.from, rename.to);
+        }
+
+        match (file.old_file.as_deref(), file.new_file.as_deref()) {
+            (Some(old), Some(new)) if old != new => format!("{} → {}", old, new),
+            (_, Some(new)) => new.to_string(),
+            (Some(old), None) => old.to_string(),
+            (None, None) => "(unknown file)".to_string(),
+        }
+    }
+
+    fn flatten(files: &[DiffInfo], ignore_whitespace: bool) -> Vec<DiffRow<'_>> {
+        let mut rows = Vec::new();
+
+        for file in files {
+            rows.push(DiffRow::File(file));
+            let syntax = file.new_file.as_deref()
+                .or(file.old_file.as_deref())
+                .and_then(syntax_for_path);
+
+            if ignore_whitespace && file.hunks.is_empty() && file.rename.is_none() {
+                rows.push(DiffRow::Note("whitespace-only changes"));
+            }
+
+            for hunk in &file.hunks {
+                let hunk_start = rows.len();
+                rows.push(DiffRow::Hunk(format!(
+                    "@@ -{},{} +{},{} @@",
+                    hunk.old_start, hunk.old_lines, hunk.new_start, hunk.new_lines
+                )));
+
+                let mut old_line = hunk.old_start;
+                let mut new_line = hunk.new_start;
+                for line in &hunk.lines {
+                    let (old, new) = match line.line_type {
+                        DiffLineType::Context => {
+                            old_line += 1;
+                            new_line += 1;
+                            (Some(old_line - 1), Some(new_line - 1))
+                        }
+                        DiffLineType::Addition => {
+                            new_line += 1;
+                            (None, Some(new_line - 1))
+                        }
+                        DiffLineType::Deletion => {
+                            old_line += 1;
+                            (Some(old_line - 1), None)
+                        }
+                    };
+
+                    rows.push(DiffRow::Line {
+                        line_type: &line.line_type,
+                        content: &line.content,
+                        old_line: old,
+                        new_line: new,
+                        partner: None,
+                        syntax,
+                    });
+                }
+
+                Self::pair_changed_lines(&mut rows[hunk_start..], hunk_start);
+            }
+        }
+
+        rows
+    }
+    /// Pairs each run of deletions with the additions directly after it,
+    /// line by line. `offset` is the index of `rows[0]` in the full list.
+    fn pair_changed_lines(rows: &mut [DiffRow<'_>], offset: usize) {
+        let is_type = |row: &DiffRow<'_>, wanted: fn(&DiffLineType) -> bool| {
+            matches!(row, DiffRow::Line { line_type, .. } if wanted(line_type))
+        };
+        let is_deletion = |line_type: &DiffLineType| matches!(line_type, DiffLineType::Deletion);
+        let is_addition = |line_type: &DiffLineType| matches!(line_type, DiffLineType::Addition);
+
+        let mut index = 0;
+        while index < rows.len() {
+            if !is_type(&rows[index], is_deletion) {
+                index += 1;
+                continue;
+            }
+
+            let deletions_start = index;
+            while index < rows.len() && is_type(&rows[index], is_deletion) {
+                index += 1;
+            }
+            let additions_start = index;
+            while index < rows.len() && is_type(&rows[index], is_addition) {
+                index += 1;
+            }
+
+            let pairs = (additions_start - deletions_start).min(index - additions_start);
+            for pair in 0..pairs {
+                let deletion = deletions_start + pair;
+                let addition = additions_start + pair;
+                if let DiffRow::Line { partner, .. } = &mut rows[deletion] {
+                    *partner = Some(offset + addition);
+                }
+                if let DiffRow::Line { partner, .. } = &mut rows[addition] {
+                    *partner = Some(offset + deletion);
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,97 @@
+use crate::git::types::TreeEntry;
+use eframe::egui;
+
+#[derive(Default)]
+pub struct FileTree {
+    pub expanded_folders: Vec<String>,
+    pub selected_file: Option<String>,
+}
+
+#[derive(Default)]
+pub struct FileTreeResponse {
+    pub clicked: Option<String>,
+    pub blame: Option<String>,
+    pub history: Option<String>,
+    pub opened: Option<String>,
+}
+
+impl FileTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renders the tree and returns the file the user clicked, double-clicked,
+    /// or asked to blame or see the history of, this frame.
+    pub fn render(&mut self, ui: &mut egui::Ui, entries: &[TreeEntry]) -> FileTreeResponse {
+        let mut response = FileTreeResponse::default();
+        if entries.is_empty() {
+            ui.label("No files in this commit");
+            return response;
+        }
+
+        egui::ScrollArea::vertical()
+            .id_source("file_tree_scroll")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                self.render_entries(ui, entries, 0, &mut response);
+            });
+
+        if let Some(ref path) = response.clicked {
+            tracing::debug!("File selected in tree: {}", path);
+            self.selected_file = Some(path.clone());
+        }
+
+        response
+    }
+
+    fn render_entries(&mut self, ui: &mut egui::Ui, entries: &[TreeEntry], depth: usize, response: &mut FileTreeResponse) {
+        for entry in entries {
+            ui.horizontal(|ui| {
+                ui.add_space(depth as f32 * 14.0);
+
+                if entry.is_dir {
+                    let expanded = self.is_expanded(&entry.path);
+                    let icon = if expanded { "▼" } else { "▶" };
+                    if ui.selectable_label(false, format!("{} 📁 {}", icon, entry.name)).clicked() {
+                        self.toggle_folder(&entry.path);
+                    }
+                } else {
+                    let is_selected = self.selected_file.as_deref() == Some(entry.path.as_str());
+                    let label = ui.selectable_label(is_selected, format!("📄 {}", entry.name));
+                    if label.clicked() {
+                        response.clicked = Some(entry.path.clone());
+                    }
+                    if label.double_clicked() {
+                        response.opened = Some(entry.path.clone());
+                    }
+                    label.context_menu(|ui| {
+                        if ui.button("Blame").clicked() {
+                            response.blame = Some(entry.path.clone());
+                            ui.close_menu();
+                        }
+                        if ui.button("Show history").clicked() {
+                            response.history = Some(entry.path.clone());
+                            ui.close_menu();
+                        }
+                    });
+                }
+            });
+
+            if entry.is_dir && self.is_expanded(&entry.path) {
+                self.render_entries(ui, &entry.children, depth + 1, response);
+            }
+        }
+    }
+
+    pub fn is_expanded(&self, path: &str) -> bool {
+        self.expanded_folders.iter().any(|folder| folder == path)
+    }
+
+    pub fn toggle_folder(&mut self, path: &str) {
+        if let Some(index) = self.expanded_folders.iter().position(|folder| folder == path) {
+            self.expanded_folders.remove(index);
+        } else {
+            self.expanded_folders.push(path.to_string());
+        }
+    }
+}
\ No newline at end of file
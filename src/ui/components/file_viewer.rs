@@ -0,0 +1,146 @@
+use crate::git::types::CommitId;
+use crate::ui::highlight::{paint_line, syntax_for_path, HighlightCache};
+use eframe::egui;
+use syntect::parsing::SyntaxReference;
+
+enum FileContent {
+    Text(Vec<String>),
+    Binary(usize),
+}
+
+/// Read-only window showing a file as it was at one commit.
+pub struct FileViewer {
+    pub open: bool,
+    pub syntax_highlighting: bool,
+    path: String,
+    commit: Option<CommitId>,
+    content: FileContent,
+    syntax: Option<&'static SyntaxReference>,
+    highlight_cache: HighlightCache,
+}
+
+impl Default for FileViewer {
+    fn default() -> Self {
+        Self {
+            open: false,
+            syntax_highlighting: true,
+            path: String::new(),
+            commit: None,
+            content: FileContent::Text(Vec::new()),
+            syntax: None,
+            highlight_cache: HighlightCache::default(),
+        }
+    }
+}
+
+impl FileViewer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn show(&mut self, path: String, commit: CommitId, bytes: Vec<u8>, is_binary: bool) {
+        tracing::debug!("Showing {} at {} ({} bytes)", path, commit.short(), bytes.len());
+        self.content = if is_binary {
+            FileContent::Binary(bytes.len())
+        } else {
+            FileContent::Text(String::from_utf8_lossy(&bytes).lines().map(str::to_string).collect())
+        };
+        self.syntax = syntax_for_path(&path);
+        self.path = path;
+        self.commit = Some(commit);
+        self.open = true;
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+        self.path.clear();
+        self.commit = None;
+        self.content = FileContent::Text(Vec::new());
+        self.syntax = None;
+        self.highlight_cache = HighlightCache::default();
+    }
+
+    pub fn render(&mut self, ctx: &egui::Context) {
+        if !self.open {
+            return;
+        }
+
+        let title = match self.commit {
+            Some(commit) => format!("{} @ {}", self.path, commit.short()),
+            None => self.path.clone(),
+        };
+
+        let mut open = self.open;
+        egui::Window::new(title)
+            .id(egui::Id::new("file_viewer"))
+            .open(&mut open)
+            .default_size([800.0, 500.0])
+            .resizable(true)
+            .show(ctx, |ui| match self.content {
+                FileContent::Binary(size) => {
+                    ui.label(format!("Binary file ({} bytes)", size));
+                }
+                FileContent::Text(ref lines) => {
+                    let syntax = self.syntax.filter(|_| self.syntax_highlighting);
+                    Self::render_lines(ui, lines, syntax, &mut self.highlight_cache);
+                }
+            });
+
+        if !open {
+            self.close();
+        }
+    }
+
+    fn render_lines(ui: &mut egui::Ui, lines: &[String], syntax: Option<&SyntaxReference>, highlight_cache: &mut HighlightCache) {
+        if lines.is_empty() {
+            ui.label("File is empty");
+            return;
+        }
+
+        let font_id = egui::TextStyle::Monospace.resolve(ui.style());
+        let row_height = ui.fonts(|fonts| fonts.row_height(&font_id)) + 2.0;
+        let char_width = ui.fonts(|fonts| fonts.glyph_width(&font_id, 'M'));
+        let gutter_width = char_width * (lines.len().to_string().len() as f32 + 2.0);
+        let text_color = ui.visuals().text_color();
+        let weak_color = ui.visuals().weak_text_color();
+        let dark_mode = ui.visuals().dark_mode;
+
+        let longest = lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+        let content_width = gutter_width + char_width * longest as f32 + 8.0;
+
+        egui::ScrollArea::both()
+            .id_source("file_viewer_scroll")
+            .auto_shrink([false, false])
+            .show_rows(ui, row_height, lines.len(), |ui, range| {
+                let width = content_width.max(ui.available_width());
+                for index in range {
+                    let (rect, _) = ui.allocate_exact_size(egui::vec2(width, row_height), egui::Sense::hover());
+                    let painter = ui.painter_at(rect);
+
+                    painter.text(
+                        egui::pos2(rect.left() + gutter_width - char_width, rect.center().y),
+                        egui::Align2::RIGHT_CENTER,
+                        (index + 1).to_string(),
+                        font_id.clone(),
+                        weak_color,
+                    );
+                    let line_pos = egui::pos2(rect.left() + gutter_width + 4.0, rect.center().y);
+                    let spans = syntax.map(|syntax| highlight_cache.get(syntax, &lines[index], dark_mode));
+                    match spans {
+                        Some(spans) if !spans.is_empty() => {
+                            paint_line(&painter, line_pos, &lines[index], spans, &font_id, char_width);
+                        }
+                        _ => {
+                            painter.text(
+                                line_pos,
+                                egui::Align2::LEFT_CENTER,
+                                &lines[index],
+                                font_id.clone(),
+                                text_color,
+                            );
+                        }
+                    }
+                }
+            });
+    }
+}
\ No newline at end of file
@@ -1,7 +1,10 @@
 use eframe::egui;
 use std::fs;
+use std::io::{Read, Seek, SeekFrom};
 use std::path::PathBuf;
 use chrono::{DateTime, Local};
+use regex::Regex;
+use serde::Deserialize;
 use crate::error::{Result, TwiggyError};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -45,8 +48,45 @@ pub struct LogEntry {
     pub raw_line: String,
 }
 
+/// A line of the file log, as written by the `.json()` layer in `logging.rs`.
+#[derive(Deserialize)]
+struct JsonLogLine {
+    timestamp: String,
+    level: String,
+    #[serde(default)]
+    target: String,
+    #[serde(default)]
+    fields: JsonLogFields,
+}
+
+#[derive(Deserialize, Default)]
+struct JsonLogFields {
+    #[serde(default)]
+    message: String,
+}
+
 impl LogEntry {
-    fn parse(line: &str) -> Option<Self> {
+    /// Parses a JSON log line, falling back to the plaintext format.
+    pub fn parse(line: &str) -> Option<Self> {
+        Self::parse_json(line).or_else(|| Self::parse_plain(line))
+    }
+
+    fn parse_json(line: &str) -> Option<Self> {
+        let json: JsonLogLine = serde_json::from_str(line).ok()?;
+        let timestamp = DateTime::parse_from_rfc3339(&json.timestamp)
+            .ok()?
+            .with_timezone(&Local);
+
+        Some(LogEntry {
+            timestamp,
+            level: LogLevel::from_str(&json.level)?,
+            target: json.target,
+            message: json.fields.message,
+            raw_line: line.to_string(),
+        })
+    }
+
+    fn parse_plain(line: &str) -> Option<Self> {
         let parts: Vec<&str> = line.splitn(5, ' ').collect();
         if parts.len() < 5 {
             return None;
@@ -74,6 +114,7 @@ impl LogEntry {
 #[derive(Default)]
 pub struct LogViewerState {
     pub search_text: String,
+    pub use_regex: bool,
     pub selected_levels: Vec<LogLevel>,
     pub auto_scroll: bool,
     pub show_timestamps: bool,
@@ -85,7 +126,10 @@ pub struct LogViewer {
     filtered_entries: Vec<usize>,
     state: LogViewerState,
     log_file_path: Option<PathBuf>,
-    last_modified: Option<std::time::SystemTime>,
+    /// Bytes of the log file consumed so far; only complete lines count.
+    read_offset: u64,
+    search_regex: Option<Regex>,
+    regex_error: Option<String>,
 }
 
 impl Default for LogViewer {
@@ -101,7 +145,9 @@ impl Default for LogViewer {
                 ..Default::default()
             },
             log_file_path: None,
-            last_modified: None,
+            read_offset: 0,
+            search_regex: None,
+            regex_error: None,
         }
     }
 }
@@ -113,64 +159,129 @@ impl LogViewer {
 
     pub fn set_log_file(&mut self, path: PathBuf) -> Result<()> {
         self.log_file_path = Some(path);
+        self.entries.clear();
+        self.filtered_entries.clear();
+        self.read_offset = 0;
         self.reload_logs()
     }
 
+    /// Reads the lines appended to the log file since the last call. A file
+    /// smaller than what was already read has been rotated or truncated, so
+    /// it is read again from the start.
     pub fn reload_logs(&mut self) -> Result<()> {
-        if let Some(ref path) = self.log_file_path {
-            if path.exists() {
-                let metadata = fs::metadata(path)
-                    .map_err(|e| TwiggyError::Io { 
-                        operation: format!("Failed to read log file metadata: {}", e),
-                        source: e.into(),
-                    })?;
-
-                let modified = metadata.modified()
-                    .map_err(|e| TwiggyError::Io { 
-                        operation: format!("Failed to get file modification time: {}", e),
-                        source: e.into(),
-                    })?;
-
-                if self.last_modified.map_or(true, |last| modified > last) {
-                    let content = fs::read_to_string(path)
-                    .map_err(|e| TwiggyError::Io { 
-                        operation: format!("Failed to read log file: {}", e),
-                        source: e.into(),
-                    })?;
-
-                    self.entries.clear();
-                    for line in content.lines() {
-                        if let Some(entry) = LogEntry::parse(line) {
-                            self.entries.push(entry);
-                        }
-                    }
+        let Some(path) = self.log_file_path.clone() else {
+            return Ok(());
+        };
+
+        if !path.exists() {
+            return Ok(());
+        }
+
+        let metadata = fs::metadata(&path)
+            .map_err(|e| TwiggyError::Io { 
+                operation: format!("Failed to read log file metadata: {}", e),
+                source: e,
+            })?;
+
+        let len = metadata.len();
+        if len < self.read_offset {
+            tracing::debug!("Log file shrank, reloading from the start");
+            self.entries.clear();
+            self.read_offset = 0;
+            self.apply_filters();
+        }
+
+        if len == self.read_offset {
+            return Ok(());
+        }
 
-                    self.last_modified = Some(modified);
-                    self.apply_filters();
+        let mut appended = Vec::new();
+        fs::File::open(&path)
+            .and_then(|mut file| {
+                file.seek(SeekFrom::Start(self.read_offset))?;
+                file.read_to_end(&mut appended)
+            })
+            .map_err(|e| TwiggyError::Io { 
+                operation: format!("Failed to read log file: {}", e),
+                source: e,
+            })?;
+
+        // A line still being written has no newline yet; leave it for the
+        // next read.
+        let Some(complete) = appended.iter().rposition(|&byte| byte == b'\n').map(|i| i + 1) else {
+            return Ok(());
+        };
+        self.read_offset += complete as u64;
+
+        for line in String::from_utf8_lossy(&appended[..complete]).lines() {
+            if let Some(entry) = LogEntry::parse(line) {
+                if self.entry_matches(&entry) {
+                    self.filtered_entries.push(self.entries.len());
                 }
+                self.entries.push(entry);
             }
         }
+
         Ok(())
     }
 
+    pub fn set_search(&mut self, text: &str, use_regex: bool) {
+        self.state.search_text = text.to_string();
+        self.state.use_regex = use_regex;
+        self.search_changed();
+    }
+
+    /// The compile error of the current pattern, if regex search is on.
+    pub fn regex_error(&self) -> Option<&str> {
+        self.regex_error.as_deref()
+    }
+
+    pub fn visible_entries(&self) -> impl Iterator<Item = &LogEntry> {
+        self.filtered_entries.iter().filter_map(|&index| self.entries.get(index))
+    }
+
+    fn search_changed(&mut self) {
+        self.search_regex = None;
+        self.regex_error = None;
+
+        if self.state.use_regex && !self.state.search_text.is_empty() {
+            match Regex::new(&self.state.search_text) {
+                Ok(regex) => self.search_regex = Some(regex),
+                Err(e) => self.regex_error = Some(e.to_string()),
+            }
+        }
+
+        self.apply_filters();
+    }
+
     fn apply_filters(&mut self) {
         self.filtered_entries.clear();
         
         for (index, entry) in self.entries.iter().enumerate() {
-            if !self.state.selected_levels.contains(&entry.level) {
-                continue;
+            if self.entry_matches(entry) {
+                self.filtered_entries.push(index);
             }
+        }
+    }
 
-            if !self.state.search_text.is_empty() {
-                let search_lower = self.state.search_text.to_lowercase();
-                if !entry.message.to_lowercase().contains(&search_lower) &&
-                   !entry.target.to_lowercase().contains(&search_lower) {
-                    continue;
-                }
-            }
+    fn entry_matches(&self, entry: &LogEntry) -> bool {
+        if !self.state.selected_levels.contains(&entry.level) {
+            return false;
+        }
 
-            self.filtered_entries.push(index);
+        if self.state.use_regex {
+            // An invalid pattern leaves the list unfiltered; the error is
+            // shown next to the search box instead.
+            if let Some(ref regex) = self.search_regex {
+                return regex.is_match(&entry.message) || regex.is_match(&entry.target);
+            }
+        } else if !self.state.search_text.is_empty() {
+            let search_lower = self.state.search_text.to_lowercase();
+            return entry.message.to_lowercase().contains(&search_lower) ||
+                entry.target.to_lowercase().contains(&search_lower);
         }
+
+        true
     }
 
     pub fn render(&mut self, ui: &mut egui::Ui) -> Result<()> {
@@ -179,8 +290,14 @@ impl LogViewer {
         ui.horizontal(|ui| {
             ui.label("Search:");
             let search_response = ui.text_edit_singleline(&mut self.state.search_text);
-            if search_response.changed() {
-                self.apply_filters();
+            let regex_toggled = ui.checkbox(&mut self.state.use_regex, "Regex").changed();
+            if search_response.changed() || regex_toggled {
+                self.search_changed();
+            }
+
+            if let Some(ref error) = self.regex_error {
+                ui.colored_label(egui::Color32::RED, "Invalid pattern")
+                    .on_hover_text(error);
             }
 
             ui.separator();
@@ -0,0 +1,195 @@
+use eframe::egui;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+use crate::error::Result;
+use crate::git::{CommitId, DiffAlgorithm, GitRepository};
+
+#[derive(Default)]
+pub struct PickaxeSearch {
+    pub open: bool,
+    needle: String,
+    limit: usize,
+    results: Vec<CommitId>,
+    searched_needle: Option<String>,
+    progress: Arc<AtomicUsize>,
+    cancel: Arc<AtomicBool>,
+    receiver: Option<mpsc::Receiver<Result<Vec<CommitId>>>>,
+    error: Option<String>,
+}
+
+#[derive(Default)]
+pub struct PickaxeSearchResponse {
+    pub selected: Option<CommitId>,
+}
+
+impl PickaxeSearch {
+    pub fn new() -> Self {
+        Self {
+            limit: 10000,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.receiver.is_some()
+    }
+
+    pub fn start(&mut self, path: PathBuf, algorithm: DiffAlgorithm) {
+        let needle = self.needle.clone();
+        if needle.is_empty() {
+            return;
+        }
+
+        self.cancel_search();
+
+        let progress = Arc::new(AtomicUsize::new(0));
+        let cancel = Arc::new(AtomicBool::new(false));
+        let (sender, receiver) = mpsc::channel();
+        let limit = Some(self.limit.max(1));
+
+        let worker_progress = Arc::clone(&progress);
+        let worker_cancel = Arc::clone(&cancel);
+        std::thread::spawn(move || {
+            let result = GitRepository::open(&path).and_then(|mut repo| {
+                repo.set_diff_algorithm(algorithm);
+                repo.search_diff_content_with_progress(&needle, limit, |scanned| {
+                    worker_progress.store(scanned, Ordering::Relaxed);
+                    !worker_cancel.load(Ordering::Relaxed)
+                })
+            });
+            let _ = sender.send(result);
+        });
+
+        tracing::info!("Started diff search for '{}'", self.needle);
+        self.results.clear();
+        self.error = None;
+        self.searched_needle = Some(self.needle.clone());
+        self.progress = progress;
+        self.cancel = cancel;
+        self.receiver = Some(receiver);
+    }
+
+    pub fn cancel_search(&mut self) {
+        if self.receiver.take().is_some() {
+            self.cancel.store(true, Ordering::Relaxed);
+            tracing::info!("Cancelled diff search");
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.cancel_search();
+        self.results.clear();
+        self.searched_needle = None;
+        self.error = None;
+        self.open = false;
+    }
+
+    pub fn poll(&mut self, ctx: &egui::Context) {
+        let Some(ref receiver) = self.receiver else {
+            return;
+        };
+
+        match receiver.try_recv() {
+            Ok(Ok(results)) => {
+                self.results = results;
+                self.receiver = None;
+            }
+            Ok(Err(e)) => {
+                tracing::error!("Diff search failed: {}", e);
+                self.error = Some(e.to_string());
+                self.receiver = None;
+            }
+            Err(mpsc::TryRecvError::Empty) => {
+                ctx.request_repaint_after(Duration::from_millis(100));
+            }
+            Err(mpsc::TryRecvError::Disconnected) => {
+                self.receiver = None;
+            }
+        }
+    }
+
+    pub fn render(&mut self, ctx: &egui::Context, repository: Option<&GitRepository>) -> PickaxeSearchResponse {
+        let mut response = PickaxeSearchResponse::default();
+        if !self.open {
+            return response;
+        }
+
+        let mut open = self.open;
+        egui::Window::new("Search Diffs")
+            .open(&mut open)
+            .default_width(460.0)
+            .default_height(360.0)
+            .resizable(true)
+            .show(ctx, |ui| {
+                ui.label("Find commits that add or remove a string, like git log -S.");
+                ui.add_space(4.0);
+
+                let mut submitted = false;
+                ui.horizontal(|ui| {
+                    ui.label("Text:");
+                    let edit = ui.add(
+                        egui::TextEdit::singleline(&mut self.needle)
+                            .desired_width(220.0)
+                            .hint_text("string to look for")
+                    );
+                    submitted = edit.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+                    ui.label("Max commits:");
+                    ui.add(egui::DragValue::new(&mut self.limit).clamp_range(1..=1_000_000));
+                });
+
+                ui.horizontal(|ui| {
+                    let can_search = repository.is_some() && !self.needle.is_empty() && !self.is_running();
+                    if ui.add_enabled(can_search, egui::Button::new("🔍 Search")).clicked() || (submitted && can_search) {
+                        if let Some(repo) = repository {
+                            self.start(repo.path().to_path_buf(), repo.diff_algorithm());
+                        }
+                    }
+
+                    if self.is_running() {
+                        if ui.button("Cancel").clicked() {
+                            self.cancel_search();
+                        }
+                        ui.spinner();
+                        ui.label(format!("Scanned {} commits...", self.progress.load(Ordering::Relaxed)));
+                    }
+                });
+
+                if let Some(ref error) = self.error {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+
+                ui.separator();
+
+                if let Some(ref needle) = self.searched_needle {
+                    if !self.is_running() {
+                        ui.label(format!("{} commits change '{}'", self.results.len(), needle));
+                    }
+                }
+
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        for id in &self.results {
+                            let summary = repository
+                                .and_then(|repo| repo.get_commit_by_id(id))
+                                .map(|commit| commit.summary.clone())
+                                .unwrap_or_default();
+                            let label = format!("{}  {}", id.short(), summary);
+                            if ui.selectable_label(false, label).clicked() {
+                                response.selected = Some(*id);
+                            }
+                        }
+                    });
+            });
+
+        if !open {
+            self.cancel_search();
+        }
+        self.open = open;
+
+        response
+    }
+}
\ No newline at end of file
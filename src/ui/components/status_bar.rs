@@ -0,0 +1,200 @@
+use crate::git::repository::{BranchInfo, BranchState, GitRepository, RepositoryHealth};
+use eframe::egui;
+
+pub struct StatusBar {
+    pub current_branch: String,
+    pub uncommitted_changes: usize,
+    branch_info: Option<BranchInfo>,
+    health: Option<RepositoryHealth>,
+    has_repository: bool,
+}
+
+/// How a repository state is flagged in the status bar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthIndicator {
+    pub icon: &'static str,
+    pub label: String,
+    pub tooltip: String,
+    pub color: egui::Color32,
+}
+
+/// Indicator for `health`, or `None` when there is nothing to flag.
+pub fn health_indicator(health: &RepositoryHealth) -> Option<HealthIndicator> {
+    match health {
+        RepositoryHealth::Healthy => None,
+        RepositoryHealth::InOperation(operation) => Some(HealthIndicator {
+            icon: "⏳",
+            label: format!("{} in progress", operation),
+            tooltip: format!("A {} was started and not finished yet. {}", operation, operation_hint(operation)),
+            color: egui::Color32::from_rgb(255, 165, 0),
+        }),
+        RepositoryHealth::Corrupted => Some(HealthIndicator {
+            icon: "⚠",
+            label: "Repository corrupted".to_string(),
+            tooltip: "Git reported damaged objects. Run `git fsck` to find out what is broken.".to_string(),
+            color: egui::Color32::from_rgb(220, 50, 50),
+        }),
+        RepositoryHealth::Unknown => Some(HealthIndicator {
+            icon: "❓",
+            label: "Unknown state".to_string(),
+            tooltip: "Git reported a repository state Twiggy does not recognize.".to_string(),
+            color: egui::Color32::GRAY,
+        }),
+    }
+}
+
+fn operation_hint(operation: &str) -> &'static str {
+    match operation {
+        "merge" => "Resolve conflicts and commit, or run `git merge --abort`.",
+        "revert" => "Run `git revert --continue` or `git revert --abort`.",
+        "cherry-pick" => "Run `git cherry-pick --continue` or `git cherry-pick --abort`.",
+        "bisect" => "Mark commits with `git bisect good`/`bad`, or end with `git bisect reset`.",
+        "apply mailbox" => "Run `git am --continue` or `git am --abort`.",
+        _ => "Run `git rebase --continue` or `git rebase --abort`.",
+    }
+}
+
+impl Default for StatusBar {
+    fn default() -> Self {
+        Self {
+            current_branch: "main".to_string(),
+            uncommitted_changes: 0,
+            branch_info: None,
+            health: None,
+            has_repository: false,
+        }
+    }
+}
+
+impl StatusBar {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, repository: Option<&GitRepository>) {
+        let Some(repo) = repository else {
+            *self = Self::default();
+            return;
+        };
+
+        self.has_repository = true;
+        self.current_branch = repo.current_branch().unwrap_or("HEAD").to_string();
+
+        self.branch_info = match repo.branch_info_cached() {
+            Ok(info) => Some(info),
+            Err(e) => {
+                tracing::warn!("Failed to read branch info for status bar: {}", e);
+                None
+            }
+        };
+
+        self.health = match repo.validate() {
+            Ok(health) => Some(health),
+            Err(e) => {
+                tracing::warn!("Failed to check repository health: {}", e);
+                None
+            }
+        };
+
+        self.uncommitted_changes = match repo.count_uncommitted_changes() {
+            Ok(count) => count,
+            Err(e) => {
+                tracing::warn!("Failed to count uncommitted changes: {}", e);
+                0
+            }
+        };
+    }
+
+    pub fn render(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = 8.0;
+
+            if !self.has_repository {
+                ui.colored_label(egui::Color32::GRAY, egui::RichText::new("No repository").size(12.0).italics());
+                return;
+            }
+
+            let Some(ref branch_info) = self.branch_info else {
+                ui.colored_label(egui::Color32::RED, egui::RichText::new("❌ Error reading branch info").size(12.0));
+                return;
+            };
+
+            match branch_info.state {
+                BranchState::Normal => {
+                    ui.label(egui::RichText::new("🌿").size(16.0));
+                    ui.strong(egui::RichText::new(&self.current_branch).size(14.0));
+                    ui.colored_label(egui::Color32::from_rgb(34, 139, 34), egui::RichText::new("●").size(12.0));
+                }
+                BranchState::DetachedHead => {
+                    ui.label(egui::RichText::new("⚠️").size(16.0));
+                    ui.colored_label(egui::Color32::YELLOW, egui::RichText::new("DETACHED HEAD").size(12.0).strong());
+                    ui.colored_label(egui::Color32::from_rgb(255, 165, 0), egui::RichText::new("●").size(12.0));
+                }
+                BranchState::Unborn => {
+                    ui.label(egui::RichText::new("🆕").size(16.0));
+                    ui.colored_label(egui::Color32::LIGHT_BLUE, egui::RichText::new("UNBORN").size(12.0).strong());
+                    ui.colored_label(egui::Color32::LIGHT_BLUE, egui::RichText::new("●").size(12.0));
+                }
+            }
+
+            if let Some(indicator) = self.health.as_ref().and_then(health_indicator) {
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.colored_label(indicator.color, egui::RichText::new(indicator.icon).size(14.0));
+                    ui.colored_label(indicator.color, egui::RichText::new(&indicator.label).size(12.0).strong());
+                })
+                .response
+                .on_hover_text(&indicator.tooltip);
+            }
+
+            if let Some(ref upstream) = branch_info.upstream {
+                ui.separator();
+                ui.label(egui::RichText::new("📡").size(14.0));
+                ui.label(egui::RichText::new("→").size(12.0).color(egui::Color32::GRAY));
+                ui.colored_label(egui::Color32::from_rgb(100, 149, 237), egui::RichText::new(upstream).size(12.0));
+
+                if branch_info.ahead > 0 || branch_info.behind > 0 {
+                    ui.separator();
+
+                    if branch_info.ahead > 0 {
+                        ui.colored_label(egui::Color32::GREEN, egui::RichText::new("↑").size(14.0));
+                        ui.colored_label(egui::Color32::GREEN, egui::RichText::new(format!("{}", branch_info.ahead)).size(12.0).strong());
+                    }
+
+                    if branch_info.behind > 0 {
+                        ui.colored_label(egui::Color32::RED, egui::RichText::new("↓").size(14.0));
+                        ui.colored_label(egui::Color32::RED, egui::RichText::new(format!("{}", branch_info.behind)).size(12.0).strong());
+                    }
+
+                    let sync_status = if branch_info.behind == 0 {
+                        ("📤", "Push needed", egui::Color32::BLUE)
+                    } else if branch_info.ahead == 0 {
+                        ("📥", "Pull needed", egui::Color32::from_rgb(255, 165, 0))
+                    } else {
+                        ("🔄", "Sync needed", egui::Color32::YELLOW)
+                    };
+
+                    ui.separator();
+                    ui.label(egui::RichText::new(sync_status.0).size(14.0));
+                    ui.colored_label(sync_status.2, egui::RichText::new(sync_status.1).size(11.0));
+                } else {
+                    ui.separator();
+                    ui.label(egui::RichText::new("✅").size(14.0));
+                    ui.colored_label(egui::Color32::GREEN, egui::RichText::new("In sync").size(11.0));
+                }
+            } else {
+                ui.separator();
+                ui.label(egui::RichText::new("🔗").size(14.0));
+                ui.colored_label(egui::Color32::GRAY, egui::RichText::new("No upstream").size(11.0).italics());
+            }
+
+            ui.separator();
+            let (changes_text, changes_color) = match self.uncommitted_changes {
+                0 => ("Clean".to_string(), egui::Color32::GRAY),
+                1 => ("1 change".to_string(), egui::Color32::from_rgb(255, 165, 0)),
+                count => (format!("{} changes", count), egui::Color32::from_rgb(255, 165, 0)),
+            };
+            ui.colored_label(changes_color, egui::RichText::new(changes_text).size(11.0));
+        });
+    }
+}
\ No newline at end of file
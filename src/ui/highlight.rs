@@ -0,0 +1,114 @@
+use eframe::egui;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::Path;
+use std::sync::OnceLock;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Theme, ThemeSet};
+use syntect::parsing::{SyntaxReference, SyntaxSet};
+
+const DARK_THEME: &str = "base16-ocean.dark";
+const LIGHT_THEME: &str = "InspiredGitHub";
+
+/// Highlighted lines kept by a `HighlightCache` before it starts over.
+const CACHE_LIMIT: usize = 10_000;
+
+/// Colored byte ranges covering one line of text.
+pub type LineSpans = Vec<(Range<usize>, egui::Color32)>;
+
+/// Building the syntax set takes tens of milliseconds, so it is loaded once
+/// on first use and shared.
+fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAXES.get_or_init(SyntaxSet::load_defaults_nonewlines)
+}
+
+fn theme(dark: bool) -> &'static Theme {
+    static THEMES: OnceLock<ThemeSet> = OnceLock::new();
+    let themes = THEMES.get_or_init(ThemeSet::load_defaults);
+    &themes.themes[if dark { DARK_THEME } else { LIGHT_THEME }]
+}
+
+/// Syntax for a file, picked by extension and then by file name (for
+/// `Makefile` and friends). `None` when nothing better than plain text
+/// matches.
+pub fn syntax_for_path(path: &str) -> Option<&'static SyntaxReference> {
+    let set = syntax_set();
+    let path = Path::new(path);
+
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .and_then(|extension| set.find_syntax_by_extension(extension))
+        .or_else(|| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| set.find_syntax_by_extension(name))
+        })
+        .filter(|syntax| syntax.name != set.find_syntax_plain_text().name)
+}
+
+/// Highlights `line` on its own, without state carried over from earlier
+/// lines, so it can be drawn over any diff background. Empty if the line
+/// cannot be parsed.
+pub fn highlight_line(syntax: &SyntaxReference, line: &str, dark: bool) -> LineSpans {
+    let mut highlighter = HighlightLines::new(syntax, theme(dark));
+    let Ok(regions) = highlighter.highlight_line(line, syntax_set()) else {
+        return Vec::new();
+    };
+
+    let mut start = 0;
+    regions
+        .into_iter()
+        .map(|(style, text)| {
+            let range = start..start + text.len();
+            start = range.end;
+            let color = style.foreground;
+            (range, egui::Color32::from_rgb(color.r, color.g, color.b))
+        })
+        .collect()
+}
+
+/// Draws `line` left-aligned at `pos` in the colors of `spans`, assuming a
+/// monospace font `char_width` wide.
+pub fn paint_line(painter: &egui::Painter, pos: egui::Pos2, line: &str, spans: &LineSpans, font_id: &egui::FontId, char_width: f32) {
+    let mut column = 0;
+    for (range, color) in spans {
+        let text = &line[range.clone()];
+        painter.text(
+            pos + egui::vec2(char_width * column as f32, 0.0),
+            egui::Align2::LEFT_CENTER,
+            text,
+            font_id.clone(),
+            *color,
+        );
+        column += text.chars().count();
+    }
+}
+
+/// Remembers highlighted lines between frames, keyed by syntax and text.
+#[derive(Default)]
+pub struct HighlightCache {
+    dark: bool,
+    lines: HashMap<(String, String), LineSpans>,
+}
+
+impl HighlightCache {
+    pub fn get(&mut self, syntax: &SyntaxReference, line: &str, dark: bool) -> &LineSpans {
+        if dark != self.dark || self.lines.len() >= CACHE_LIMIT {
+            self.dark = dark;
+            self.lines.clear();
+        }
+
+        self.lines
+            .entry((syntax.name.clone(), line.to_string()))
+            .or_insert_with(|| highlight_line(syntax, line, dark))
+    }
+
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+}
\ No newline at end of file
@@ -1,4 +1,7 @@
 pub mod components;
+pub mod highlight;
+pub mod selection_history;
+pub mod settings_search;
 
 #[allow(unused_imports)]
 pub use components::*;
\ No newline at end of file
@@ -0,0 +1,92 @@
+use crate::git::types::CommitId;
+
+const DEFAULT_CAPACITY: usize = 100;
+
+/// Browser-style back/forward history of selected commits.
+#[derive(Debug, Clone)]
+pub struct SelectionHistory {
+    entries: Vec<CommitId>,
+    cursor: usize,
+    capacity: usize,
+}
+
+impl Default for SelectionHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl SelectionHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Vec::new(),
+            cursor: 0,
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Records a new selection, dropping any forward entries. Selecting the
+    /// current commit again is a no-op.
+    pub fn push(&mut self, commit_id: CommitId) {
+        if self.current() == Some(commit_id) {
+            return;
+        }
+
+        if !self.entries.is_empty() {
+            self.entries.truncate(self.cursor + 1);
+        }
+        self.entries.push(commit_id);
+
+        if self.entries.len() > self.capacity {
+            let excess = self.entries.len() - self.capacity;
+            self.entries.drain(..excess);
+        }
+        self.cursor = self.entries.len() - 1;
+    }
+
+    pub fn current(&self) -> Option<CommitId> {
+        self.entries.get(self.cursor).copied()
+    }
+
+    /// Index of the current entry, if there is one.
+    pub fn position(&self) -> Option<usize> {
+        (!self.entries.is_empty()).then_some(self.cursor)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        !self.entries.is_empty() && self.cursor > 0
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        self.cursor + 1 < self.entries.len()
+    }
+
+    pub fn go_back(&mut self) -> Option<CommitId> {
+        if !self.can_go_back() {
+            return None;
+        }
+        self.cursor -= 1;
+        self.current()
+    }
+
+    pub fn go_forward(&mut self) -> Option<CommitId> {
+        if !self.can_go_forward() {
+            return None;
+        }
+        self.cursor += 1;
+        self.current()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.cursor = 0;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,22 @@
+/// Whether `label` contains `query`, ignoring case; a blank query matches
+/// everything.
+pub fn matches(query: &str, label: &str) -> bool {
+    let query = query.trim();
+    query.is_empty() || label.to_lowercase().contains(&query.to_lowercase())
+}
+
+/// Section of the first entry in `index` whose label matches `query`.
+pub fn first_match<T: Clone>(query: &str, index: &[(T, String)]) -> Option<T> {
+    index
+        .iter()
+        .find(|(_, label)| matches(query, label))
+        .map(|(section, _)| section.clone())
+}
+
+/// Number of entries in `section` whose label matches `query`.
+pub fn match_count<T: PartialEq>(query: &str, index: &[(T, String)], section: &T) -> usize {
+    index
+        .iter()
+        .filter(|(entry, label)| entry == section && matches(query, label))
+        .count()
+}
\ No newline at end of file
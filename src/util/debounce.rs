@@ -0,0 +1,45 @@
+use std::time::{Duration, Instant};
+
+/// Coalesces a burst of changes into a single action that fires once no
+/// further change has arrived for `delay`.
+#[derive(Debug, Clone)]
+pub struct Debouncer {
+    delay: Duration,
+    last_change: Option<Instant>,
+}
+
+impl Debouncer {
+    pub fn new(delay: Duration) -> Self {
+        Self { delay, last_change: None }
+    }
+
+    /// Records a change at `now`, pushing the deadline back.
+    pub fn mark(&mut self, now: Instant) {
+        self.last_change = Some(now);
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.last_change.is_some()
+    }
+
+    /// Time left at `now` before a pending change is due, if any.
+    pub fn remaining(&self, now: Instant) -> Option<Duration> {
+        self.last_change
+            .map(|last| (last + self.delay).saturating_duration_since(now))
+    }
+
+    /// Whether the pending change is due at `now`; a `true` answer consumes it.
+    pub fn ready(&mut self, now: Instant) -> bool {
+        if self.remaining(now) == Some(Duration::ZERO) {
+            self.last_change = None;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes any pending change regardless of the deadline, e.g. on exit.
+    pub fn flush(&mut self) -> bool {
+        self.last_change.take().is_some()
+    }
+}
\ No newline at end of file
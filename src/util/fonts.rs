@@ -0,0 +1,36 @@
+use crate::error::{Result, TwiggyError};
+use ab_glyph::Font;
+use eframe::egui;
+use std::path::Path;
+
+const CUSTOM_FONT_NAME: &str = "custom";
+
+/// egui's default fonts with the TTF/OTF file at `path` placed first in both
+/// the proportional and monospace families, so the defaults only fill in
+/// glyphs it lacks. The file is parsed here because egui panics on font data
+/// it cannot read.
+pub fn font_definitions_with(path: &Path) -> Result<egui::FontDefinitions> {
+    let data = std::fs::read(path).map_err(|e| TwiggyError::FileSystem {
+        path: path.display().to_string(),
+        source: e,
+    })?;
+
+    let font = ab_glyph::FontRef::try_from_slice(&data).map_err(|e| TwiggyError::Validation {
+        field: "theme.font_family".to_string(),
+        message: format!("{} is not a readable TTF/OTF font: {}", path.display(), e),
+    })?;
+    if !font.units_per_em().is_some_and(|units| (16.0..=16384.0).contains(&units)) {
+        return Err(TwiggyError::Validation {
+            field: "theme.font_family".to_string(),
+            message: format!("{} has an unsupported units-per-em value", path.display()),
+        });
+    }
+
+    let mut fonts = egui::FontDefinitions::default();
+    fonts.font_data.insert(CUSTOM_FONT_NAME.to_string(), egui::FontData::from_owned(data));
+    for family in [egui::FontFamily::Proportional, egui::FontFamily::Monospace] {
+        fonts.families.entry(family).or_default().insert(0, CUSTOM_FONT_NAME.to_string());
+    }
+
+    Ok(fonts)
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+use eframe::egui::{Key, KeyboardShortcut, Modifiers};
+
+const KEYS: [Key; 73] = [
+    Key::ArrowDown, Key::ArrowLeft, Key::ArrowRight, Key::ArrowUp,
+    Key::Escape, Key::Tab, Key::Backspace, Key::Enter, Key::Space,
+    Key::Insert, Key::Delete, Key::Home, Key::End, Key::PageUp, Key::PageDown,
+    Key::Minus, Key::PlusEquals,
+    Key::Num0, Key::Num1, Key::Num2, Key::Num3, Key::Num4,
+    Key::Num5, Key::Num6, Key::Num7, Key::Num8, Key::Num9,
+    Key::A, Key::B, Key::C, Key::D, Key::E, Key::F, Key::G, Key::H, Key::I,
+    Key::J, Key::K, Key::L, Key::M, Key::N, Key::O, Key::P, Key::Q, Key::R,
+    Key::S, Key::T, Key::U, Key::V, Key::W, Key::X, Key::Y, Key::Z,
+    Key::F1, Key::F2, Key::F3, Key::F4, Key::F5, Key::F6, Key::F7, Key::F8, Key::F9, Key::F10,
+    Key::F11, Key::F12, Key::F13, Key::F14, Key::F15, Key::F16, Key::F17, Key::F18, Key::F19, Key::F20,
+];
+
+/// Parses bindings such as `Ctrl+Shift+O` or `F5`. Modifier and key names
+/// are case-insensitive; keys use egui's names (`Plus`, `PageUp`, `1`, ...).
+pub fn parse_shortcut(text: &str) -> Option<KeyboardShortcut> {
+    let mut modifiers = Modifiers::NONE;
+    let mut key = None;
+
+    for part in text.split('+').map(str::trim) {
+        if key.is_some() {
+            return None;
+        }
+
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => modifiers = modifiers | Modifiers::CTRL,
+            "shift" => modifiers = modifiers | Modifiers::SHIFT,
+            "alt" | "option" => modifiers = modifiers | Modifiers::ALT,
+            "cmd" | "command" => modifiers = modifiers | Modifiers::MAC_CMD,
+            name => key = Some(KEYS.into_iter().find(|key| key.name().eq_ignore_ascii_case(name))?),
+        }
+    }
+
+    Some(KeyboardShortcut::new(modifiers, key?))
+}
+
+/// The inverse of `parse_shortcut`, e.g. `Ctrl+Shift+O`.
+pub fn format_shortcut(shortcut: &KeyboardShortcut) -> String {
+    let modifiers = shortcut.modifiers;
+    let mut parts = Vec::new();
+
+    if modifiers.ctrl {
+        parts.push("Ctrl");
+    }
+    if modifiers.alt {
+        parts.push("Alt");
+    }
+    if modifiers.shift {
+        parts.push("Shift");
+    }
+    if modifiers.mac_cmd {
+        parts.push("Cmd");
+    }
+    parts.push(shortcut.key.name());
+
+    parts.join("+")
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+pub mod debounce;
+pub mod fonts;
+pub mod keys;
+pub mod os;
+pub mod text;
+
+#[allow(unused_imports)]
+pub use os::*;
+#[allow(unused_imports)]
+pub use text::*;
\ No newline at end of file
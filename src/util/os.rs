@@ -0,0 +1,86 @@
+use std::path::Path;
+use std::process::Command;
+use crate::error::{Result, TwiggyError};
+
+/// Opens an `https://` URL in the default browser. Anything else is
+/// rejected, since the URL may come from repository config. On Windows the
+/// URL is handed to `url.dll` directly rather than through `cmd.exe`, which
+/// would interpret `&`, `|` and `^`.
+pub fn open_url(url: &str) -> Result<()> {
+    if !url.starts_with("https://") {
+        return Err(TwiggyError::Validation {
+            field: "url".to_string(),
+            message: format!("Refusing to open non-https URL: {}", url),
+        });
+    }
+
+    tracing::info!("Opening URL: {}", url);
+
+    #[cfg(target_os = "windows")]
+    let result = Command::new("rundll32").args(["url.dll,FileProtocolHandler", url]).spawn();
+
+    #[cfg(target_os = "macos")]
+    let result = Command::new("open").arg(url).spawn();
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let result = Command::new("xdg-open").arg(url).spawn();
+
+    result.map(|_| ()).map_err(|e| TwiggyError::Io {
+        operation: format!("open {}", url),
+        source: e,
+    })
+}
+
+/// Opens `path` in the platform's file manager.
+pub fn open_path_in_file_manager(path: &Path) -> Result<()> {
+    tracing::info!("Opening in file manager: {}", path.display());
+
+    #[cfg(target_os = "windows")]
+    let result = Command::new("explorer").arg(path).spawn();
+
+    #[cfg(target_os = "macos")]
+    let result = Command::new("open").arg(path).spawn();
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let result = Command::new("xdg-open").arg(path).spawn();
+
+    result.map(|_| ()).map_err(|e| TwiggyError::Io {
+        operation: format!("open {} in file manager", path.display()),
+        source: e,
+    })
+}
+
+/// Launches a terminal window whose working directory is `path`. On Linux
+/// `$TERMINAL` wins, then the first common emulator that starts.
+pub fn open_terminal_at(path: &Path) -> Result<()> {
+    tracing::info!("Opening terminal at: {}", path.display());
+
+    #[cfg(target_os = "windows")]
+    let result = Command::new("cmd").args(["/C", "start", "cmd"]).current_dir(path).spawn();
+
+    #[cfg(target_os = "macos")]
+    let result = Command::new("open").args(["-a", "Terminal"]).arg(path).spawn();
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let result = {
+        let candidates = std::env::var("TERMINAL")
+            .ok()
+            .filter(|terminal| !terminal.trim().is_empty())
+            .into_iter()
+            .chain(["x-terminal-emulator", "gnome-terminal", "konsole", "xfce4-terminal", "xterm"].map(String::from));
+
+        let mut result = Err(std::io::Error::new(std::io::ErrorKind::NotFound, "no terminal emulator found"));
+        for terminal in candidates {
+            result = Command::new(&terminal).current_dir(path).spawn();
+            if result.is_ok() {
+                break;
+            }
+        }
+        result
+    };
+
+    result.map(|_| ()).map_err(|e| TwiggyError::Io {
+        operation: format!("open terminal at {}", path.display()),
+        source: e,
+    })
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+/// Shortens `text` to at most `max` characters by replacing the middle with
+/// an ellipsis, keeping both ends visible (e.g. `jane.doe…@example.com`).
+pub fn truncate_middle(text: &str, max: usize) -> String {
+    let char_count = text.chars().count();
+    if char_count <= max {
+        return text.to_string();
+    }
+
+    if max == 0 {
+        return String::new();
+    }
+
+    let keep = max - 1;
+    let head = keep.div_ceil(2);
+    let tail = keep - head;
+
+    let mut result: String = text.chars().take(head).collect();
+    result.push('…');
+    result.extend(text.chars().skip(char_count - tail));
+    result
+}
+
+/// Byte range of the first case-insensitive occurrence of `needle` in
+/// `haystack`, on character boundaries so it can slice `haystack` directly.
+pub fn find_ignore_case(haystack: &str, needle: &str) -> Option<(usize, usize)> {
+    let needle: Vec<char> = needle.chars().flat_map(char::to_lowercase).collect();
+    if needle.is_empty() {
+        return None;
+    }
+
+    for (start, _) in haystack.char_indices() {
+        let mut wanted = needle.iter();
+        for (offset, c) in haystack[start..].char_indices() {
+            if !c.to_lowercase().all(|l| wanted.next() == Some(&l)) {
+                break;
+            }
+            if wanted.len() == 0 {
+                return Some((start, start + offset + c.len_utf8()));
+            }
+        }
+    }
+
+    None
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+mod common;
+
+#[cfg(test)]
+mod author_stats_tests {
+    use git2::{Oid, Repository};
+    use twiggy::git::repository::GitRepository;
+    use crate::common::{commit_index_as, signature_at};
+
+    fn commit_as(repo: &Repository, name: &str, email: &str, seconds: i64) -> Oid {
+        commit_index_as(repo, None, &signature_at(name, email, seconds), "Commit")
+    }
+
+    #[test]
+    fn test_author_stats_groups_by_email_and_sorts_by_count() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        commit_as(&raw, "Ada", "ada@example.com", 1_000);
+        commit_as(&raw, "Bob", "bob@example.com", 2_000);
+        commit_as(&raw, "Ada L.", "ADA@example.com", 3_000);
+        commit_as(&raw, "Ada Lovelace", "ada@example.com", 4_000);
+
+        let mut repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+        repo.load_commits(None).expect("Failed to load commits");
+        let stats = repo.author_stats().expect("Failed to compute author stats");
+
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].name, "Ada Lovelace");
+        assert_eq!(stats[0].commit_count, 3);
+        assert_eq!(stats[0].first_commit.timestamp(), 1_000);
+        assert_eq!(stats[0].last_commit.timestamp(), 4_000);
+        assert_eq!((stats[1].name.as_str(), stats[1].commit_count), ("Bob", 1));
+    }
+
+    #[test]
+    fn test_author_stats_empty_without_loaded_commits() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        Repository::init(dir.path()).expect("Failed to initialize repository");
+
+        let repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+
+        assert!(repo.author_stats().expect("Failed to compute author stats").is_empty());
+    }
+}
\ No newline at end of file
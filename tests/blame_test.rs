@@ -0,0 +1,105 @@
+mod common;
+
+#[cfg(test)]
+mod blame_tests {
+    use git2::Repository;
+    use std::path::Path;
+    use twiggy::error::TwiggyError;
+    use twiggy::git::repository::GitRepository;
+    use twiggy::git::types::CommitId;
+    use twiggy::ui::components::BlameView;
+    use crate::common::commit_file_as;
+
+    #[test]
+    fn test_blame_attributes_lines_to_commits() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let first = commit_file_as(&raw, "a.txt", b"one\ntwo\n", "Update", "Alice");
+        let second = commit_file_as(&raw, "a.txt", b"one\ntwo\nthree\n", "Update", "Bob");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let lines = repo.blame_file(Path::new("a.txt"), None).expect("Failed to blame file");
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].content, "one");
+        assert_eq!(lines[0].commit, CommitId(first));
+        assert_eq!(lines[0].author, "Alice");
+        assert_eq!(lines[2].line_number, 3);
+        assert_eq!(lines[2].commit, CommitId(second));
+        assert_eq!(lines[2].author, "Bob");
+
+        let older = repo.blame_file(Path::new("a.txt"), Some(CommitId(first))).expect("Failed to blame file");
+        assert_eq!(older.len(), 2);
+        assert!(older.iter().all(|line| line.commit == CommitId(first)));
+    }
+
+    #[test]
+    fn test_blame_rejects_binary_files() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+        commit_file_as(&raw, "image.bin", [0u8, 159, 146, 150, 0, 1], "Update", "Alice");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let result = repo.blame_file(Path::new("image.bin"), None);
+
+        assert!(matches!(result, Err(TwiggyError::Validation { .. })));
+    }
+
+    #[test]
+    fn test_blame_parents_marks_root_commits() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let first = commit_file_as(&raw, "a.txt", b"one\n", "Update", "Alice");
+        let second = commit_file_as(&raw, "a.txt", b"uno\n", "Update", "Bob");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let lines = repo.blame_file(Path::new("a.txt"), Some(CommitId(second))).expect("Failed to blame file");
+        let parents = repo.blame_parents(&lines).expect("Failed to read parents");
+
+        assert_eq!(parents.len(), 1);
+        assert_eq!(parents[&CommitId(second)], Some(CommitId(first)));
+
+        let before = repo.blame_file(Path::new("a.txt"), Some(CommitId(first))).expect("Failed to blame file");
+        assert_eq!(before[0].content, "one");
+        let root_parents = repo.blame_parents(&before).expect("Failed to read parents");
+        assert_eq!(root_parents[&CommitId(first)], None);
+    }
+
+    #[test]
+    fn test_blame_view_steps_back_and_forward() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let first = CommitId(commit_file_as(&raw, "a.txt", b"one\n", "Update", "Alice"));
+        let second = CommitId(commit_file_as(&raw, "a.txt", b"uno\n", "Update", "Bob"));
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let blame_at = |id: CommitId| {
+            let lines = repo.blame_file(Path::new("a.txt"), Some(id)).expect("Failed to blame file");
+            let parents = repo.blame_parents(&lines).expect("Failed to read parents");
+            (lines, parents)
+        };
+
+        let mut view = BlameView::new();
+        let (lines, parents) = blame_at(second);
+        view.show("a.txt".to_string(), second, lines, parents);
+        assert!(!view.can_step_forward());
+
+        let (lines, parents) = blame_at(first);
+        view.step_back(first, lines, parents);
+        assert_eq!(view.revision(), Some(first));
+        assert!(view.can_step_forward());
+
+        assert!(view.step_forward());
+        assert_eq!(view.revision(), Some(second));
+        assert!(!view.step_forward());
+
+        let (lines, parents) = blame_at(first);
+        view.step_back(first, lines, parents);
+        let (lines, parents) = blame_at(second);
+        view.show("a.txt".to_string(), second, lines, parents);
+        assert!(!view.can_step_forward());
+    }
+}
\ No newline at end of file
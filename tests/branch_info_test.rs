@@ -0,0 +1,209 @@
+mod common;
+
+#[cfg(test)]
+mod branch_info_tests {
+    use git2::Repository;
+    use twiggy::git::repository::{BranchFilter, BranchState, GitRepository};
+    use twiggy::git::types::CommitId;
+    use crate::common::commit_file;
+
+    #[test]
+    fn test_detached_at_branch_tip_names_branch() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        commit_file(&raw, "a.txt", "one", "First");
+        let tip = commit_file(&raw, "a.txt", "two", "Second");
+        let branch_name = raw.head().unwrap().shorthand().unwrap().to_string();
+
+        raw.set_head_detached(tip).expect("Failed to detach HEAD");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let info = repo.get_branch_info().expect("Failed to read branch info");
+
+        assert!(matches!(info.state, BranchState::DetachedHead));
+        assert_eq!(info.name, format!("HEAD detached at {:.7} (== {})", tip, branch_name));
+        assert_eq!(repo.current_branch(), Some(info.name.as_str()));
+    }
+
+    #[test]
+    fn test_detached_name_lists_local_then_remote_branches() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let tip = commit_file(&raw, "a.txt", "one", "First");
+        raw.reference("refs/heads/feature/x", tip, true, "test branch").expect("Failed to create branch");
+        raw.reference("refs/remotes/origin/main", tip, true, "test remote branch")
+            .expect("Failed to create remote ref");
+        raw.reference_symbolic("refs/remotes/origin/HEAD", "refs/remotes/origin/main", true, "test remote head")
+            .expect("Failed to create remote HEAD");
+        raw.tag_lightweight("v1.0", &raw.find_object(tip, None).unwrap(), false).expect("Failed to create tag");
+        let branch_name = raw.head().unwrap().shorthand().unwrap().to_string();
+        raw.set_head_detached(tip).expect("Failed to detach HEAD");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let info = repo.get_branch_info().expect("Failed to read branch info");
+
+        assert_eq!(
+            info.name,
+            format!("HEAD detached at {:.7} (== feature/x, {}, origin/main)", tip, branch_name)
+        );
+    }
+
+    #[test]
+    fn test_detached_off_branch_tip_has_no_branch() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let first = commit_file(&raw, "a.txt", "one", "First");
+        commit_file(&raw, "a.txt", "two", "Second");
+
+        raw.set_head_detached(first).expect("Failed to detach HEAD");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let info = repo.get_branch_info().expect("Failed to read branch info");
+
+        assert_eq!(info.name, format!("HEAD detached at {:.7}", first));
+    }
+
+    #[test]
+    fn test_unborn_branch_is_recognized() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+        raw.set_head("refs/heads/trunk").expect("Failed to point HEAD at trunk");
+
+        let mut repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+
+        assert!(repo.is_unborn());
+        assert_eq!(repo.current_branch(), Some("trunk"));
+
+        let info = repo.get_branch_info().expect("Failed to read branch info");
+        assert!(matches!(info.state, BranchState::Unborn));
+        assert_eq!(info.name, "trunk");
+
+        repo.load_commits(Some(10)).expect("Loading an unborn branch should not fail");
+        assert!(repo.get_commits().is_empty());
+
+        let branch_commits = repo.load_commits_for_branch("trunk", Some(10))
+            .expect("Loading the unborn branch by name should not fail");
+        assert!(branch_commits.is_empty());
+
+        commit_file(&raw, "a.txt", "one", "First");
+        assert!(!repo.is_unborn());
+    }
+
+    #[test]
+    fn test_count_uncommitted_changes() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+        commit_file(&raw, "a.txt", "one\n", "First");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        assert_eq!(repo.count_uncommitted_changes().expect("Failed to count changes"), 0);
+
+        std::fs::write(temp_dir.path().join("a.txt"), "two\n").expect("Failed to modify file");
+        std::fs::write(temp_dir.path().join("b.txt"), "new\n").expect("Failed to write file");
+        assert_eq!(repo.count_uncommitted_changes().expect("Failed to count changes"), 2);
+    }
+
+    #[test]
+    fn test_list_branches_by_filter() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        assert!(repo.list_branches(BranchFilter::All).expect("Failed to list branches").is_empty());
+
+        let first = commit_file(&raw, "a.txt", "one\n", "First");
+        let commit = raw.find_commit(first).expect("Failed to find commit");
+        raw.branch("feature", &commit, false).expect("Failed to create branch");
+        raw.reference("refs/remotes/origin/main", first, true, "test remote branch")
+            .expect("Failed to create remote ref");
+        raw.reference_symbolic("refs/remotes/origin/HEAD", "refs/remotes/origin/main", true, "test remote head")
+            .expect("Failed to create remote HEAD");
+
+        let local: Vec<String> = repo.list_branches(BranchFilter::Local).expect("Failed to list branches")
+            .into_iter().map(|branch| branch.name).collect();
+        let remote: Vec<String> = repo.list_branches(BranchFilter::Remote).expect("Failed to list branches")
+            .into_iter().map(|branch| branch.name).collect();
+        let all = repo.list_branches(BranchFilter::All).expect("Failed to list branches");
+
+        let head = raw.head().expect("Failed to read HEAD").shorthand().expect("Invalid HEAD").to_string();
+        let mut expected_local = vec!["feature".to_string(), head];
+        expected_local.sort();
+        assert_eq!(local, expected_local);
+        assert_eq!(remote, vec!["origin/main".to_string()]);
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[2].name, "origin/main");
+
+        let commits = GitRepository::open(temp_dir.path()).expect("Failed to open repository")
+            .load_commits_for_branch("origin/main", None)
+            .expect("Failed to load remote branch");
+        assert_eq!(commits.len(), 1);
+    }
+
+    #[test]
+    fn test_cached_ahead_behind_is_reused_until_tip_moves() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let first = commit_file(&raw, "a.txt", "one\n", "First");
+        raw.remote("origin", "https://example.com/repo.git").expect("Failed to add remote");
+        raw.reference("refs/remotes/origin/main", first, true, "test remote branch")
+            .expect("Failed to create remote ref");
+        let head = raw.head().expect("Failed to read HEAD").shorthand().expect("Invalid HEAD").to_string();
+        raw.find_branch(&head, git2::BranchType::Local).expect("Failed to find branch")
+            .set_upstream(Some("origin/main")).expect("Failed to set upstream");
+        commit_file(&raw, "a.txt", "two\n", "Second");
+
+        let mut repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+
+        let info = repo.branch_info_cached().expect("Failed to read branch info");
+        assert_eq!((info.ahead, info.behind), (1, 0));
+        assert_eq!(repo.ahead_behind_computations(), 1);
+
+        let again = repo.branch_info_cached().expect("Failed to read branch info");
+        assert_eq!((again.ahead, again.behind), (1, 0));
+        assert_eq!(repo.ahead_behind_computations(), 1);
+
+        commit_file(&raw, "a.txt", "three\n", "Third");
+        let moved = repo.branch_info_cached().expect("Failed to read branch info");
+        assert_eq!((moved.ahead, moved.behind), (2, 0));
+        assert_eq!(repo.ahead_behind_computations(), 2);
+        assert_eq!(repo.ahead_behind_cache_len(), 2);
+
+        repo.refresh().expect("Failed to refresh");
+        let refreshed = repo.branch_info_cached().expect("Failed to read branch info");
+        assert_eq!((refreshed.ahead, refreshed.behind), (2, 0));
+        assert_eq!(repo.ahead_behind_computations(), 2);
+
+        assert_eq!(repo.get_branch_info().expect("Failed to read branch info").ahead, 2);
+        assert_eq!(repo.ahead_behind_computations(), 3);
+    }
+
+    #[test]
+    fn test_rewritten_commit_leaves_history_but_stays_in_odb() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let first = commit_file(&raw, "a.txt", "one", "First");
+        let selected = commit_file(&raw, "a.txt", "two", "Second");
+        raw.branch("feature", &raw.find_commit(selected).unwrap(), false).expect("Failed to create branch");
+
+        let mut repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let selected_id = CommitId(selected);
+        assert!(repo.is_in_history(&selected_id, None));
+
+        // Rewrite HEAD past the selected commit, as a hard reset or force-push would.
+        raw.reset(raw.find_commit(first).unwrap().as_object(), git2::ResetType::Hard, None)
+            .expect("Failed to reset");
+        raw.find_branch("feature", git2::BranchType::Local).unwrap()
+            .get_mut().set_target(first, "rewrite").expect("Failed to move branch");
+        repo.refresh().expect("Failed to refresh");
+
+        assert!(matches!(repo.find_commit_by_hash(&selected.to_string()), Ok(Some(_))));
+        assert!(!repo.is_in_history(&selected_id, None));
+        assert!(!repo.is_in_history(&selected_id, Some("feature")));
+        assert!(repo.is_in_history(&CommitId(first), Some("feature")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod command_palette_tests {
+    use std::path::PathBuf;
+    use twiggy::ui::components::command_palette::{fuzzy_score, CommandPalette, PaletteCommand, PaletteEntry};
+
+    fn palette() -> CommandPalette {
+        let mut palette = CommandPalette::new();
+        palette.show(vec![
+            PaletteEntry::new("Open Repository…", PaletteCommand::OpenRepository),
+            PaletteEntry::new("Toggle Theme", PaletteCommand::ToggleTheme),
+            PaletteEntry::new("Open Settings", PaletteCommand::OpenSettings),
+            PaletteEntry::new("Show Log Viewer", PaletteCommand::ShowLogViewer),
+            PaletteEntry::new("Open Recent: twiggy", PaletteCommand::OpenRecent(PathBuf::from("/src/twiggy"))),
+        ]);
+        palette
+    }
+
+    fn labels(palette: &CommandPalette) -> Vec<&str> {
+        palette.matches().iter().map(|entry| entry.label.as_str()).collect()
+    }
+
+    #[test]
+    fn test_fuzzy_score_requires_subsequence() {
+        assert!(fuzzy_score("tgth", "Toggle Theme").is_some());
+        assert!(fuzzy_score("TOGGLE", "toggle theme").is_some());
+        assert!(fuzzy_score("theme toggle", "Toggle Theme").is_none());
+        assert_eq!(fuzzy_score("", "Anything"), Some(0));
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_word_starts_and_runs() {
+        let word_start = fuzzy_score("set", "Open Settings").expect("Should match");
+        let scattered = fuzzy_score("set", "Show Log Viewer Test").expect("Should match");
+        assert!(word_start > scattered);
+    }
+
+    #[test]
+    fn test_empty_query_lists_everything_in_order() {
+        let palette = palette();
+        assert_eq!(labels(&palette).len(), 5);
+        assert_eq!(labels(&palette)[0], "Open Repository…");
+    }
+
+    #[test]
+    fn test_query_filters_and_ranks() {
+        let mut palette = palette();
+
+        palette.set_query("log");
+        assert_eq!(labels(&palette), vec!["Show Log Viewer"]);
+
+        palette.set_query("open");
+        let matches = labels(&palette);
+        assert_eq!(matches.len(), 3);
+        assert!(matches.iter().all(|label| label.starts_with("Open")));
+
+        palette.set_query("zzz");
+        assert!(palette.matches().is_empty());
+    }
+
+    #[test]
+    fn test_close_clears_entries() {
+        let mut palette = palette();
+        palette.close();
+
+        assert!(!palette.open);
+        assert!(palette.matches().is_empty());
+    }
+}
\ No newline at end of file
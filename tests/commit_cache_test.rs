@@ -0,0 +1,56 @@
+mod common;
+
+#[cfg(test)]
+mod commit_cache_tests {
+    use git2::{Repository, Signature};
+    use twiggy::git::commit_cache::{estimate_commit_size, CommitCache};
+    use twiggy::git::repository::GitRepository;
+    use crate::common::make_commit;
+
+    #[test]
+    fn test_least_recently_used_commit_is_evicted() {
+        let size = estimate_commit_size(&make_commit(1, &[]));
+        let mut cache = CommitCache::new(size * 2);
+
+        cache.insert(make_commit(1, &[]));
+        cache.insert(make_commit(2, &[]));
+        assert!(cache.get(&make_commit(1, &[]).id).is_some());
+
+        cache.insert(make_commit(3, &[]));
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.peek(&make_commit(1, &[]).id).is_some());
+        assert!(cache.peek(&make_commit(2, &[]).id).is_none());
+        assert!(cache.peek(&make_commit(3, &[]).id).is_some());
+        assert_eq!(cache.size_bytes(), size * 2);
+    }
+
+    #[test]
+    fn test_repository_cache_stays_under_limit() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+        let signature = Signature::now("Test User", "test@example.com").expect("Failed to create signature");
+        let tree_id = raw.index().expect("Failed to open index").write_tree().expect("Failed to write tree");
+        let tree = raw.find_tree(tree_id).expect("Failed to find tree");
+
+        let mut parent: Option<git2::Commit> = None;
+        for n in 0..60 {
+            let parents: Vec<&git2::Commit> = parent.iter().collect();
+            let id = raw.commit(Some("HEAD"), &signature, &signature, &format!("Commit number {}", n), &tree, &parents)
+                .expect("Failed to create commit");
+            parent = Some(raw.find_commit(id).expect("Failed to find commit"));
+        }
+
+        let limit = 8 * 1024;
+        let mut repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        repo.set_cache_limit_bytes(limit);
+        repo.load_commits(None).expect("Failed to load commits");
+
+        assert_eq!(repo.commit_count(), 60);
+        assert!(repo.cache_size_bytes() <= limit, "cache uses {} bytes", repo.cache_size_bytes());
+        assert!(repo.cache_size() > 0 && repo.cache_size() < 60);
+
+        let oldest = repo.get_commits().last().expect("Expected commits").id;
+        assert!(repo.get_commit_by_id(&oldest).is_some(), "loaded commits stay reachable after eviction");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod commit_detail_tests {
+    use chrono::{TimeZone, Utc};
+    use git2::Oid;
+    use twiggy::git::types::{Commit, CommitId, Signature};
+    use twiggy::ui::components::commit_detail::{format_signature, is_merge};
+
+    fn signature(email: &str) -> Signature {
+        Signature {
+            name: "Test User".to_string(),
+            email: email.to_string(),
+            time: Utc.with_ymd_and_hms(2024, 1, 31, 14, 5, 0).unwrap(),
+        }
+    }
+
+    fn commit_with_parents(count: u8) -> Commit {
+        Commit {
+            id: CommitId(Oid::from_bytes(&[9; 20]).unwrap()),
+            author: signature("test@example.com"),
+            committer: signature("test@example.com"),
+            message: "Summary\n\nBody".to_string(),
+            summary: "Summary".to_string(),
+            parents: (0..count).map(|n| CommitId(Oid::from_bytes(&[n; 20]).unwrap())).collect(),
+            tree_id: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_merge_detection() {
+        assert!(!is_merge(&commit_with_parents(0)));
+        assert!(!is_merge(&commit_with_parents(1)));
+        assert!(is_merge(&commit_with_parents(2)));
+    }
+
+    #[test]
+    fn test_format_signature() {
+        assert_eq!(format_signature(&signature("test@example.com")), "Test User <test@example.com>, 2024-01-31 14:05");
+        assert_eq!(format_signature(&signature("")), "Test User, 2024-01-31 14:05");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,103 @@
+mod common;
+
+#[cfg(test)]
+mod commit_export_tests {
+    use git2::{Oid, Repository};
+    use twiggy::git::repository::GitRepository;
+    use twiggy::git::types::CommitInfo;
+    use crate::common::{commit_index_as, signature_at};
+
+    fn commit_at(repo: &Repository, parents: &[Oid], message: &str, seconds: i64) -> Oid {
+        commit_index_as(repo, Some(parents), &signature_at("Jane Doe", "jane@example.com", seconds), message)
+    }
+
+    #[test]
+    fn test_export_writes_loaded_commits_as_pretty_json() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        let root = commit_at(&raw, &[], "Root\n\nFirst commit body", 1_700_000_000);
+        let tip = commit_at(&raw, &[root], "Tip", 1_700_000_100);
+
+        let mut repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+        repo.load_commits(None).expect("Failed to load commits");
+
+        let mut output = Vec::new();
+        repo.export_commits_json(&mut output).expect("Failed to export commits");
+        let text = String::from_utf8(output.clone()).expect("Export should be UTF-8");
+        assert!(text.starts_with("[\n  {"));
+        assert!(text.ends_with("]\n"));
+
+        let exported: Vec<CommitInfo> = serde_json::from_str(&text).expect("Export should be valid JSON");
+        assert_eq!(exported.len(), 2);
+        assert_eq!(exported[0].id, tip.to_string());
+        assert_eq!(exported[0].parents, vec![root.to_string()]);
+        assert_eq!(exported[1].id, root.to_string());
+        assert!(exported[1].parents.is_empty());
+        assert_eq!(exported[1].message, "Root\n\nFirst commit body");
+        assert_eq!(exported[1].author.name, "Jane Doe");
+        assert_eq!(exported[1].author.email, "jane@example.com");
+        assert_eq!(exported[1].timestamp.timestamp(), 1_700_000_000);
+
+        let mut again = Vec::new();
+        repo.export_commits_json(&mut again).expect("Failed to export commits");
+        assert_eq!(output, again);
+    }
+    /// Minimal RFC 4180 reader, enough to check the export round-trips.
+    fn parse_csv(text: &str) -> Vec<Vec<String>> {
+        let mut rows = Vec::new();
+        let mut row = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut chars = text.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match (c, quoted) {
+                ('"', true) if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                ('"', _) => quoted = !quoted,
+                (',', false) => row.push(std::mem::take(&mut field)),
+                ('\n', false) => {
+                    row.push(std::mem::take(&mut field));
+                    rows.push(std::mem::take(&mut row));
+                }
+                (c, _) => field.push(c),
+            }
+        }
+
+        rows
+    }
+
+    #[test]
+    fn test_csv_export_has_header_and_escapes_fields() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        let root = commit_at(&raw, &[], "Plain summary", 1_700_000_000);
+        let tip = commit_at(&raw, &[root], "Fix \"quoted\", comma", 1_700_000_100);
+
+        let mut repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+        repo.load_commits(None).expect("Failed to load commits");
+
+        let mut output = Vec::new();
+        repo.export_commits_csv(&mut output).expect("Failed to export commits");
+        let text = String::from_utf8(output).expect("Export should be UTF-8");
+
+        assert!(text.contains("\"Fix \"\"quoted\"\", comma\""));
+
+        let rows = parse_csv(&text);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], ["hash", "author_name", "author_email", "date", "summary", "parent_count"]);
+        assert_eq!(rows[1], [
+            tip.to_string().as_str(),
+            "Jane Doe",
+            "jane@example.com",
+            "2023-11-14T22:15:00+00:00",
+            "Fix \"quoted\", comma",
+            "1",
+        ]);
+        assert_eq!(rows[2][0], root.to_string());
+        assert_eq!(rows[2][4], "Plain summary");
+        assert_eq!(rows[2][5], "0");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,144 @@
+mod common;
+
+#[cfg(test)]
+mod commit_filter_tests {
+    use git2::{Repository, Signature as GitSignature};
+    use twiggy::git::filter::{hide_merge_commits, merge_stashes, CommitFilter, MergeFilter};
+    use twiggy::git::repository::GitRepository;
+    use twiggy::git::types::{Commit, CommitId};
+    use crate::common::{commit_id, make_commit_by};
+
+    fn sample() -> Vec<Commit> {
+        vec![
+            make_commit_by(4, &[3, 2], "Alice", "Merge branch 'feature'"),
+            make_commit_by(3, &[1], "Bob", "Fix parser crash"),
+            make_commit_by(2, &[1], "Alice", "Add feature flag"),
+            make_commit_by(1, &[], "Carol", "Initial commit"),
+        ]
+    }
+
+    fn matching(filter: &CommitFilter) -> Vec<CommitId> {
+        sample().iter().filter(|commit| filter.matches(commit)).map(|commit| commit.id).collect()
+    }
+
+    fn ids(commits: &[Commit]) -> Vec<CommitId> {
+        commits.iter().map(|commit| commit.id).collect()
+    }
+
+    fn parents_of(commits: &[Commit], n: u8) -> Vec<CommitId> {
+        commits.iter().find(|commit| commit.id == commit_id(n)).expect("Commit should be visible").parents.clone()
+    }
+
+    #[test]
+    fn test_empty_filter_matches_everything() {
+        let filter = CommitFilter::default();
+
+        assert!(filter.is_empty());
+        assert_eq!(matching(&filter).len(), 4);
+    }
+
+    #[test]
+    fn test_merge_filters() {
+        let merges_only = CommitFilter { merges: MergeFilter::MergesOnly, ..Default::default() };
+        let no_merges = CommitFilter { merges: MergeFilter::NoMerges, ..Default::default() };
+
+        assert_eq!(matching(&merges_only), vec![commit_id(4)]);
+        assert_eq!(matching(&no_merges), vec![commit_id(3), commit_id(2), commit_id(1)]);
+    }
+
+    #[test]
+    fn test_substring_fields_are_case_insensitive_and_combined() {
+        let by_author = CommitFilter { author: Some("ALICE".to_string()), ..Default::default() };
+        assert_eq!(matching(&by_author), vec![commit_id(4), commit_id(2)]);
+
+        let by_message = CommitFilter { message: Some("FEATURE".to_string()), ..Default::default() };
+        assert_eq!(matching(&by_message), vec![commit_id(4), commit_id(2)]);
+
+        let combined = CommitFilter {
+            author: Some("alice".to_string()),
+            message: Some("feature".to_string()),
+            merges: MergeFilter::NoMerges,
+            ..Default::default()
+        };
+        assert_eq!(matching(&combined), vec![commit_id(2)]);
+    }
+
+    #[test]
+    fn test_search_commits_filtered_on_repository() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+        let signature = GitSignature::now("Test User", "test@example.com").expect("Failed to create signature");
+        let tree_id = raw.index().expect("Failed to open index").write_tree().expect("Failed to write tree");
+        let tree = raw.find_tree(tree_id).expect("Failed to find tree");
+
+        let first = raw.commit(Some("HEAD"), &signature, &signature, "First", &tree, &[])
+            .expect("Failed to create commit");
+        let first = raw.find_commit(first).expect("Failed to find commit");
+        raw.commit(Some("HEAD"), &signature, &signature, "Second", &tree, &[&first])
+            .expect("Failed to create commit");
+
+        let mut repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        repo.load_commits(None).expect("Failed to load commits");
+
+        assert_eq!(repo.search_commits_filtered(&CommitFilter::default()).len(), 2);
+        assert_eq!(repo.search_commits("second").len(), 1);
+
+        let filter = CommitFilter { message: Some("FIRST".to_string()), ..Default::default() };
+        let results = repo.search_commits_filtered(&filter);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].summary, "First");
+    }
+
+    #[test]
+    fn test_hide_merges_resolves_chained_and_nested_merges() {
+        let commits = vec![
+            make_commit_by(8, &[7], "Alice", "Tip"),
+            make_commit_by(7, &[6, 5], "Alice", "Merge merges"),
+            make_commit_by(6, &[3, 2], "Alice", "Merge 'a'"),
+            make_commit_by(5, &[4, 2], "Alice", "Merge 'b'"),
+            make_commit_by(4, &[1], "Bob", "B"),
+            make_commit_by(3, &[1], "Bob", "A"),
+            make_commit_by(2, &[1], "Bob", "Shared"),
+            make_commit_by(1, &[], "Carol", "Initial commit"),
+        ];
+
+        let visible = hide_merge_commits(&commits);
+
+        assert_eq!(ids(&visible), vec![commit_id(8), commit_id(4), commit_id(3), commit_id(2), commit_id(1)]);
+        assert_eq!(parents_of(&visible, 8), vec![commit_id(3), commit_id(2), commit_id(4)]);
+        assert_eq!(parents_of(&visible, 4), vec![commit_id(1)]);
+    }
+
+    #[test]
+    fn test_hide_merges_keeps_parents_outside_loaded_range() {
+        let commits = vec![
+            make_commit_by(4, &[3], "Alice", "Tip"),
+            make_commit_by(3, &[2, 90], "Alice", "Merge older work"),
+            make_commit_by(2, &[1], "Bob", "Last loaded"),
+            make_commit_by(1, &[91, 92], "Bob", "Merge at page edge"),
+        ];
+
+        let visible = hide_merge_commits(&commits);
+
+        assert_eq!(ids(&visible), vec![commit_id(4), commit_id(2)]);
+        assert_eq!(parents_of(&visible, 4), vec![commit_id(2), commit_id(90)]);
+        assert_eq!(parents_of(&visible, 2), vec![commit_id(91), commit_id(92)]);
+    }
+
+    #[test]
+    fn test_hide_merges_keeps_stashes_and_rebases_them_past_merges() {
+        let commits = vec![
+            make_commit_by(4, &[3], "Alice", "Tip"),
+            make_commit_by(3, &[2, 1], "Alice", "Merge branch 'feature'"),
+            make_commit_by(2, &[1], "Bob", "Feature"),
+            make_commit_by(1, &[], "Carol", "Initial commit"),
+        ];
+        let stash = make_commit_by(9, &[3, 10, 11], "Alice", "WIP on main");
+
+        let visible = hide_merge_commits(&merge_stashes(&commits, &[stash]));
+
+        assert_eq!(ids(&visible), vec![commit_id(4), commit_id(9), commit_id(2), commit_id(1)]);
+        assert_eq!(parents_of(&visible, 9), vec![commit_id(2), commit_id(1)]);
+        assert_eq!(parents_of(&visible, 4), vec![commit_id(2), commit_id(1)]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,159 @@
+mod common;
+
+#[cfg(test)]
+mod commit_graph_tests {
+    use std::collections::HashMap;
+    use twiggy::git::types::{Commit, RefKind, RefLabel};
+    use twiggy::ui::components::commit_graph::{compute_layout, ref_color, row_refs, tip_name, CommitGraph, GraphEdge};
+    use crate::common::{commit_id, make_commit};
+
+    #[test]
+    fn test_linear_history_uses_one_lane() {
+        let commits = vec![make_commit(3, &[2]), make_commit(2, &[1]), make_commit(1, &[])];
+        let layout = compute_layout(&commits);
+
+        assert_eq!(layout.lanes, vec![0, 0, 0]);
+        assert_eq!(layout.lane_count, 1);
+        assert_eq!(layout.edges.len(), 2);
+        assert!(layout.edges.iter().all(|edge| edge.from_lane == 0 && edge.to_lane == 0));
+    }
+
+    #[test]
+    fn test_merge_branches_into_second_lane() {
+        // 4 merges 3 (main) and 2 (feature); both come from 1.
+        let commits = vec![
+            make_commit(4, &[3, 2]),
+            make_commit(3, &[1]),
+            make_commit(2, &[1]),
+            make_commit(1, &[]),
+        ];
+        let layout = compute_layout(&commits);
+
+        assert_eq!(layout.lanes, vec![0, 0, 1, 0]);
+        assert_eq!(layout.lane_count, 2);
+        assert!(layout.edges.contains(&GraphEdge { from_row: 0, from_lane: 0, via_lane: 1, to_row: 2, to_lane: 1 }));
+        assert!(layout.edges.contains(&GraphEdge { from_row: 2, from_lane: 1, via_lane: 0, to_row: 3, to_lane: 0 }));
+    }
+
+    #[test]
+    fn test_unloaded_parent_runs_off_the_bottom() {
+        let commits = vec![make_commit(3, &[2]), make_commit(2, &[1])];
+        let layout = compute_layout(&commits);
+
+        let last = layout.edges.last().expect("Expected an edge to the unloaded parent");
+        assert_eq!(last.from_row, 1);
+        assert_eq!(last.to_row, commits.len());
+        assert_eq!(last.to_lane, last.via_lane);
+    }
+
+    fn linear_history(len: u8) -> Vec<Commit> {
+        (1..=len)
+            .rev()
+            .map(|n| {
+                let parents: Vec<u8> = (n > 1).then(|| n - 1).into_iter().collect();
+                make_commit(n, &parents)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_synced_rows_match_list_at_fractional_zoom() {
+        let commits = linear_history(50);
+        let list_centers: Vec<f32> = (0..commits.len()).map(|row| row as f32 * 47.3 + 23.65).collect();
+
+        let mut graph = CommitGraph::new();
+        graph.zoom_level = 1.37;
+        graph.desired_width(&commits);
+        graph.sync_rows(&list_centers, 812.5);
+
+        for (row, center) in list_centers.iter().enumerate() {
+            assert_eq!(graph.row_center(row), *center);
+        }
+        assert_eq!(graph.scroll_offset.1, 812.5);
+    }
+
+    #[test]
+    fn test_unsynced_rows_do_not_drift_at_fractional_zoom() {
+        let commits = linear_history(10);
+        let mut graph = CommitGraph::new();
+        graph.zoom_level = 0.73;
+        graph.desired_width(&commits);
+
+        let row_height = graph.row_center(1) - graph.row_center(0);
+        let far = 5000;
+        let expected = far as f32 * row_height + graph.row_center(0);
+
+        assert!((graph.row_center(far) - expected).abs() < 0.05);
+    }
+
+    fn label(name: &str, kind: RefKind) -> RefLabel {
+        RefLabel {
+            name: name.to_string(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_tip_name_prefers_local_branches() {
+        let labels = vec![
+            label("HEAD", RefKind::Head),
+            label("v1.0", RefKind::Tag),
+            label("origin/main", RefKind::RemoteBranch),
+            label("main", RefKind::LocalBranch),
+        ];
+
+        assert_eq!(tip_name(&labels), Some("main"));
+        assert_eq!(tip_name(&labels[..3]), Some("origin/main"));
+        assert_eq!(tip_name(&labels[..2]), Some("v1.0"));
+        assert_eq!(tip_name(&labels[..1]), None);
+    }
+
+    #[test]
+    fn test_ref_color_is_stable() {
+        assert_eq!(ref_color("main"), ref_color("main"));
+        assert_eq!(ref_color("feature/login"), ref_color(&String::from("feature/login")));
+    }
+
+    #[test]
+    fn test_branch_color_follows_its_lane() {
+        // 4 merges 3 (main) and 2 (feature); both come from 1.
+        let commits = vec![
+            make_commit(4, &[3, 2]),
+            make_commit(3, &[1]),
+            make_commit(2, &[1]),
+            make_commit(1, &[]),
+        ];
+        let layout = compute_layout(&commits);
+        let tips = HashMap::from([
+            (commit_id(4), "main".to_string()),
+            (commit_id(2), "feature".to_string()),
+        ]);
+
+        let refs = row_refs(&layout, &commits, &tips);
+
+        assert_eq!(
+            refs,
+            vec![
+                Some("main".to_string()),
+                Some("main".to_string()),
+                Some("feature".to_string()),
+                Some("main".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_untipped_rows_use_lane_colors() {
+        let commits = linear_history(3);
+        let mut graph = CommitGraph::new();
+        graph.desired_width(&commits);
+        let lane_color = graph.row_color(0);
+
+        let labels = HashMap::from([(commits[0].id, vec![label("main", RefKind::LocalBranch)])]);
+        graph.set_ref_labels(&labels);
+        graph.desired_width(&commits);
+
+        assert!((0..commits.len()).all(|row| graph.row_color(row) == ref_color("main")));
+        assert_eq!(CommitGraph::new().row_color(0), lane_color);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod commit_list_scroll_tests {
+    use twiggy::ui::components::commit_list::scroll_offset_to_reveal;
+
+    const ROW: f32 = 60.0;
+    const VIEWPORT: f32 = 300.0;
+    const CONTENT: f32 = 100.0 * ROW;
+
+    #[test]
+    fn test_visible_row_keeps_offset() {
+        assert_eq!(scroll_offset_to_reveal(600.0, VIEWPORT, 660.0, ROW, CONTENT), 600.0);
+    }
+
+    #[test]
+    fn test_row_below_viewport_scrolls_just_enough() {
+        assert_eq!(scroll_offset_to_reveal(0.0, VIEWPORT, 300.0, ROW, CONTENT), 60.0);
+    }
+
+    #[test]
+    fn test_row_above_viewport_aligns_to_top() {
+        assert_eq!(scroll_offset_to_reveal(900.0, VIEWPORT, 420.0, ROW, CONTENT), 420.0);
+    }
+
+    #[test]
+    fn test_first_and_last_selection_stay_in_bounds() {
+        let max_offset = CONTENT - VIEWPORT;
+
+        let first = scroll_offset_to_reveal(2400.0, VIEWPORT, 0.0, ROW, CONTENT);
+        assert_eq!(first, 0.0);
+
+        let last = scroll_offset_to_reveal(0.0, VIEWPORT, CONTENT - ROW, ROW, CONTENT);
+        assert_eq!(last, max_offset);
+
+        let oversized_row = scroll_offset_to_reveal(0.0, VIEWPORT, CONTENT - ROW, VIEWPORT * 2.0, CONTENT);
+        assert!((0.0..=max_offset).contains(&oversized_row));
+    }
+
+    #[test]
+    fn test_short_content_never_scrolls() {
+        assert_eq!(scroll_offset_to_reveal(0.0, VIEWPORT, 120.0, ROW, 180.0), 0.0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,81 @@
+mod common;
+
+#[cfg(test)]
+mod commit_prefix_tests {
+    use git2::{Repository, Signature};
+    use twiggy::error::TwiggyError;
+    use twiggy::git::repository::GitRepository;
+    use twiggy::git::types::{CommitId, CommitMatch};
+    use crate::common::commit_index_on;
+
+    #[test]
+    fn test_unique_prefix_resolves_to_commit() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        let root = commit_index_on(&raw, &[], "Root");
+        let tip = commit_index_on(&raw, &[root], "Tip");
+
+        let repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+        let prefix = tip.to_string()[..10].to_uppercase();
+
+        match repo.find_commit_by_prefix(&prefix).expect("Lookup failed") {
+            CommitMatch::Unique(commit) => {
+                assert_eq!(commit.id, CommitId(tip));
+                assert_eq!(commit.summary, "Tip");
+            }
+            other => panic!("Expected a unique match, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_unknown_prefix_is_not_found() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        let root = commit_index_on(&raw, &[], "Root");
+
+        let repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+        let first = root.to_string().chars().next().unwrap();
+        let other = if first == '0' { "1111111" } else { "0000000" };
+
+        assert!(matches!(repo.find_commit_by_prefix(other), Ok(CommitMatch::NotFound)));
+    }
+
+    #[test]
+    fn test_too_short_or_non_hex_prefix_is_rejected() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        let root = commit_index_on(&raw, &[], "Root");
+
+        let repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+
+        let short = &root.to_string()[..6];
+        assert!(matches!(repo.find_commit_by_prefix(short), Err(TwiggyError::Validation { .. })));
+        assert!(matches!(repo.find_commit_by_prefix("main-branch"), Err(TwiggyError::Validation { .. })));
+    }
+    #[test]
+    fn test_reference_resolves_branches_tags_and_revisions() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        let root = commit_index_on(&raw, &[], "Root");
+        let tip = commit_index_on(&raw, &[root], "Tip");
+
+        let root_commit = raw.find_commit(root).unwrap();
+        raw.branch("feature", &root_commit, false).expect("Failed to create branch");
+        let signature = Signature::now("Test User", "test@example.com").unwrap();
+        raw.tag("v1.0", root_commit.as_object(), &signature, "Release", false).expect("Failed to create tag");
+
+        let repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+        let resolve = |reference: &str| match repo.resolve_commit_reference(reference).expect("Lookup failed") {
+            CommitMatch::Unique(commit) => Some(commit.id),
+            CommitMatch::Ambiguous(_) => panic!("Unexpected ambiguous match for {}", reference),
+            CommitMatch::NotFound => None,
+        };
+
+        assert_eq!(resolve("feature"), Some(CommitId(root)));
+        assert_eq!(resolve("v1.0"), Some(CommitId(root)));
+        assert_eq!(resolve("HEAD~1"), Some(CommitId(root)));
+        assert_eq!(resolve(&tip.to_string()[..8]), Some(CommitId(tip)));
+        assert_eq!(resolve("no-such-branch"), None);
+        assert_eq!(resolve("   "), None);
+    }
+}
\ No newline at end of file
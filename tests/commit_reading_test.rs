@@ -1,8 +1,9 @@
 #[cfg(test)]
 mod commit_reading_tests {
     use std::path::PathBuf;
+    use std::sync::atomic::{AtomicBool, Ordering};
     use twiggy::git::repository::GitRepository;
-    use twiggy::git::types::{Commit, CommitId};
+    use twiggy::git::types::{CommitId, RefKind};
 
     fn get_test_repo_path() -> Option<PathBuf> {
         let current_dir = std::env::current_dir().ok()?;
@@ -164,4 +165,175 @@ mod commit_reading_tests {
         
         println!("Empty repository handling works correctly");
     }
+
+    #[test]
+    fn test_empty_repository_has_no_history() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let repo_path = temp_dir.path();
+        
+        let raw = git2::Repository::init(repo_path).expect("Failed to initialize empty repository");
+        
+        let repo = GitRepository::open(repo_path)
+            .expect("Failed to open empty repository");
+        assert!(repo.is_empty().unwrap());
+        assert!(!repo.has_history(), "Empty repository should have no history");
+        
+        let signature = git2::Signature::now("Test User", "test@example.com").unwrap();
+        let tree_id = raw.index().unwrap().write_tree().unwrap();
+        let tree = raw.find_tree(tree_id).unwrap();
+        raw.commit(Some("HEAD"), &signature, &signature, "Initial", &tree, &[]).unwrap();
+        
+        let repo = GitRepository::open(repo_path)
+            .expect("Failed to reopen repository");
+        assert!(repo.has_history(), "Repository with a commit should have history");
+    }
+
+    #[test]
+    fn test_ref_labels_include_head() {
+        let repo_path = match get_test_repo_path() {
+            Some(path) => path,
+            None => {
+                println!("Skipping test: No git repository found in current directory");
+                return;
+            }
+        };
+
+        let mut repo = GitRepository::open(&repo_path)
+            .expect("Failed to open test repository");
+
+        repo.load_commits(Some(1)).expect("Failed to load commits");
+        let head_commit = repo.get_commits()[0].id;
+
+        let labels = repo.refs_at(&head_commit).expect("Failed to read ref labels");
+        assert!(
+            labels.iter().any(|label| label.kind == RefKind::Head),
+            "HEAD commit should carry a HEAD label"
+        );
+        assert_eq!(labels[0].kind, RefKind::Head, "HEAD label should sort first");
+    }
+
+    #[test]
+    fn test_resolve_revspec() {
+        let repo_path = match get_test_repo_path() {
+            Some(path) => path,
+            None => {
+                println!("Skipping test: No git repository found in current directory");
+                return;
+            }
+        };
+
+        let mut repo = GitRepository::open(&repo_path)
+            .expect("Failed to open test repository");
+
+        repo.load_commits(Some(1)).expect("Failed to load commits");
+        let head_commit = repo.get_commits()[0].id;
+
+        assert_eq!(repo.resolve_revspec("HEAD").expect("Failed to resolve HEAD"), head_commit);
+        assert_eq!(repo.resolve_revspec(&head_commit.short()).expect("Failed to resolve short hash"), head_commit);
+        assert!(repo.resolve_revspec("definitely-not-a-ref").is_err());
+    }
+
+    #[test]
+    fn test_load_commits_reports_progress() {
+        let temp_dir = linear_history(3);
+        let mut repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+
+        let mut reports = Vec::new();
+        repo.load_commits_with_progress(Some(5), |loaded, total| reports.push((loaded, total)))
+            .expect("Failed to load commits");
+
+        assert_eq!(reports.first(), Some(&(0, None)));
+        assert_eq!(reports.get(1), Some(&(0, Some(3))));
+        assert_eq!(reports.last(), Some(&(3, Some(3))));
+        assert!(reports.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+    }
+
+    fn linear_history(count: usize) -> tempfile::TempDir {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = git2::Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+        let signature = git2::Signature::now("Test User", "test@example.com").unwrap();
+        let tree_id = raw.index().unwrap().write_tree().unwrap();
+        let tree = raw.find_tree(tree_id).unwrap();
+        let mut parent: Option<git2::Oid> = None;
+        for n in 0..count {
+            let parents: Vec<git2::Commit> = parent.iter().map(|id| raw.find_commit(*id).unwrap()).collect();
+            let parents: Vec<&git2::Commit> = parents.iter().collect();
+            parent = Some(raw.commit(Some("HEAD"), &signature, &signature, &format!("Commit {}", n), &tree, &parents).unwrap());
+        }
+        temp_dir
+    }
+
+    #[test]
+    fn test_parallel_parsing_matches_serial() {
+        let temp_dir = linear_history(300);
+
+        let mut serial = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let start_time = std::time::Instant::now();
+        serial.load_commits(Some(1000)).expect("Failed to load commits serially");
+        let serial_elapsed = start_time.elapsed();
+
+        let mut parallel = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        parallel.set_parse_threads(2);
+        let mut reports = Vec::new();
+        let start_time = std::time::Instant::now();
+        parallel.load_commits_with_progress(Some(1000), |loaded, _| reports.push(loaded))
+            .expect("Failed to load commits in parallel");
+        let parallel_elapsed = start_time.elapsed();
+
+        println!("Loaded 300 commits: serial {:?}, parallel {:?}", serial_elapsed, parallel_elapsed);
+
+        let serial_ids: Vec<CommitId> = serial.get_commits().iter().map(|commit| commit.id).collect();
+        let parallel_ids: Vec<CommitId> = parallel.get_commits().iter().map(|commit| commit.id).collect();
+        assert_eq!(serial_ids.len(), 300);
+        assert_eq!(parallel_ids, serial_ids, "Parallel parsing should keep revwalk order");
+        assert_eq!(parallel.get_commits()[0].summary, "Commit 299");
+        assert!(reports.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(reports.last(), Some(&300));
+    }
+
+    #[test]
+    fn test_cancelled_load_keeps_partial_results() {
+        let temp_dir = linear_history(300);
+        let cancel = AtomicBool::new(false);
+
+        let mut repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        repo.load_commits_cancellable(Some(1000), &cancel, |loaded, _| {
+            if loaded >= 100 {
+                cancel.store(true, Ordering::Relaxed);
+            }
+        })
+        .expect("Cancelled load should not be an error");
+
+        assert_eq!(repo.commit_count(), 100);
+        assert_eq!(repo.get_commits()[0].summary, "Commit 299");
+        assert_eq!(repo.cache_size(), 100);
+
+        let cancelled = AtomicBool::new(true);
+        repo.load_commits_cancellable(Some(1000), &cancelled, |_, _| {})
+            .expect("Cancelled load should not be an error");
+        assert_eq!(repo.commit_count(), 0);
+    }
+
+    #[test]
+    fn test_load_more_commits_appends_pages() {
+        let temp_dir = linear_history(250);
+
+        let mut full = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        full.load_commits(Some(1000)).expect("Failed to load commits");
+        assert!(!full.has_more_commits());
+
+        let mut repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        repo.load_commits(Some(100)).expect("Failed to load first page");
+        assert!(repo.has_more_commits());
+
+        assert_eq!(repo.load_more_commits(100).expect("Failed to load second page"), 100);
+        assert!(repo.has_more_commits());
+        assert_eq!(repo.load_more_commits(100).expect("Failed to load last page"), 50);
+        assert!(!repo.has_more_commits());
+        assert_eq!(repo.load_more_commits(100).expect("Loading past the end should be a no-op"), 0);
+
+        let paged: Vec<CommitId> = repo.get_commits().iter().map(|commit| commit.id).collect();
+        let expected: Vec<CommitId> = full.get_commits().iter().map(|commit| commit.id).collect();
+        assert_eq!(paged, expected);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod commit_signature_tests {
+    use git2::{Oid, Repository, Signature};
+    use twiggy::git::repository::GitRepository;
+    use twiggy::git::types::{CommitId, SignatureStatus};
+
+    const PGP_SIGNATURE: &str = "-----BEGIN PGP SIGNATURE-----\n\niQEzBAABCAAdFiEE\n-----END PGP SIGNATURE-----";
+
+    fn signed_commit(repo: &Repository, signature_text: &str, message: &str) -> Oid {
+        let signature = Signature::now("Test User", "test@example.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let buffer = repo.commit_create_buffer(&signature, &signature, message, &tree, &[]).unwrap();
+
+        repo.commit_signed(buffer.as_str().unwrap(), signature_text, None)
+            .expect("Failed to create signed commit")
+    }
+
+    fn unsigned_commit(repo: &Repository) -> Oid {
+        let signature = Signature::now("Test User", "test@example.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+
+        repo.commit(Some("HEAD"), &signature, &signature, "Unsigned", &tree, &[])
+            .expect("Failed to create commit")
+    }
+
+    #[test]
+    fn test_signature_status() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let unsigned = unsigned_commit(&raw);
+        let signed = signed_commit(&raw, PGP_SIGNATURE, "Signed");
+        let garbled = signed_commit(&raw, "not a signature", "Garbled");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+
+        assert_eq!(repo.commit_signature_status(&CommitId(unsigned)).unwrap(), SignatureStatus::Unsigned);
+        assert_eq!(repo.commit_signature_status(&CommitId(signed)).unwrap(), SignatureStatus::Valid);
+        assert_eq!(repo.commit_signature_status(&CommitId(garbled)).unwrap(), SignatureStatus::Invalid);
+    }
+
+    #[test]
+    fn test_signature_status_of_missing_commit_is_an_error() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+        unsigned_commit(&raw);
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let missing = CommitId(Oid::from_bytes(&[7; 20]).unwrap());
+
+        assert!(repo.commit_signature_status(&missing).is_err());
+    }
+}
\ No newline at end of file
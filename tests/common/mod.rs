@@ -0,0 +1,102 @@
+//! Fixtures shared by the integration tests. Each test file uses only some
+//! of them.
+#![allow(dead_code)]
+
+use chrono::Utc;
+use git2::{Oid, Repository};
+use std::path::Path;
+use twiggy::git::types::{Commit, CommitId, Signature};
+
+/// A fake commit id made of the byte `n` repeated.
+pub fn commit_id(n: u8) -> CommitId {
+    CommitId(Oid::from_bytes(&[n; 20]).expect("Failed to build oid"))
+}
+
+/// An in-memory commit `n` by "Test User" with the message "Commit n".
+pub fn make_commit(n: u8, parents: &[u8]) -> Commit {
+    make_commit_by(n, parents, "Test User", &format!("Commit {}", n))
+}
+
+pub fn make_commit_by(n: u8, parents: &[u8], author: &str, message: &str) -> Commit {
+    let signature = Signature {
+        name: author.to_string(),
+        email: format!("{}@example.com", author.to_lowercase().replace(' ', ".")),
+        time: Utc::now(),
+    };
+
+    Commit {
+        id: commit_id(n),
+        author: signature.clone(),
+        committer: signature,
+        message: message.to_string(),
+        summary: message.lines().next().unwrap_or("").to_string(),
+        parents: parents.iter().map(|p| commit_id(*p)).collect(),
+        tree_id: String::new(),
+    }
+}
+
+/// Writes `name` into the work tree and commits it on top of HEAD.
+pub fn commit_file(repo: &Repository, name: &str, contents: impl AsRef<[u8]>, message: &str) -> Oid {
+    commit_file_as(repo, name, contents, message, "Test User")
+}
+
+pub fn commit_file_as(repo: &Repository, name: &str, contents: impl AsRef<[u8]>, message: &str, author: &str) -> Oid {
+    write_and_commit(repo, &[(name, contents.as_ref())], message, author)
+}
+
+/// Like `commit_file` for several files, creating their directories.
+pub fn commit_files(repo: &Repository, files: &[(&str, &str)], message: &str) -> Oid {
+    let files: Vec<(&str, &[u8])> = files.iter().map(|(name, contents)| (*name, contents.as_bytes())).collect();
+    write_and_commit(repo, &files, message, "Test User")
+}
+
+fn write_and_commit(repo: &Repository, files: &[(&str, &[u8])], message: &str, author: &str) -> Oid {
+    let workdir = repo.workdir().expect("Repository should have a workdir");
+    let mut index = repo.index().expect("Failed to open index");
+
+    for (name, contents) in files {
+        let path = workdir.join(name);
+        std::fs::create_dir_all(path.parent().expect("File should have a parent"))
+            .expect("Failed to create directories");
+        std::fs::write(&path, contents).expect("Failed to write file");
+        index.add_path(Path::new(name)).expect("Failed to stage file");
+    }
+
+    index.write().expect("Failed to write index");
+    let signature = git2::Signature::now(author, "test@example.com").expect("Failed to create signature");
+    commit_index_as(repo, None, &signature, message)
+}
+
+/// Commits the index as it stands on top of HEAD, leaving the work tree
+/// alone. In a fresh repository that is an empty tree.
+pub fn commit_index(repo: &Repository, message: &str) -> Oid {
+    let signature = git2::Signature::now("Test User", "test@example.com").expect("Failed to create signature");
+    commit_index_as(repo, None, &signature, message)
+}
+
+/// Like `commit_index` with explicit parents.
+pub fn commit_index_on(repo: &Repository, parents: &[Oid], message: &str) -> Oid {
+    let signature = git2::Signature::now("Test User", "test@example.com").expect("Failed to create signature");
+    commit_index_as(repo, Some(parents), &signature, message)
+}
+
+/// Commits the index as `signature`; `parents` of `None` means HEAD, if
+/// there is one.
+pub fn commit_index_as(repo: &Repository, parents: Option<&[Oid]>, signature: &git2::Signature, message: &str) -> Oid {
+    let tree_id = repo.index().expect("Failed to open index").write_tree().expect("Failed to write tree");
+    let tree = repo.find_tree(tree_id).expect("Failed to find tree");
+
+    let parents: Vec<git2::Commit> = match parents {
+        Some(parents) => parents.iter().map(|id| repo.find_commit(*id).expect("Failed to find parent")).collect(),
+        None => repo.head().ok().and_then(|head| head.peel_to_commit().ok()).into_iter().collect(),
+    };
+    let parents: Vec<&git2::Commit> = parents.iter().collect();
+
+    repo.commit(Some("HEAD"), signature, signature, message, &tree, &parents)
+        .expect("Failed to create commit")
+}
+
+/// A signature at a fixed time, for tests that depend on commit dates.
+pub fn signature_at(name: &str, email: &str, seconds: i64) -> git2::Signature<'static> {
+    git2::Signature::new(name, email, &git2::Time::new(seconds, 0)).expect("Failed to create signature")
+}
\ No newline at end of file
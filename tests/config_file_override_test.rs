@@ -0,0 +1,23 @@
+// The override is process-wide, so it gets a test binary of its own.
+#[cfg(test)]
+mod config_file_override_tests {
+    use twiggy::config::AppConfig;
+
+    #[test]
+    fn test_load_and_save_use_overridden_config_file() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let path = dir.path().join("session.json");
+        assert!(AppConfig::use_config_file(path.clone()));
+        assert!(!AppConfig::use_config_file(dir.path().join("other.json")));
+
+        let mut config = AppConfig::load().expect("Failed to load config");
+        assert!(path.exists(), "A missing override file should be created with defaults");
+
+        config.theme.font_size = 21.0;
+        config.save().expect("Failed to save config");
+
+        let reloaded = AppConfig::load().expect("Failed to reload config");
+        assert_eq!(reloaded.theme.font_size, 21.0);
+        assert!(!dir.path().join("other.json").exists());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,448 @@
+#[cfg(test)]
+mod config_tests {
+    use eframe::egui;
+    use std::path::{Component, Path, PathBuf};
+    use twiggy::config::{parse_hex_color, AppConfig, CustomTheme, KeybindAction, LogLevel, LoggingConfig, RecentRepositories, RepoOverrides, CONFIG_VERSION, DEFAULT_ACCENT_COLOR, MAX_UI_SCALE, MIN_UI_SCALE};
+    use twiggy::git::types::DiffAlgorithm;
+
+    fn relative_to_current_dir(path: &Path) -> PathBuf {
+        let current_dir = std::env::current_dir().expect("Failed to read current directory");
+        let mut relative = PathBuf::new();
+
+        for _ in current_dir.components().filter(|c| matches!(c, Component::Normal(_))) {
+            relative.push("..");
+        }
+        for component in path.components().filter(|c| matches!(c, Component::Normal(_))) {
+            relative.push(component);
+        }
+
+        relative
+    }
+
+    #[test]
+    fn test_recent_repositories_dedup_equivalent_paths() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let repo_dir = temp_dir.path().join("repo");
+        std::fs::create_dir(&repo_dir).expect("Failed to create repo directory");
+
+        let mut recent = RecentRepositories::default();
+        recent.add_repository(repo_dir.clone(), "repo".to_string());
+        recent.add_repository(PathBuf::from(format!("{}/", repo_dir.display())), "repo".to_string());
+        recent.add_repository(temp_dir.path().join("repo").join("..").join("repo"), "repo".to_string());
+        recent.add_repository(relative_to_current_dir(&repo_dir.canonicalize().unwrap()), "repo".to_string());
+
+        assert_eq!(recent.repositories.len(), 1, "Equivalent paths should collapse to one entry");
+    }
+
+    #[test]
+    fn test_recent_repositories_missing_path_falls_back_to_raw() {
+        let mut recent = RecentRepositories::default();
+        let missing = PathBuf::from("/definitely/not/a/real/twiggy/repo");
+
+        recent.add_repository(missing.clone(), "missing".to_string());
+        recent.add_repository(missing.clone(), "missing".to_string());
+
+        assert_eq!(recent.repositories.len(), 1);
+        assert_eq!(recent.repositories[0].path, missing);
+
+        recent.remove_repository(&missing);
+        assert!(recent.repositories.is_empty());
+    }
+
+    #[test]
+    fn test_recent_repositories_max_count() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let mut recent = RecentRepositories::default();
+
+        for i in 0..5 {
+            let dir = temp_dir.path().join(format!("repo{}", i));
+            std::fs::create_dir(&dir).expect("Failed to create repo directory");
+            recent.add_repository(dir, format!("repo{}", i));
+        }
+
+        recent.set_max_count(3);
+        assert_eq!(recent.repositories.len(), 3);
+        assert_eq!(recent.repositories[0].name, "repo4", "Most recent entry should be kept first");
+    }
+
+    #[test]
+    fn test_pinned_recent_repositories_survive_truncation() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let mut recent = RecentRepositories::default();
+        recent.set_max_count(3);
+
+        let pinned = temp_dir.path().join("pinned");
+        std::fs::create_dir(&pinned).expect("Failed to create repo directory");
+        recent.add_repository(pinned.clone(), "pinned".to_string());
+        recent.set_pinned(&pinned, true);
+
+        for i in 0..5 {
+            let dir = temp_dir.path().join(format!("repo{}", i));
+            std::fs::create_dir(&dir).expect("Failed to create repo directory");
+            recent.add_repository(dir, format!("repo{}", i));
+        }
+
+        let names: Vec<&str> = recent.repositories.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["pinned", "repo4", "repo3", "repo2"]);
+
+        recent.add_repository(pinned.clone(), "pinned".to_string());
+        assert!(recent.is_pinned(&pinned), "Reopening a pinned repository should keep it pinned");
+
+        recent.set_pinned(&pinned, false);
+        assert_eq!(recent.repositories.len(), 3);
+        assert_eq!(recent.repositories[0].name, "pinned");
+        assert!(!recent.is_pinned(&pinned));
+    }
+
+    #[test]
+    fn test_validate_and_clean_removes_missing_paths() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let mut recent = RecentRepositories::default();
+
+        let kept = temp_dir.path().join("kept");
+        std::fs::create_dir(&kept).expect("Failed to create repo directory");
+        let deleted = temp_dir.path().join("deleted");
+        std::fs::create_dir(&deleted).expect("Failed to create repo directory");
+        recent.add_repository(kept, "kept".to_string());
+        recent.add_repository(deleted.clone(), "deleted".to_string());
+        recent.add_repository(temp_dir.path().join("never-existed"), "never-existed".to_string());
+        std::fs::remove_dir(&deleted).expect("Failed to remove repo directory");
+
+        assert_eq!(recent.validate_and_clean(), 2);
+        let names: Vec<&str> = recent.repositories.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["kept"]);
+        assert_eq!(recent.validate_and_clean(), 0);
+    }
+
+    #[test]
+    fn test_recent_repositories_max_count_validation() {
+        let mut config = AppConfig::default();
+        assert!(config.validate().is_ok());
+
+        config.recent_repositories.max_count = 0;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_diff_algorithm_round_trip() {
+        let mut config = AppConfig::default();
+        assert_eq!(config.git.diff_algorithm, DiffAlgorithm::Myers);
+
+        config.git.diff_algorithm = DiffAlgorithm::Patience;
+        let json = serde_json::to_string(&config).expect("Failed to serialize config");
+        let loaded: AppConfig = serde_json::from_str(&json).expect("Failed to deserialize config");
+
+        assert_eq!(loaded.git.diff_algorithm, DiffAlgorithm::Patience);
+    }
+
+    #[test]
+    fn test_unknown_diff_algorithm_falls_back_to_myers() {
+        let algorithm: DiffAlgorithm = serde_json::from_str("\"histogram\"").expect("Unknown names should not fail");
+        assert_eq!(algorithm, DiffAlgorithm::Myers);
+
+        let algorithm: DiffAlgorithm = serde_json::from_str("\"minimal\"").expect("Lowercase names should parse");
+        assert_eq!(algorithm, DiffAlgorithm::Minimal);
+    }
+    #[test]
+    fn test_export_import_round_trip() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let path = temp_dir.path().join("settings.json");
+
+        let mut config = AppConfig::default();
+        config.git.diff_algorithm = DiffAlgorithm::Minimal;
+        config.theme.font_size = 18.0;
+        config.export_to(&path).expect("Failed to export config");
+
+        let imported = AppConfig::import_from(&path).expect("Failed to import config");
+        assert_eq!(imported.git.diff_algorithm, DiffAlgorithm::Minimal);
+        assert_eq!(imported.theme.font_size, 18.0);
+    }
+
+    #[test]
+    fn test_import_rejects_invalid_config() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let path = temp_dir.path().join("settings.json");
+
+        let mut config = AppConfig::default();
+        config.theme.font_size = 100.0;
+        config.export_to(&path).expect("Failed to export config");
+        assert!(AppConfig::import_from(&path).is_err());
+
+        std::fs::write(&path, "{ not json").expect("Failed to write file");
+        assert!(AppConfig::import_from(&path).is_err());
+    }
+    #[test]
+    fn test_interrupted_write_keeps_previous_config() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let path = temp_dir.path().join("config.json");
+
+        let mut config = AppConfig::default();
+        config.theme.font_size = 20.0;
+        config.export_to(&path).expect("Failed to write config");
+        assert!(!temp_dir.path().join("config.json.tmp").exists());
+
+        // A write killed halfway only ever touches the temporary file.
+        std::fs::write(temp_dir.path().join("config.json.tmp"), "{\"window\": {\"wid")
+            .expect("Failed to write partial file");
+
+        let loaded = AppConfig::import_from(&path).expect("Previous config should still load");
+        assert_eq!(loaded.theme.font_size, 20.0);
+
+        config.theme.font_size = 22.0;
+        config.export_to(&path).expect("Writing over a stale temp file should succeed");
+        let loaded = AppConfig::import_from(&path).expect("Failed to load config");
+        assert_eq!(loaded.theme.font_size, 22.0);
+    }
+    #[test]
+    fn test_repo_overrides_merge_known_fields() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        std::fs::write(
+            temp_dir.path().join(".twiggy.json"),
+            r#"{"git": {"max_commits": 250, "show_stashes": true, "no_such_field": 1}, "ui": {"hide_merge_commits": true}, "theme": {"font_size": 30.0}}"#,
+        ).expect("Failed to write repository config");
+
+        let global = AppConfig::default();
+        let merged = global.with_repo_overrides(temp_dir.path());
+
+        assert_eq!(merged.git.max_commits, 250);
+        assert!(merged.git.show_stashes);
+        assert!(merged.ui.hide_merge_commits);
+        assert_eq!(merged.theme.font_size, global.theme.font_size, "Only git and ui can be overridden");
+        assert_eq!(merged.git.default_branch, global.git.default_branch);
+        assert_eq!(global.git.max_commits, 1000, "The global config must not change");
+    }
+
+    #[test]
+    fn test_repo_overrides_missing_or_malformed() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let global = AppConfig::default();
+
+        assert!(RepoOverrides::load(temp_dir.path()).expect("Missing file is not an error").is_none());
+        assert_eq!(global.with_repo_overrides(temp_dir.path()).git.max_commits, 1000);
+
+        std::fs::write(temp_dir.path().join(".twiggy.json"), "{ max_commits: ").expect("Failed to write file");
+        assert!(RepoOverrides::load(temp_dir.path()).is_err());
+        assert_eq!(global.with_repo_overrides(temp_dir.path()).git.max_commits, 1000);
+
+        let wrong_type = RepoOverrides::from_json(r#"{"git": {"max_commits": "lots"}}"#).expect("Valid JSON");
+        assert!(global.with_overrides(&wrong_type).is_err());
+    }
+    #[test]
+    fn test_minimal_v1_config_gets_v2_defaults() {
+        let json = r#"{"version": 1, "git": {"max_commits": 500, "show_stashes": true}, "theme": {"font_size": 16.0}}"#;
+
+        let (config, migrated) = AppConfig::parse_and_migrate(json).expect("v1 config should load");
+
+        assert!(migrated);
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.git.max_commits, 500);
+        assert!(config.git.show_stashes);
+        assert_eq!(config.theme.font_size, 16.0);
+        assert_eq!(config.git.default_branch, "main");
+        assert_eq!(config.keybinds.get(KeybindAction::OpenRepository), "Ctrl+O");
+        assert_eq!(config.theme.ui_scale, 1.0);
+        assert!(config.git.auto_open_cwd_repo);
+        assert!(config.git.reopen_last_on_start);
+        assert_eq!(config.last_opened_repository, None);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_config_without_version_is_migrated_from_v1() {
+        let json = r#"{"git": {"max_commits": 500}, "keybinds": {"settings": "Ctrl+S"}}"#;
+
+        let (config, migrated) = AppConfig::parse_and_migrate(json).expect("Unversioned config should load");
+
+        assert!(migrated);
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.git.max_commits, 500);
+        assert_eq!(config.keybinds.get(KeybindAction::Settings), AppConfig::default().keybinds.get(KeybindAction::Settings));
+    }
+
+    #[test]
+    fn test_custom_themes_default_and_round_trip() {
+        let config = AppConfig::default();
+        assert_eq!(config.theme.custom_themes, vec![CustomTheme::high_contrast()]);
+        assert!(config.theme.active_custom_theme().is_none());
+
+        let mut config = config;
+        let mut solarized = CustomTheme::new("Solarized");
+        solarized.background = Some("#002B36".to_string());
+        config.theme.custom_themes.push(solarized.clone());
+        config.theme.custom_theme = Some("Solarized".to_string());
+
+        let json = serde_json::to_string(&config).expect("Failed to serialize config");
+        let (loaded, _) = AppConfig::parse_and_migrate(&json).expect("Failed to load config");
+        assert_eq!(loaded.theme.active_custom_theme(), Some(&solarized));
+    }
+
+    #[test]
+    fn test_custom_theme_names_must_be_unique_and_non_empty() {
+        let mut config = AppConfig::default();
+        config.theme.custom_themes.push(CustomTheme::high_contrast());
+        assert!(config.validate().is_err());
+
+        config.theme.custom_themes = vec![CustomTheme::new("  ")];
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_parse_hex_color() {
+        assert_eq!(parse_hex_color("#007ACC"), Ok(egui::Color32::from_rgb(0x00, 0x7A, 0xCC)));
+        assert_eq!(parse_hex_color("ff8000"), Ok(egui::Color32::from_rgb(0xFF, 0x80, 0x00)));
+        assert!(parse_hex_color("#GGG").is_err());
+        assert!(parse_hex_color("#GGGGGG").is_err());
+        assert!(parse_hex_color("#12345").is_err());
+        assert!(parse_hex_color("#1234567").is_err());
+        assert!(parse_hex_color("aé123").is_err());
+    }
+
+    #[test]
+    fn test_invalid_accent_color_fails_validation_and_resets() {
+        let mut config = AppConfig::default();
+        config.theme.accent_color = "#GGG".to_string();
+        assert!(config.validate().is_err());
+
+        assert!(config.theme.reset_invalid_accent_color());
+        assert_eq!(config.theme.accent_color, DEFAULT_ACCENT_COLOR);
+        assert!(config.validate().is_ok());
+        assert!(!config.theme.reset_invalid_accent_color());
+    }
+
+    #[test]
+    fn test_ui_scale_validation() {
+        let mut config = AppConfig::default();
+        config.theme.ui_scale = MAX_UI_SCALE;
+        assert!(config.validate().is_ok());
+
+        config.theme.ui_scale = MIN_UI_SCALE - 0.1;
+        assert!(config.validate().is_err());
+
+        config.theme.ui_scale = MAX_UI_SCALE + 0.1;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_full_v1_config_keeps_its_values() {
+        let mut v1 = AppConfig::default();
+        v1.ui.max_author_length = 12;
+        v1.recent_repositories.max_count = 3;
+        let mut json = serde_json::to_value(&v1).expect("Failed to serialize config");
+        json["version"] = serde_json::json!(1);
+        json.as_object_mut().expect("Config is an object").remove("keybinds");
+
+        let (config, migrated) = AppConfig::parse_and_migrate(&json.to_string()).expect("v1 config should load");
+
+        assert!(migrated);
+        assert_eq!(config.ui.max_author_length, 12);
+        assert_eq!(config.recent_repositories.max_count, 3);
+        assert_eq!(config.keybinds.shortcuts().len(), KeybindAction::ALL.len());
+
+        let (_, migrated_again) = AppConfig::parse_and_migrate(&serde_json::to_string(&config).expect("Failed to serialize"))
+            .expect("v2 config should load");
+        assert!(!migrated_again);
+    }
+
+    #[test]
+    fn test_v2_settings_shortcut_moves_off_ctrl_s() {
+        let mut v2 = AppConfig::default();
+        v2.keybinds.set(KeybindAction::Settings, "Ctrl+S".to_string());
+        v2.keybinds.set(KeybindAction::Quit, "Ctrl+Shift+Q".to_string());
+        let mut json = serde_json::to_value(&v2).expect("Failed to serialize config");
+        json["version"] = serde_json::json!(2);
+
+        let (config, migrated) = AppConfig::parse_and_migrate(&json.to_string()).expect("v2 config should load");
+
+        assert!(migrated);
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.keybinds.get(KeybindAction::Settings), "Ctrl+Alt+S");
+        assert_eq!(config.keybinds.get(KeybindAction::Quit), "Ctrl+Shift+Q");
+
+        let mut custom = serde_json::to_value(&v2).expect("Failed to serialize config");
+        custom["version"] = serde_json::json!(2);
+        custom["keybinds"]["settings"] = serde_json::json!("F2");
+
+        let (config, _) = AppConfig::parse_and_migrate(&custom.to_string()).expect("v2 config should load");
+        assert_eq!(config.keybinds.get(KeybindAction::Settings), "F2");
+    }
+    #[test]
+    fn test_logging_config_defaults() {
+        let logging = LoggingConfig::default();
+
+        assert_eq!(logging.level, LogLevel::Info);
+        assert!(logging.file_enabled);
+        assert_eq!(logging.max_file_size, 10 * 1024 * 1024);
+        assert_eq!(logging.max_files, 5);
+        assert!(logging.log_directory.is_none());
+    }
+
+    #[test]
+    fn test_full_config_round_trip() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let mut config = AppConfig::default();
+
+        config.logging.level = LogLevel::Debug;
+        config.logging.max_files = 8;
+        config.logging.log_directory = Some(temp_dir.path().display().to_string());
+        config.ui.menu_preferences.compact_menus = true;
+        config.ui.menu_preferences.show_menu_bar = false;
+        config.performance.enable_background_operations = false;
+        config.git.default_clone_path = temp_dir.path().display().to_string();
+        config.recent_repositories.add_repository(temp_dir.path().to_path_buf(), "repo".to_string());
+
+        let json = serde_json::to_string(&config).expect("Failed to serialize config");
+        let loaded: AppConfig = serde_json::from_str(&json).expect("Failed to deserialize config");
+
+        assert_eq!(loaded.logging.level, LogLevel::Debug);
+        assert_eq!(loaded.logging.max_files, 8);
+        assert_eq!(loaded.logging.log_directory, config.logging.log_directory);
+        assert!(loaded.ui.menu_preferences.compact_menus);
+        assert!(!loaded.ui.menu_preferences.show_menu_bar);
+        assert!(!loaded.performance.enable_background_operations);
+        assert_eq!(loaded.git.default_clone_path, config.git.default_clone_path);
+        assert_eq!(loaded.recent_repositories.repositories.len(), 1);
+        assert_eq!(loaded.recent_repositories.repositories[0].name, "repo");
+        assert!(loaded.validate().is_ok());
+    }
+
+    #[test]
+    fn test_default_config_has_no_differences() {
+        assert!(AppConfig::default().diff_from_default().is_empty());
+    }
+
+    #[test]
+    fn test_diff_from_default_lists_changed_fields() {
+        let mut config = AppConfig::default();
+        config.git.max_commits = 123;
+        config.keybinds.set(KeybindAction::Quit, "Ctrl+Shift+Q".to_string());
+        config.recent_repositories.add_repository(std::env::temp_dir(), "temp".to_string());
+        config.last_opened_repository = Some(std::env::temp_dir());
+        config.window.width += 200.0;
+        config.window.position_x = Some(40.0);
+        config.window.maximized = !config.window.maximized;
+
+        let diff = config.diff_from_default();
+        let fields: Vec<&str> = diff.iter().map(|(field, _, _)| field.as_str()).collect();
+
+        assert_eq!(fields.len(), 2);
+        assert!(fields.contains(&"git.max_commits"));
+        assert!(fields.contains(&"keybinds.quit"));
+
+        let (_, current, default) = diff.iter().find(|(field, _, _)| field == "git.max_commits").unwrap();
+        assert_eq!(current, "123");
+        assert_eq!(default, &AppConfig::default().git.max_commits.to_string());
+    }
+
+    #[test]
+    fn test_reset_field_restores_only_that_field() {
+        let mut config = AppConfig::default();
+        config.git.max_commits = 123;
+        config.logging.max_files = 9;
+
+        config.reset_field("git.max_commits").expect("Field should reset");
+
+        assert_eq!(config.git.max_commits, AppConfig::default().git.max_commits);
+        assert_eq!(config.logging.max_files, 9);
+        assert!(config.reset_field("git.no_such_field").is_err());
+    }
+}
\ No newline at end of file
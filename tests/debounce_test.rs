@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod debounce_tests {
+    use std::time::{Duration, Instant};
+    use twiggy::util::debounce::Debouncer;
+
+    const DELAY: Duration = Duration::from_millis(500);
+
+    #[test]
+    fn test_idle_debouncer_never_fires() {
+        let mut debouncer = Debouncer::new(DELAY);
+        let now = Instant::now();
+
+        assert!(!debouncer.is_pending());
+        assert!(!debouncer.ready(now + DELAY * 10));
+        assert!(!debouncer.flush());
+    }
+
+    #[test]
+    fn test_rapid_changes_coalesce_into_one_save() {
+        let mut debouncer = Debouncer::new(DELAY);
+        let start = Instant::now();
+        let mut saves = 0;
+
+        // A drag reporting a new size every 16ms for a second.
+        for frame in 0..=60 {
+            let now = start + Duration::from_millis(frame * 16);
+            debouncer.mark(now);
+            if debouncer.ready(now) {
+                saves += 1;
+            }
+        }
+
+        let drag_end = start + Duration::from_millis(60 * 16);
+        assert!(!debouncer.ready(drag_end + Duration::from_millis(499)));
+        assert_eq!(saves, 0);
+
+        if debouncer.ready(drag_end + DELAY) {
+            saves += 1;
+        }
+        assert_eq!(saves, 1);
+        assert!(!debouncer.is_pending());
+        assert!(!debouncer.ready(drag_end + DELAY * 2));
+    }
+
+    #[test]
+    fn test_remaining_counts_down_from_last_change() {
+        let mut debouncer = Debouncer::new(DELAY);
+        let now = Instant::now();
+
+        assert_eq!(debouncer.remaining(now), None);
+
+        debouncer.mark(now);
+        assert_eq!(debouncer.remaining(now + Duration::from_millis(200)), Some(Duration::from_millis(300)));
+        assert_eq!(debouncer.remaining(now + DELAY * 2), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_flush_consumes_pending_change_early() {
+        let mut debouncer = Debouncer::new(DELAY);
+        let now = Instant::now();
+
+        debouncer.mark(now);
+        assert!(debouncer.flush());
+        assert!(!debouncer.is_pending());
+        assert!(!debouncer.ready(now + DELAY));
+    }
+}
\ No newline at end of file
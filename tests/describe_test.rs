@@ -0,0 +1,75 @@
+mod common;
+
+#[cfg(test)]
+mod describe_tests {
+    use git2::{Oid, Repository};
+    use twiggy::git::repository::GitRepository;
+    use twiggy::git::types::CommitId;
+    use crate::common::commit_index;
+
+    fn tag(repo: &Repository, name: &str, id: Oid) {
+        let object = repo.find_object(id, None).unwrap();
+        repo.tag_lightweight(name, &object, false).expect("Failed to create tag");
+    }
+
+    #[test]
+    fn test_describe_counts_commits_past_tag() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        let tagged = commit_index(&raw, "Release");
+        tag(&raw, "v1.2.0", tagged);
+        commit_index(&raw, "Second");
+        let tip = commit_index(&raw, "Third");
+
+        let repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+
+        assert_eq!(repo.describe_commit(&CommitId(tagged)).unwrap(), "v1.2.0");
+        assert_eq!(
+            repo.describe_commit(&CommitId(tip)).unwrap(),
+            format!("v1.2.0-2-g{}", &tip.to_string()[..7])
+        );
+    }
+
+    #[test]
+    fn test_describe_falls_back_to_short_hash_without_tags() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        let id = commit_index(&raw, "Untagged");
+
+        let repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+
+        assert_eq!(repo.describe_commit(&CommitId(id)).unwrap(), CommitId(id).short());
+    }
+
+    #[test]
+    fn test_describe_is_cached_until_refresh() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        let id = commit_index(&raw, "Initial");
+
+        let mut repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+        let short = CommitId(id).short();
+        assert_eq!(repo.describe_commit(&CommitId(id)).unwrap(), short);
+
+        tag(&raw, "v0.1.0", id);
+        assert_eq!(repo.describe_commit(&CommitId(id)).unwrap(), short);
+
+        repo.refresh().expect("Failed to refresh");
+        assert_eq!(repo.describe_commit(&CommitId(id)).unwrap(), "v0.1.0");
+    }
+
+    #[test]
+    fn test_reopened_repository_drops_descriptions_when_tags_change() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        let id = commit_index(&raw, "Initial");
+
+        let old = GitRepository::open(dir.path()).expect("Failed to open repository");
+        assert_eq!(old.describe_commit(&CommitId(id)).unwrap(), CommitId(id).short());
+
+        tag(&raw, "v0.1.0", id);
+        let mut reopened = GitRepository::open(dir.path()).expect("Failed to open repository");
+        reopened.take_caches_from(old);
+        assert_eq!(reopened.describe_commit(&CommitId(id)).unwrap(), "v0.1.0");
+    }
+}
\ No newline at end of file
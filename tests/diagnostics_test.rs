@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod diagnostics_tests {
+    use std::path::PathBuf;
+    use twiggy::config::{AppConfig, LoggingConfig};
+    use twiggy::diagnostics::{log_tail, redact_paths, write_report, ErrorSummary};
+
+    fn summary() -> ErrorSummary {
+        ErrorSummary {
+            code: 1000,
+            message: "Git operation failed".to_string(),
+            details: "Git { operation: \"fetch\" }".to_string(),
+            recoverable: true,
+        }
+    }
+
+    fn report(config: &AppConfig, log_file: &std::path::Path) -> serde_json::Value {
+        let mut output = Vec::new();
+        write_report(&mut output, &summary(), config, log_file).expect("Failed to write report");
+        serde_json::from_slice(&output).expect("Report is not valid JSON")
+    }
+
+    #[test]
+    fn test_report_includes_error_version_and_log_tail() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let log_file = dir.path().join("twiggy.log");
+        let lines: Vec<String> = (0..250).map(|i| format!("line {}", i)).collect();
+        std::fs::write(&log_file, lines.join("\n")).unwrap();
+
+        let report = report(&AppConfig::default(), &log_file);
+
+        assert_eq!(report["app_version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(report["error"]["code"], 1000);
+        assert_eq!(report["error"]["message"], "Git operation failed");
+        assert_eq!(report["error"]["recoverable"], true);
+
+        let tail = report["log"]["tail"].as_array().expect("Missing log tail");
+        assert_eq!(tail.len(), 200);
+        assert_eq!(tail[0], "line 50");
+        assert_eq!(tail[199], "line 249");
+    }
+
+    #[test]
+    fn test_report_notes_missing_log_instead_of_failing() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+
+        let report = report(&AppConfig::default(), &dir.path().join("missing.log"));
+
+        assert!(report["log"]["tail"].is_null());
+        assert!(report["log"]["unavailable"].is_string());
+        assert_eq!(report["error"]["code"], 1000);
+    }
+
+    #[test]
+    fn test_report_redacts_paths_in_config() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let config = AppConfig {
+            last_opened_repository: Some(PathBuf::from("/home/jane/projects/secret")),
+            logging: LoggingConfig {
+                log_directory: Some("C:\\Users\\jane\\logs".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let report = report(&config, &dir.path().join("twiggy.log"));
+        let text = report.to_string();
+
+        assert!(!text.contains("jane"));
+        assert_eq!(report["config"]["last_opened_repository"], "<redacted>");
+        assert_eq!(report["config"]["logging"]["log_directory"], "<redacted>");
+    }
+
+    #[test]
+    fn test_redact_paths_leaves_other_values_alone() {
+        let mut value = serde_json::json!({
+            "theme": "Dark",
+            "nested": [{ "home": "~/repo" }, 42, true],
+        });
+
+        redact_paths(&mut value);
+
+        assert_eq!(value["theme"], "Dark");
+        assert_eq!(value["nested"][0]["home"], "<redacted>");
+        assert_eq!(value["nested"][1], 42);
+        assert_eq!(value["nested"][2], true);
+    }
+
+    #[test]
+    fn test_log_tail_reads_short_files_whole() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let log_file = dir.path().join("twiggy.log");
+        std::fs::write(&log_file, "first\nsecond\n").unwrap();
+
+        assert_eq!(log_tail(&log_file, 200).unwrap(), vec!["first", "second"]);
+    }
+}
\ No newline at end of file
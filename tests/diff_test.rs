@@ -0,0 +1,222 @@
+mod common;
+
+#[cfg(test)]
+mod diff_tests {
+    use git2::Repository;
+    use twiggy::git::repository::GitRepository;
+    use twiggy::git::types::{CommitId, DiffAlgorithm, DiffLineType, RenameKind};
+    use crate::common::commit_file;
+
+    #[test]
+    fn test_diff_commit_against_parent() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        commit_file(&raw, "a.txt", "one\ntwo\nthree\n", "First");
+        let second = commit_file(&raw, "a.txt", "one\nTWO\nthree\n", "Second");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let files = repo.diff_commit(&CommitId(second)).expect("Failed to diff commit");
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].new_file.as_deref(), Some("a.txt"));
+        assert_eq!(files[0].hunks.len(), 1);
+
+        let lines = &files[0].hunks[0].lines;
+        let additions: Vec<&str> = lines.iter()
+            .filter(|line| matches!(line.line_type, DiffLineType::Addition))
+            .map(|line| line.content.as_str())
+            .collect();
+        let deletions: Vec<&str> = lines.iter()
+            .filter(|line| matches!(line.line_type, DiffLineType::Deletion))
+            .map(|line| line.content.as_str())
+            .collect();
+
+        assert_eq!(additions, vec!["TWO"]);
+        assert_eq!(deletions, vec!["two"]);
+    }
+
+    #[test]
+    fn test_diff_root_commit() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let root = commit_file(&raw, "a.txt", "one\n", "First");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let files = repo.diff_commit(&CommitId(root)).expect("Failed to diff root commit");
+
+        assert_eq!(files.len(), 1);
+        assert!(files[0].hunks[0].lines.iter().all(|line| matches!(line.line_type, DiffLineType::Addition)));
+    }
+
+    #[test]
+    fn test_benchmark_reports_each_phase() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        commit_file(&raw, "a.txt", "one\n", "First");
+        commit_file(&raw, "a.txt", "two\n", "Second");
+
+        let report = twiggy::bench::run(temp_dir.path(), 10).expect("Benchmark should succeed");
+
+        assert_eq!(report.commit_count, 2);
+        assert_eq!(report.diff_files, 1);
+        assert!(report.total() >= report.open);
+        assert!(report.summary().contains("2 commits"));
+    }
+
+    #[test]
+    fn test_cached_diff_reuses_result() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        commit_file(&raw, "a.txt", "one\n", "First");
+        let second = commit_file(&raw, "a.txt", "one\ntwo\n", "Second");
+
+        let mut repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let id = CommitId(second);
+
+        let first_files = repo.cached_diff(&id).expect("Failed to diff commit").len();
+        let second_files = repo.cached_diff(&id).expect("Failed to diff commit").len();
+        assert_eq!(first_files, 1);
+        assert_eq!(second_files, 1);
+        assert_eq!(repo.diff_cache_size(), 1);
+
+        repo.set_diff_algorithm(DiffAlgorithm::Patience);
+        assert_eq!(repo.diff_cache_size(), 0);
+    }
+
+    #[test]
+    fn test_reopened_repository_takes_cached_diffs() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+        let id = CommitId(commit_file(&raw, "a.txt", "one\n", "First"));
+
+        let mut old = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        old.cached_diff(&id).expect("Failed to diff commit");
+
+        let mut reopened = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        reopened.take_caches_from(old);
+        assert_eq!(reopened.diff_cache_size(), 1);
+
+        let mut old = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        old.cached_diff(&id).expect("Failed to diff commit");
+
+        let mut reopened = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        reopened.set_diff_algorithm(DiffAlgorithm::Patience);
+        reopened.take_caches_from(old);
+        assert_eq!(reopened.diff_cache_size(), 0);
+    }
+
+    #[test]
+    fn test_commit_stats_counts_lines() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let root = commit_file(&raw, "a.txt", "one\ntwo\nthree\n", "First");
+        let second = commit_file(&raw, "a.txt", "one\nTWO\nthree\n", "Second");
+
+        let mut repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+
+        let stats = repo.commit_stats(&CommitId(second)).expect("Failed to compute stats");
+        assert_eq!((stats.files_changed, stats.insertions, stats.deletions), (1, 1, 1));
+        assert_eq!(stats.summary(), "1 file changed, +1 −1");
+
+        let root_stats = repo.cached_commit_stats(&CommitId(root)).expect("Failed to compute root stats");
+        assert_eq!((root_stats.files_changed, root_stats.insertions, root_stats.deletions), (1, 3, 0));
+    }
+
+    #[test]
+    fn test_search_diff_content_finds_added_and_removed() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        commit_file(&raw, "a.txt", "alpha\n", "First");
+        let added = commit_file(&raw, "a.txt", "alpha\nneedle\n", "Add needle");
+        commit_file(&raw, "b.txt", "unrelated\n", "Unrelated");
+        let moved = commit_file(&raw, "a.txt", "needle\nalpha\n", "Reorder lines");
+        let removed = commit_file(&raw, "a.txt", "alpha\n", "Remove needle");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let matches = repo.search_diff_content("needle", None).expect("Failed to search diffs");
+
+        assert_eq!(matches, vec![CommitId(removed), CommitId(added)]);
+        assert!(!matches.contains(&CommitId(moved)));
+
+        let limited = repo.search_diff_content("needle", Some(1)).expect("Failed to search diffs");
+        assert_eq!(limited, vec![CommitId(removed)]);
+
+        let cancelled = repo.search_diff_content_with_progress("needle", None, |_| false)
+            .expect("Failed to search diffs");
+        assert!(cancelled.is_empty());
+    }
+
+    #[test]
+    fn test_diff_detects_renamed_file() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let contents = "one\ntwo\nthree\nfour\nfive\n";
+        commit_file(&raw, "old.txt", contents, "First");
+
+        std::fs::rename(temp_dir.path().join("old.txt"), temp_dir.path().join("new.txt")).unwrap();
+        let mut index = raw.index().unwrap();
+        index.remove_path(std::path::Path::new("old.txt")).unwrap();
+        index.write().unwrap();
+        let renamed = commit_file(&raw, "new.txt", contents, "Rename");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let files = repo.diff_commit(&CommitId(renamed)).expect("Failed to diff commit");
+
+        assert_eq!(files.len(), 1, "Rename should be a single entry: {:?}", files);
+        let rename = files[0].rename.as_ref().expect("Entry should be marked as a rename");
+        assert_eq!(rename.kind, RenameKind::Renamed);
+        assert_eq!((rename.from.as_str(), rename.to.as_str()), ("old.txt", "new.txt"));
+        assert!(files[0].hunks.is_empty());
+
+        let stats = repo.commit_stats(&CommitId(renamed)).expect("Failed to compute stats");
+        assert_eq!((stats.files_changed, stats.insertions, stats.deletions), (1, 0, 0));
+    }
+
+    #[test]
+    fn test_ignore_whitespace_hides_reformat_only_changes() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        commit_file(&raw, "a.txt", "fn main() {\n    run();\n}\n", "First");
+        let reformat = commit_file(&raw, "a.txt", "fn main() {\n        run();\n}\n", "Reindent");
+
+        let mut repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        assert!(!repo.ignore_whitespace());
+        let files = repo.cached_diff(&CommitId(reformat)).expect("Failed to diff commit");
+        assert_eq!(files[0].hunks.len(), 1);
+
+        repo.set_ignore_whitespace(true);
+        let files = repo.cached_diff(&CommitId(reformat)).expect("Failed to diff commit");
+        assert!(files.iter().all(|file| file.hunks.is_empty()), "Whitespace-only hunks should be dropped: {:?}", files);
+
+        let stats = repo.cached_commit_stats(&CommitId(reformat)).expect("Failed to compute stats");
+        assert_eq!((stats.insertions, stats.deletions), (0, 0));
+    }
+    #[test]
+    fn test_diff_between_spans_intermediate_commits() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let first = commit_file(&raw, "a.txt", "one\n", "First");
+        commit_file(&raw, "b.txt", "two\n", "Second");
+        let third = commit_file(&raw, "a.txt", "one\nthree\n", "Third");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let files = repo.diff_between(&CommitId(first), &CommitId(third)).expect("Failed to diff commits");
+
+        let mut paths: Vec<_> = files.iter().filter_map(|file| file.new_file.as_deref()).collect();
+        paths.sort();
+        assert_eq!(paths, vec!["a.txt", "b.txt"]);
+
+        let reversed = repo.diff_between(&CommitId(third), &CommitId(first)).expect("Failed to diff commits");
+        let a = reversed.iter().find(|file| file.old_file.as_deref() == Some("a.txt")).unwrap();
+        assert!(a.hunks[0].lines.iter().any(|line| matches!(line.line_type, DiffLineType::Deletion) && line.content == "three"));
+    }
+}
\ No newline at end of file
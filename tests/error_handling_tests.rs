@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod error_handling_tests {
+    use twiggy::error::{Severity, TwiggyError};
+
+    fn io_error() -> std::io::Error {
+        std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied")
+    }
+
+    fn json_error() -> serde_json::Error {
+        serde_json::from_str::<serde_json::Value>("{").unwrap_err()
+    }
+
+    fn git_error(class: git2::ErrorClass) -> TwiggyError {
+        git2::Error::new(git2::ErrorCode::GenericError, class, "failed").into()
+    }
+
+    #[test]
+    fn test_severity_for_each_variant() {
+        let cases = vec![
+            (git_error(git2::ErrorClass::Reference), Severity::Error),
+            (TwiggyError::Io { operation: "read".to_string(), source: io_error() }, Severity::Error),
+            (TwiggyError::Config { message: "bad".to_string() }, Severity::Warning),
+            (TwiggyError::Ui { message: "bad".to_string() }, Severity::Warning),
+            (TwiggyError::Application { message: "bad".to_string() }, Severity::Critical),
+            (TwiggyError::Serialization { operation: "parse".to_string(), source: json_error() }, Severity::Error),
+            (TwiggyError::FileSystem { path: "a.txt".to_string(), source: io_error() }, Severity::Error),
+            (TwiggyError::Network { message: "offline".to_string() }, Severity::Warning),
+            (TwiggyError::Validation { field: "hash".to_string(), message: "too short".to_string() }, Severity::Warning),
+        ];
+
+        for (error, expected) in cases {
+            assert_eq!(error.severity(), expected, "{:?}", error);
+        }
+    }
+
+    #[test]
+    fn test_git_corruption_is_critical() {
+        for class in [git2::ErrorClass::Odb, git2::ErrorClass::Object, git2::ErrorClass::Zlib] {
+            let error = git_error(class);
+            assert!(error.is_corruption());
+            assert_eq!(error.severity(), Severity::Critical);
+        }
+
+        assert!(!git_error(git2::ErrorClass::Net).is_corruption());
+    }
+
+    #[test]
+    fn test_only_warnings_skip_the_dialog() {
+        assert!(Severity::Critical.shows_dialog());
+        assert!(Severity::Error.shows_dialog());
+        assert!(!Severity::Warning.shows_dialog());
+    }
+
+    #[test]
+    fn test_severity_leaves_codes_and_recoverability_unchanged() {
+        let validation = TwiggyError::Validation { field: "hash".to_string(), message: "too short".to_string() };
+        assert_eq!(validation.error_code(), 9000);
+        assert!(validation.is_recoverable());
+
+        let git = git_error(git2::ErrorClass::Odb);
+        assert_eq!(git.error_code(), 1000);
+        assert!(!git.is_recoverable());
+    }
+}
\ No newline at end of file
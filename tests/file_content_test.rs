@@ -0,0 +1,52 @@
+mod common;
+
+#[cfg(test)]
+mod file_content_tests {
+    use git2::Repository;
+    use std::path::Path;
+    use twiggy::error::TwiggyError;
+    use twiggy::git::repository::GitRepository;
+    use twiggy::git::types::CommitId;
+    use crate::common::commit_file;
+
+    #[test]
+    fn test_file_contents_match_each_commit() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+        let first = commit_file(&raw, "a.txt", b"one\n", "Update");
+        let second = commit_file(&raw, "a.txt", b"one\ntwo\n", "Update");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+
+        assert_eq!(repo.file_at_commit(&CommitId(first), Path::new("a.txt")).unwrap(), b"one\n");
+        assert_eq!(repo.file_at_commit(&CommitId(second), Path::new("a.txt")).unwrap(), b"one\ntwo\n");
+        assert!(!repo.is_binary_at_commit(&CommitId(second), Path::new("a.txt")).unwrap());
+    }
+
+    #[test]
+    fn test_binary_files_are_detected() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+        let commit = commit_file(&raw, "image.bin", [0u8, 159, 146, 150, 0, 1], "Update");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+
+        assert!(repo.is_binary_at_commit(&CommitId(commit), Path::new("image.bin")).unwrap());
+        assert_eq!(repo.file_at_commit(&CommitId(commit), Path::new("image.bin")).unwrap().len(), 6);
+    }
+
+    #[test]
+    fn test_missing_path_is_a_git_error() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+        let commit = commit_file(&raw, "a.txt", b"one\n", "Update");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let result = repo.file_at_commit(&CommitId(commit), Path::new("missing.txt"));
+
+        match result {
+            Err(TwiggyError::Git { message, .. }) => assert!(message.contains("missing.txt")),
+            other => panic!("Expected a Git error, got {:?}", other.map(|bytes| bytes.len())),
+        }
+    }
+}
\ No newline at end of file
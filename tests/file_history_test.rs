@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod file_history_tests {
+    use git2::{Oid, Repository, Signature};
+    use std::path::Path;
+    use twiggy::git::repository::GitRepository;
+    use twiggy::git::types::CommitId;
+
+    fn commit_tree(repo: &Repository, message: &str, update: impl FnOnce(&mut git2::Index, &Path)) -> Oid {
+        let workdir = repo.workdir().expect("Repository should have a workdir").to_path_buf();
+        let mut index = repo.index().expect("Failed to open index");
+        update(&mut index, &workdir);
+        index.write().expect("Failed to write index");
+        let tree_id = index.write_tree().expect("Failed to write tree");
+        let tree = repo.find_tree(tree_id).expect("Failed to find tree");
+
+        let signature = Signature::now("Test User", "test@example.com").expect("Failed to create signature");
+        let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+
+        repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)
+            .expect("Failed to create commit")
+    }
+
+    fn write(index: &mut git2::Index, workdir: &Path, name: &str, contents: &str) {
+        std::fs::write(workdir.join(name), contents).expect("Failed to write file");
+        index.add_path(Path::new(name)).expect("Failed to stage file");
+    }
+
+    const BODY: &str = "line one\nline two\nline three\nline four\nline five\n";
+
+    #[test]
+    fn test_history_only_lists_commits_touching_the_file() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let added = commit_tree(&raw, "Add a", |index, dir| write(index, dir, "a.txt", BODY));
+        commit_tree(&raw, "Add b", |index, dir| write(index, dir, "b.txt", "b\n"));
+        let edited = commit_tree(&raw, "Edit a", |index, dir| write(index, dir, "a.txt", &format!("{}six\n", BODY)));
+        commit_tree(&raw, "Edit b", |index, dir| write(index, dir, "b.txt", "b2\n"));
+
+        let mut repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let history = repo.file_history(Path::new("a.txt"), None).expect("Failed to load history");
+        let ids: Vec<CommitId> = history.iter().map(|commit| commit.id).collect();
+
+        assert_eq!(ids, vec![CommitId(edited), CommitId(added)]);
+
+        let limited = repo.file_history(Path::new("a.txt"), Some(1)).expect("Failed to load history");
+        assert_eq!(limited.len(), 1);
+        assert_eq!(limited[0].id, CommitId(edited));
+    }
+
+    #[test]
+    fn test_history_follows_renames() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let added = commit_tree(&raw, "Add old", |index, dir| write(index, dir, "old.txt", BODY));
+        let renamed = commit_tree(&raw, "Rename", |index, dir| {
+            std::fs::rename(dir.join("old.txt"), dir.join("new.txt")).expect("Failed to rename file");
+            index.remove_path(Path::new("old.txt")).expect("Failed to unstage file");
+            index.add_path(Path::new("new.txt")).expect("Failed to stage file");
+        });
+        let edited = commit_tree(&raw, "Edit new", |index, dir| write(index, dir, "new.txt", &format!("{}six\n", BODY)));
+
+        let mut repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let history = repo.file_history(Path::new("new.txt"), None).expect("Failed to load history");
+        let ids: Vec<CommitId> = history.iter().map(|commit| commit.id).collect();
+
+        assert_eq!(ids, vec![CommitId(edited), CommitId(renamed), CommitId(added)]);
+    }
+
+    #[test]
+    fn test_history_of_missing_file_is_empty() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+        commit_tree(&raw, "Add a", |index, dir| write(index, dir, "a.txt", BODY));
+
+        let mut repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let history = repo.file_history(Path::new("missing.txt"), None).expect("Failed to load history");
+
+        assert!(history.is_empty());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+mod common;
+
+#[cfg(test)]
+mod file_tree_tests {
+    use git2::Repository;
+    use twiggy::git::repository::GitRepository;
+    use twiggy::git::types::CommitId;
+    use crate::common::commit_files;
+
+    #[test]
+    fn test_list_tree_nests_directories() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let id = commit_files(
+            &raw,
+            &[("README.md", "readme"), ("src/main.rs", "fn main() {}"), ("src/ui/mod.rs", "")],
+            "Initial",
+        );
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let entries = repo.list_tree(&CommitId(id)).expect("Failed to list tree");
+
+        let names: Vec<&str> = entries.iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, vec!["src", "README.md"]);
+
+        let src = &entries[0];
+        assert!(src.is_dir);
+        assert_eq!(src.children[0].path, "src/ui");
+        assert_eq!(src.children[0].children[0].path, "src/ui/mod.rs");
+        assert_eq!(src.children[1].path, "src/main.rs");
+        assert!(!src.children[1].is_dir);
+    }
+}
\ No newline at end of file
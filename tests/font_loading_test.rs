@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod font_loading_tests {
+    use eframe::egui;
+    use twiggy::util::fonts::font_definitions_with;
+
+    #[test]
+    fn test_custom_font_is_preferred_in_both_families() {
+        let defaults = egui::FontDefinitions::default();
+        let (_, data) = defaults.font_data.iter().next().expect("egui should bundle a default font");
+
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let path = dir.path().join("custom.ttf");
+        std::fs::write(&path, &*data.font).unwrap();
+
+        let fonts = font_definitions_with(&path).expect("Bundled font should load");
+
+        for family in [egui::FontFamily::Proportional, egui::FontFamily::Monospace] {
+            let names = &fonts.families[&family];
+            assert_eq!(fonts.font_data[&names[0]].font, data.font);
+            assert_eq!(names.len(), defaults.families[&family].len() + 1);
+        }
+    }
+
+    #[test]
+    fn test_unreadable_font_is_rejected() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let path = dir.path().join("broken.ttf");
+        std::fs::write(&path, b"definitely not a font").unwrap();
+
+        assert!(font_definitions_with(&path).is_err());
+        assert!(font_definitions_with(&dir.path().join("missing.ttf")).is_err());
+    }
+}
\ No newline at end of file
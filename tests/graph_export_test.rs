@@ -0,0 +1,57 @@
+mod common;
+
+#[cfg(test)]
+mod graph_export_tests {
+    use twiggy::git::graph_export::to_dot;
+    use crate::common::{commit_id, make_commit_by};
+
+    fn count_nodes(dot: &str) -> usize {
+        dot.lines().filter(|line| line.contains("[label=")).count()
+    }
+
+    fn count_edges(dot: &str) -> usize {
+        dot.lines().filter(|line| line.contains(" -> ")).count()
+    }
+
+    #[test]
+    fn test_dot_for_merge_dag() {
+        let commits = vec![
+            make_commit_by(4, &[2, 3], "Test User", "Merge feature"),
+            make_commit_by(3, &[1], "Test User", "Feature work"),
+            make_commit_by(2, &[1], "Test User", "Main work"),
+            make_commit_by(1, &[], "Test User", "Initial commit"),
+        ];
+
+        let dot = to_dot(&commits);
+
+        assert!(dot.starts_with("digraph"), "Output should be a digraph");
+        assert!(dot.trim_end().ends_with('}'), "Output should close the graph");
+        assert_eq!(count_nodes(&dot), 4);
+        assert_eq!(count_edges(&dot), 4);
+        assert!(dot.contains(&format!("\"{}\" -> \"{}\"", commit_id(4), commit_id(3))));
+        assert!(dot.contains(&format!("[label=\"{}\"", commit_id(1).short())));
+    }
+
+    #[test]
+    fn test_dot_skips_parents_outside_loaded_range() {
+        let commits = vec![
+            make_commit_by(2, &[1], "Test User", "Second"),
+        ];
+
+        let dot = to_dot(&commits);
+
+        assert_eq!(count_nodes(&dot), 1);
+        assert_eq!(count_edges(&dot), 0);
+    }
+
+    #[test]
+    fn test_dot_escapes_quotes_in_summary() {
+        let commits = vec![
+            make_commit_by(1, &[], "Test User", "Say \"hello\""),
+        ];
+
+        let dot = to_dot(&commits);
+
+        assert!(dot.contains("Say \\\"hello\\\""));
+    }
+}
\ No newline at end of file
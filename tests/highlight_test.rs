@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod highlight_tests {
+    use twiggy::ui::highlight::{highlight_line, syntax_for_path, HighlightCache};
+
+    #[test]
+    fn test_syntax_is_picked_by_extension() {
+        assert_eq!(syntax_for_path("src/main.rs").expect("Rust should be known").name, "Rust");
+        assert_eq!(syntax_for_path("scripts/build.py").expect("Python should be known").name, "Python");
+        assert_eq!(syntax_for_path("Makefile").expect("Makefile should be known").name, "Makefile");
+    }
+
+    #[test]
+    fn test_unknown_extensions_fall_back_to_plain_text() {
+        assert!(syntax_for_path("notes.unknown-extension").is_none());
+        assert!(syntax_for_path("LICENSE").is_none());
+        assert!(syntax_for_path("notes.txt").is_none());
+    }
+
+    #[test]
+    fn test_spans_cover_the_whole_line() {
+        let syntax = syntax_for_path("lib.rs").unwrap();
+        let line = "fn main() { let answer = 42; }";
+
+        let spans = highlight_line(syntax, line, true);
+
+        assert_eq!(spans.first().unwrap().0.start, 0);
+        assert_eq!(spans.last().unwrap().0.end, line.len());
+        for pair in spans.windows(2) {
+            assert_eq!(pair[0].0.end, pair[1].0.start);
+        }
+
+        let mut colors: Vec<_> = spans.iter().map(|(_, color)| *color).collect();
+        colors.dedup();
+        assert!(colors.len() > 1, "Keywords and literals should stand out");
+    }
+
+    #[test]
+    fn test_lines_are_highlighted_independently() {
+        let syntax = syntax_for_path("lib.rs").unwrap();
+
+        // An unterminated comment on one line must not color the next.
+        let after_comment = highlight_line(syntax, "let x = 1;", true);
+        highlight_line(syntax, "/* open comment", true);
+        assert_eq!(highlight_line(syntax, "let x = 1;", true), after_comment);
+    }
+
+    #[test]
+    fn test_cache_reuses_lines_and_resets_on_theme_change() {
+        let syntax = syntax_for_path("lib.rs").unwrap();
+        let mut cache = HighlightCache::default();
+
+        let first = cache.get(syntax, "let x = 1;", true).clone();
+        assert_eq!(cache.get(syntax, "let x = 1;", true), &first);
+        assert_eq!(cache.len(), 1);
+
+        cache.get(syntax, "let y = 2;", true);
+        assert_eq!(cache.len(), 2);
+
+        cache.get(syntax, "let x = 1;", false);
+        assert_eq!(cache.len(), 1);
+    }
+}
\ No newline at end of file
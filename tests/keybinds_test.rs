@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod keybinds_tests {
+    use eframe::egui::{Key, KeyboardShortcut, Modifiers};
+    use twiggy::config::{AppConfig, KeybindAction, KeybindConfig};
+    use twiggy::util::keys::{format_shortcut, parse_shortcut};
+
+    #[test]
+    fn test_parse_shortcut() {
+        assert_eq!(parse_shortcut("Ctrl+O"), Some(KeyboardShortcut::new(Modifiers::CTRL, Key::O)));
+        assert_eq!(parse_shortcut("F10"), Some(KeyboardShortcut::new(Modifiers::NONE, Key::F10)));
+        assert_eq!(
+            parse_shortcut("ctrl + shift + o"),
+            Some(KeyboardShortcut::new(Modifiers::CTRL | Modifiers::SHIFT, Key::O))
+        );
+
+        assert_eq!(parse_shortcut(""), None);
+        assert_eq!(parse_shortcut("Ctrl+"), None);
+        assert_eq!(parse_shortcut("Ctrl+Banana"), None);
+        assert_eq!(parse_shortcut("O+P"), None);
+    }
+
+    #[test]
+    fn test_format_round_trips() {
+        for binding in ["Ctrl+O", "Ctrl+Shift+O", "Alt+F4", "F5", "Ctrl+Plus"] {
+            let shortcut = parse_shortcut(binding).expect("Binding should parse");
+            assert_eq!(format_shortcut(&shortcut), binding);
+        }
+    }
+
+    #[test]
+    fn test_defaults_match_previous_bindings() {
+        let keybinds = KeybindConfig::default();
+
+        assert!(keybinds.validate().is_ok());
+        assert_eq!(keybinds.get(KeybindAction::OpenRepository), "Ctrl+O");
+        assert_eq!(keybinds.get(KeybindAction::Settings), "Ctrl+Alt+S");
+        assert_eq!(keybinds.get(KeybindAction::Quit), "Ctrl+Q");
+        assert_eq!(keybinds.get(KeybindAction::ToggleMenuBar), "F10");
+        assert_eq!(keybinds.shortcuts().len(), KeybindAction::ALL.len());
+    }
+
+    #[test]
+    fn test_validation_rejects_duplicates_and_garbage() {
+        let mut config = AppConfig::default();
+        config.keybinds.set(KeybindAction::Quit, "ctrl+o".to_string());
+        assert!(config.validate().is_err(), "Equivalent bindings should count as duplicates");
+
+        config.keybinds = KeybindConfig::default();
+        config.keybinds.set(KeybindAction::Refresh, "Hyper+R".to_string());
+        assert!(config.validate().is_err());
+
+        config.keybinds.set(KeybindAction::Refresh, "Ctrl+R".to_string());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_missing_keybinds_section_uses_defaults() {
+        let mut json = serde_json::to_value(AppConfig::default()).expect("Failed to serialize config");
+        json.as_object_mut().expect("Config is an object").remove("keybinds");
+        json["keybinds"] = serde_json::json!({ "quit": "Ctrl+Shift+Q" });
+
+        let config: AppConfig = serde_json::from_value(json).expect("Failed to deserialize config");
+        assert_eq!(config.keybinds.get(KeybindAction::Quit), "Ctrl+Shift+Q");
+        assert_eq!(config.keybinds.get(KeybindAction::OpenRepository), "Ctrl+O");
+    }
+}
\ No newline at end of file
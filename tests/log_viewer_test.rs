@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod log_viewer_tests {
+    use twiggy::ui::components::log_viewer::{LogEntry, LogLevel, LogViewer};
+
+    fn json_line(level: &str, target: &str, message: &str) -> String {
+        format!(
+            "{{\"timestamp\":\"2024-01-15T10:30:00+00:00\",\"level\":\"{}\",\"fields\":{{\"message\":\"{}\"}},\"target\":\"{}\"}}\n",
+            level, message, target
+        )
+    }
+
+    fn viewer_with(lines: &[String]) -> (tempfile::TempDir, LogViewer) {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let path = temp_dir.path().join("twiggy.log");
+        std::fs::write(&path, lines.concat()).expect("Failed to write log file");
+
+        let mut viewer = LogViewer::new();
+        viewer.set_log_file(path).expect("Failed to load log file");
+        (temp_dir, viewer)
+    }
+
+    #[test]
+    fn test_parse_json_line() {
+        let line = r#"{"timestamp":"2024-01-15T10:30:00.123456+02:00","level":"WARN","fields":{"message":"Failed to load commits"},"target":"twiggy::app","filename":"src/app.rs","line_number":42,"threadName":"main","threadId":"ThreadId(1)"}"#;
+
+        let entry = LogEntry::parse(line).expect("JSON line should parse");
+
+        assert_eq!(entry.level, LogLevel::Warn);
+        assert_eq!(entry.target, "twiggy::app");
+        assert_eq!(entry.message, "Failed to load commits");
+        assert_eq!(entry.timestamp.timestamp(), 1705307400);
+        assert_eq!(entry.raw_line, line);
+    }
+
+    #[test]
+    fn test_parse_plaintext_line() {
+        let entry = LogEntry::parse("2024-01-15 10:30:00+00:00 INFO twiggy::git Loaded 42 commits")
+            .expect("Plaintext line should parse");
+
+        assert_eq!(entry.level, LogLevel::Info);
+        assert_eq!(entry.target, "twiggy::git");
+        assert_eq!(entry.message, "Loaded 42 commits");
+    }
+
+    #[test]
+    fn test_parse_rejects_garbage() {
+        assert!(LogEntry::parse("not a log line").is_none());
+        assert!(LogEntry::parse(r#"{"level":"INFO"}"#).is_none());
+    }
+    #[test]
+    fn test_regex_search_matches_message_and_target() {
+        let (_temp_dir, mut viewer) = viewer_with(&[
+            json_line("INFO", "twiggy::git", "Loaded 42 commits"),
+            json_line("INFO", "twiggy::app", "Loaded config"),
+            json_line("WARN", "twiggy::ui", "Slow frame"),
+        ]);
+
+        viewer.set_search(r"\d+ commits", true);
+        let messages: Vec<&str> = viewer.visible_entries().map(|entry| entry.message.as_str()).collect();
+        assert_eq!(messages, vec!["Loaded 42 commits"]);
+
+        viewer.set_search("^twiggy::(app|ui)$", true);
+        assert_eq!(viewer.visible_entries().count(), 2);
+    }
+
+    #[test]
+    fn test_invalid_regex_keeps_entries_visible() {
+        let (_temp_dir, mut viewer) = viewer_with(&[
+            json_line("INFO", "twiggy::git", "Loaded 42 commits"),
+            json_line("INFO", "twiggy::app", "Loaded (config"),
+        ]);
+
+        viewer.set_search("(config", true);
+        assert!(viewer.regex_error().is_some());
+        assert_eq!(viewer.visible_entries().count(), 2);
+
+        viewer.set_search("(config", false);
+        assert!(viewer.regex_error().is_none());
+        assert_eq!(viewer.visible_entries().count(), 1);
+    }
+    #[test]
+    fn test_reload_reads_only_appended_lines() {
+        use std::io::Write;
+
+        let (temp_dir, mut viewer) = viewer_with(&[json_line("INFO", "twiggy::app", "First")]);
+        let path = temp_dir.path().join("twiggy.log");
+        assert_eq!(viewer.visible_entries().count(), 1);
+
+        let mut file = std::fs::OpenOptions::new().append(true).open(&path).expect("Failed to open log file");
+        let second = json_line("INFO", "twiggy::app", "Second");
+        let (head, tail) = second.split_at(20);
+        file.write_all(head.as_bytes()).expect("Failed to append");
+        file.flush().expect("Failed to flush");
+
+        viewer.reload_logs().expect("Failed to reload");
+        assert_eq!(viewer.visible_entries().count(), 1, "A partial line should wait for its newline");
+
+        file.write_all(tail.as_bytes()).expect("Failed to append");
+        file.flush().expect("Failed to flush");
+        viewer.reload_logs().expect("Failed to reload");
+
+        let messages: Vec<&str> = viewer.visible_entries().map(|entry| entry.message.as_str()).collect();
+        assert_eq!(messages, vec!["First", "Second"]);
+    }
+
+    #[test]
+    fn test_reload_after_rotation_starts_over() {
+        let (temp_dir, mut viewer) = viewer_with(&[
+            json_line("INFO", "twiggy::app", "Old one"),
+            json_line("INFO", "twiggy::app", "Old two"),
+        ]);
+        let path = temp_dir.path().join("twiggy.log");
+
+        std::fs::write(&path, json_line("INFO", "twiggy::app", "New")).expect("Failed to rotate log file");
+        viewer.reload_logs().expect("Failed to reload");
+
+        let messages: Vec<&str> = viewer.visible_entries().map(|entry| entry.message.as_str()).collect();
+        assert_eq!(messages, vec!["New"]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod logging_tests {
+    use std::io::Write;
+    use twiggy::logging::{cleanup_old_logs, RotatingFileWriter};
+
+    fn read(dir: &std::path::Path, name: &str) -> String {
+        std::fs::read_to_string(dir.join(name)).unwrap_or_default()
+    }
+
+    #[test]
+    fn test_rotates_when_size_is_exceeded() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let mut writer = RotatingFileWriter::new(temp_dir.path(), "twiggy.log", 10, 3)
+            .expect("Failed to create writer");
+
+        writer.write_all(b"first 01\n").expect("Failed to write");
+        writer.write_all(b"second 2\n").expect("Failed to write");
+        writer.write_all(b"third 03\n").expect("Failed to write");
+        writer.flush().expect("Failed to flush");
+
+        assert_eq!(read(temp_dir.path(), "twiggy.log"), "third 03\n");
+        assert_eq!(read(temp_dir.path(), "twiggy.log.1"), "second 2\n");
+        assert_eq!(read(temp_dir.path(), "twiggy.log.2"), "first 01\n");
+
+        writer.write_all(b"fourth 4\n").expect("Failed to write");
+        writer.flush().expect("Failed to flush");
+
+        assert_eq!(read(temp_dir.path(), "twiggy.log"), "fourth 4\n");
+        assert_eq!(read(temp_dir.path(), "twiggy.log.2"), "second 2\n");
+        assert!(!temp_dir.path().join("twiggy.log.3").exists());
+    }
+
+    #[test]
+    fn test_appends_to_existing_file() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        std::fs::write(temp_dir.path().join("twiggy.log"), "old\n").expect("Failed to write log");
+
+        let mut writer = RotatingFileWriter::new(temp_dir.path(), "twiggy.log", 1024, 5)
+            .expect("Failed to create writer");
+        writer.write_all(b"new\n").expect("Failed to write");
+        writer.flush().expect("Failed to flush");
+
+        assert_eq!(read(temp_dir.path(), "twiggy.log"), "old\nnew\n");
+    }
+
+    #[test]
+    fn test_cleanup_counts_numbered_files() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        for name in ["twiggy.log", "twiggy.log.1", "twiggy.log.2", "twiggy.log.2024-01-15", "notes.txt"] {
+            std::fs::write(temp_dir.path().join(name), "x").expect("Failed to write file");
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+
+        cleanup_old_logs(temp_dir.path(), 2).expect("Cleanup should succeed");
+
+        let mut remaining: Vec<String> = std::fs::read_dir(temp_dir.path())
+            .expect("Failed to read directory")
+            .map(|entry| entry.expect("Failed to read entry").file_name().to_string_lossy().to_string())
+            .collect();
+        remaining.sort();
+
+        assert_eq!(remaining, vec!["notes.txt", "twiggy.log.2", "twiggy.log.2024-01-15"]);
+    }
+}
\ No newline at end of file
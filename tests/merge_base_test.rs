@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod merge_base_tests {
+    use git2::{Oid, Repository, Signature};
+    use twiggy::git::repository::GitRepository;
+    use twiggy::git::types::CommitId;
+
+    fn commit_on(repo: &Repository, update_ref: Option<&str>, parents: &[Oid], message: &str) -> Oid {
+        let signature = Signature::now("Test User", "test@example.com").expect("Failed to create signature");
+        let tree_id = repo.index().unwrap().write_tree().expect("Failed to write tree");
+        let tree = repo.find_tree(tree_id).unwrap();
+        let parents: Vec<git2::Commit> = parents.iter().map(|id| repo.find_commit(*id).unwrap()).collect();
+        let parents: Vec<&git2::Commit> = parents.iter().collect();
+
+        repo.commit(update_ref, &signature, &signature, message, &tree, &parents)
+            .expect("Failed to create commit")
+    }
+
+    #[test]
+    fn test_merge_base_of_head_and_parent_in_own_history() {
+        let Ok(raw) = Repository::discover(env!("CARGO_MANIFEST_DIR")) else {
+            return;
+        };
+        let head = raw.head().unwrap().peel_to_commit().unwrap();
+        let Ok(parent) = head.parent(0) else {
+            return;
+        };
+
+        let repo = GitRepository::open(raw.workdir().unwrap()).expect("Failed to open repository");
+        let base = repo.merge_base(&CommitId(head.id()), &CommitId(parent.id()))
+            .expect("Failed to compute merge base");
+
+        assert_eq!(base, Some(CommitId(parent.id())));
+    }
+
+    #[test]
+    fn test_merge_base_of_diverged_branches() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        let root = commit_on(&raw, Some("HEAD"), &[], "Root");
+        let left = commit_on(&raw, None, &[root], "Left");
+        let right = commit_on(&raw, None, &[root], "Right");
+
+        let repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+        let base = repo.merge_base(&CommitId(left), &CommitId(right)).expect("Failed to compute merge base");
+
+        assert_eq!(base, Some(CommitId(root)));
+    }
+
+    #[test]
+    fn test_unrelated_histories_have_no_merge_base() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        let first = commit_on(&raw, Some("HEAD"), &[], "First root");
+        let second = commit_on(&raw, None, &[], "Second root");
+
+        let repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+        let base = repo.merge_base(&CommitId(first), &CommitId(second)).expect("Failed to compute merge base");
+
+        assert_eq!(base, None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,115 @@
+mod common;
+
+#[cfg(test)]
+mod remote_fetch_tests {
+    use git2::Repository;
+    use twiggy::git::repository::GitRepository;
+    use crate::common::commit_file;
+
+    fn setup_remote() -> (tempfile::TempDir, tempfile::TempDir) {
+        let origin_dir = tempfile::tempdir().expect("Failed to create origin directory");
+        let origin = Repository::init(origin_dir.path()).expect("Failed to initialize origin");
+        let tip = commit_file(&origin, "a.txt", "one", "First");
+        let tip_commit = origin.find_commit(tip).unwrap();
+        origin.branch("feature-a", &tip_commit, false).unwrap();
+        origin.branch("feature-b", &tip_commit, false).unwrap();
+        origin.tag_lightweight("v1.0.0", tip_commit.as_object(), false).unwrap();
+
+        let local_dir = tempfile::tempdir().expect("Failed to create local directory");
+        let local = Repository::init(local_dir.path()).expect("Failed to initialize local");
+        local.remote("origin", origin_dir.path().to_str().unwrap()).unwrap();
+
+        (origin_dir, local_dir)
+    }
+
+    #[test]
+    fn test_list_remote_refs() {
+        let (_origin_dir, local_dir) = setup_remote();
+        let repo = GitRepository::open(local_dir.path()).expect("Failed to open repository");
+
+        assert_eq!(repo.remote_names().unwrap(), vec!["origin".to_string()]);
+
+        let refs = repo.list_remote_refs("origin").expect("Failed to list remote refs");
+        assert_eq!(refs.len(), 4, "Expected default branch, two feature branches and a tag: {:?}", refs);
+        assert!(refs.contains(&"refs/heads/feature-a".to_string()));
+        assert!(refs.contains(&"refs/tags/v1.0.0".to_string()));
+    }
+
+    #[test]
+    fn test_fetch_selected_refs_only() {
+        let (_origin_dir, local_dir) = setup_remote();
+        let repo = GitRepository::open(local_dir.path()).expect("Failed to open repository");
+
+        repo.fetch("origin", Some(&["refs/heads/feature-a".to_string()]))
+            .expect("Failed to fetch selected refs");
+
+        let raw = Repository::open(local_dir.path()).unwrap();
+        assert!(raw.find_reference("refs/remotes/origin/feature-a").is_ok());
+        assert!(raw.find_reference("refs/remotes/origin/feature-b").is_err());
+    }
+
+    #[test]
+    fn test_fetch_all_uses_default_refspecs() {
+        let (_origin_dir, local_dir) = setup_remote();
+        let repo = GitRepository::open(local_dir.path()).expect("Failed to open repository");
+
+        repo.fetch("origin", None).expect("Failed to fetch");
+
+        let raw = Repository::open(local_dir.path()).unwrap();
+        assert!(raw.find_reference("refs/remotes/origin/feature-a").is_ok());
+        assert!(raw.find_reference("refs/remotes/origin/feature-b").is_ok());
+    }
+
+    #[test]
+    fn test_fetch_remote_reports_updated_refs() {
+        let (origin_dir, local_dir) = setup_remote();
+        let repo = GitRepository::open(local_dir.path()).expect("Failed to open repository");
+
+        assert_eq!(repo.default_remote(), Some("origin".to_string()));
+
+        let summary = repo.fetch_remote("origin").expect("Failed to fetch");
+        assert_eq!(summary.remote, "origin");
+        assert_eq!(summary.updated_refs, 4, "Expected three branches and a tag: {:?}", summary);
+        assert!(summary.received_objects > 0);
+
+        let summary = repo.fetch_remote("origin").expect("Failed to fetch again");
+        assert_eq!(summary.updated_refs, 0);
+
+        let origin = Repository::open(origin_dir.path()).unwrap();
+        commit_file(&origin, "b.txt", "two", "Second");
+
+        let summary = repo.fetch_remote("origin").expect("Failed to fetch new commit");
+        assert_eq!(summary.updated_refs, 1);
+    }
+
+    #[test]
+    fn test_list_remotes_with_urls() {
+        let (origin_dir, local_dir) = setup_remote();
+        let raw = Repository::open(local_dir.path()).unwrap();
+        raw.remote("mirror", "https://example.com/mirror.git").unwrap();
+        raw.remote_set_pushurl("mirror", Some("git@example.com:mirror.git")).unwrap();
+
+        let repo = GitRepository::open(local_dir.path()).expect("Failed to open repository");
+        let remotes = repo.list_remotes().expect("Failed to list remotes");
+        assert_eq!(remotes.len(), 2);
+
+        let origin = remotes.iter().find(|remote| remote.name == "origin").expect("origin should be listed");
+        let origin_url = origin_dir.path().to_str().unwrap();
+        assert_eq!(origin.fetch_url.as_deref(), Some(origin_url));
+        assert_eq!(origin.push_url.as_deref(), Some(origin_url));
+
+        let mirror = remotes.iter().find(|remote| remote.name == "mirror").expect("mirror should be listed");
+        assert_eq!(mirror.fetch_url.as_deref(), Some("https://example.com/mirror.git"));
+        assert_eq!(mirror.push_url.as_deref(), Some("git@example.com:mirror.git"));
+    }
+
+    #[test]
+    fn test_list_remotes_without_remotes() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        Repository::init(dir.path()).expect("Failed to initialize repository");
+
+        let repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+        assert!(repo.list_remotes().expect("Failed to list remotes").is_empty());
+        assert_eq!(repo.default_remote(), None);
+    }
+}
\ No newline at end of file
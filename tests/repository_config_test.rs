@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod repository_config_tests {
+    use twiggy::git::repository::{validate_config_entry, GitRepository};
+
+    fn init_repo() -> (tempfile::TempDir, GitRepository) {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        git2::Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        (temp_dir, repo)
+    }
+
+    #[test]
+    fn test_set_and_read_config_entries() {
+        let (_temp_dir, repo) = init_repo();
+
+        repo.set_config_entry("user.name", "Test User").expect("Failed to set user.name");
+        repo.set_config_entry("user.email", "test@example.com").expect("Failed to set user.email");
+
+        let entries = repo.get_config_entries(Some("user.")).expect("Failed to read entries");
+        assert_eq!(entries, vec![
+            ("user.email".to_string(), "test@example.com".to_string()),
+            ("user.name".to_string(), "Test User".to_string()),
+        ]);
+
+        assert_eq!(repo.get_effective_config_value("user.name").as_deref(), Some("Test User"));
+    }
+
+    #[test]
+    fn test_multivar_is_listed_per_value_and_not_overwritten() {
+        let (temp_dir, repo) = init_repo();
+        let mut config = git2::Config::open(&temp_dir.path().join(".git/config")).expect("Failed to open config");
+        config.set_multivar("remote.origin.fetch", "^$", "+refs/heads/*:refs/remotes/origin/*").unwrap();
+        config.set_multivar("remote.origin.fetch", "^$", "+refs/tags/*:refs/tags/*").unwrap();
+
+        let entries = repo.get_config_entries(Some("remote.")).expect("Failed to read entries");
+        assert_eq!(entries, vec![
+            ("remote.origin.fetch".to_string(), "+refs/heads/*:refs/remotes/origin/*".to_string()),
+            ("remote.origin.fetch".to_string(), "+refs/tags/*:refs/tags/*".to_string()),
+        ]);
+
+        assert!(repo.set_config_entry("remote.origin.fetch", "+refs/heads/main:refs/remotes/origin/main").is_err());
+        assert!(repo.set_config_entry("remote.origin.fetch", "").is_err());
+        assert_eq!(repo.get_config_entries(Some("remote.")).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_empty_value_unsets_entry() {
+        let (_temp_dir, repo) = init_repo();
+
+        repo.set_config_entry("user.name", "Test User").expect("Failed to set user.name");
+        repo.set_config_entry("user.name", "").expect("Failed to unset user.name");
+        repo.set_config_entry("user.name", "").expect("Unsetting a missing key should succeed");
+
+        let entries = repo.get_config_entries(Some("user.")).expect("Failed to read entries");
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_values_are_rejected() {
+        let (_temp_dir, repo) = init_repo();
+
+        assert!(repo.set_config_entry("user.email", "not-an-email").is_err());
+        assert!(repo.get_config_entries(Some("user.")).unwrap().is_empty());
+
+        assert!(validate_config_entry("user.email", "a@b.c").is_ok());
+        assert!(validate_config_entry("user.email", "a b@c").is_err());
+        assert!(validate_config_entry("core.autocrlf", "input").is_ok());
+        assert!(validate_config_entry("core.autocrlf", "sometimes").is_err());
+        assert!(validate_config_entry("nosection", "value").is_err());
+    }
+}
\ No newline at end of file
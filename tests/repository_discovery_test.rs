@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod repository_discovery_tests {
+    use git2::Repository;
+    use twiggy::git::repository::discover_repository_root;
+
+    #[test]
+    fn test_discovers_work_tree_root_from_subdirectory() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        Repository::init(dir.path()).expect("Failed to initialize repository");
+        let nested = dir.path().join("src").join("deep");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        let root = discover_repository_root(&nested).expect("Discovery failed");
+
+        let expected = std::fs::canonicalize(dir.path()).unwrap();
+        assert_eq!(root.map(|path| std::fs::canonicalize(path).unwrap()), Some(expected));
+    }
+
+    #[test]
+    fn test_bare_repository_root_is_the_repository_itself() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let bare = dir.path().join("project.git");
+        Repository::init_bare(&bare).expect("Failed to initialize repository");
+
+        let root = discover_repository_root(&bare).expect("Discovery failed");
+
+        let expected = std::fs::canonicalize(&bare).unwrap();
+        assert_eq!(root.map(|path| std::fs::canonicalize(path).unwrap()), Some(expected));
+    }
+
+    #[test]
+    fn test_directory_outside_any_repository() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        if Repository::discover(dir.path()).is_ok() {
+            // The temp directory itself sits inside a repository here.
+            return;
+        }
+
+        assert_eq!(discover_repository_root(dir.path()).expect("Discovery failed"), None);
+    }
+}
\ No newline at end of file
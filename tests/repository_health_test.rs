@@ -0,0 +1,45 @@
+mod common;
+
+#[cfg(test)]
+mod repository_health_tests {
+    use git2::Repository;
+    use twiggy::git::repository::{GitRepository, RepositoryHealth};
+    use twiggy::ui::components::status_bar::health_indicator;
+    use crate::common::commit_index;
+
+    #[test]
+    fn test_clean_repository_is_healthy() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        commit_index(&raw, "Initial");
+
+        let repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+        let health = repo.validate().unwrap();
+
+        assert_eq!(health, RepositoryHealth::Healthy);
+        assert!(health_indicator(&health).is_none());
+    }
+
+    #[test]
+    fn test_unfinished_merge_is_flagged() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        let id = commit_index(&raw, "Initial");
+        std::fs::write(raw.path().join("MERGE_HEAD"), format!("{}\n", id)).unwrap();
+
+        let repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+        let health = repo.validate().unwrap();
+        assert_eq!(health, RepositoryHealth::InOperation("merge".to_string()));
+
+        let indicator = health_indicator(&health).expect("Merge should be flagged");
+        assert_eq!(indicator.label, "merge in progress");
+        assert!(indicator.tooltip.contains("git merge --abort"));
+    }
+
+    #[test]
+    fn test_corrupted_repository_is_flagged_red() {
+        let indicator = health_indicator(&RepositoryHealth::Corrupted).expect("Corruption should be flagged");
+        assert!(indicator.tooltip.contains("git fsck"));
+        assert!(indicator.color.r() > indicator.color.g());
+    }
+}
\ No newline at end of file
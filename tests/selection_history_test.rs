@@ -0,0 +1,68 @@
+mod common;
+
+#[cfg(test)]
+mod selection_history_tests {
+    use twiggy::ui::selection_history::SelectionHistory;
+    use crate::common::commit_id;
+
+    #[test]
+    fn test_back_and_forward() {
+        let mut history = SelectionHistory::default();
+        assert!(history.is_empty());
+        assert_eq!(history.position(), None);
+        assert!(!history.can_go_back());
+
+        history.push(commit_id(1));
+        history.push(commit_id(2));
+        history.push(commit_id(3));
+        assert_eq!(history.position(), Some(2));
+        assert!(!history.can_go_forward());
+
+        assert_eq!(history.go_back(), Some(commit_id(2)));
+        assert_eq!(history.go_back(), Some(commit_id(1)));
+        assert_eq!(history.go_back(), None);
+        assert_eq!(history.current(), Some(commit_id(1)));
+
+        assert_eq!(history.go_forward(), Some(commit_id(2)));
+        assert_eq!(history.position(), Some(1));
+        assert!(history.can_go_back() && history.can_go_forward());
+    }
+
+    #[test]
+    fn test_push_truncates_forward_entries() {
+        let mut history = SelectionHistory::default();
+        history.push(commit_id(1));
+        history.push(commit_id(2));
+        history.push(commit_id(3));
+        history.go_back();
+        history.go_back();
+
+        history.push(commit_id(4));
+
+        assert_eq!(history.len(), 2);
+        assert!(!history.can_go_forward());
+        assert_eq!(history.go_back(), Some(commit_id(1)));
+    }
+
+    #[test]
+    fn test_repeated_selection_is_ignored() {
+        let mut history = SelectionHistory::default();
+        history.push(commit_id(1));
+        history.push(commit_id(1));
+
+        assert_eq!(history.len(), 1);
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let mut history = SelectionHistory::new(3);
+        for n in 1..=5 {
+            history.push(commit_id(n));
+        }
+
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.go_back(), Some(commit_id(4)));
+        assert_eq!(history.go_back(), Some(commit_id(3)));
+        assert_eq!(history.go_back(), None);
+    }
+}
\ No newline at end of file
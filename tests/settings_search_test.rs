@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod settings_search_tests {
+    use twiggy::app::{settings_index, SettingsTab};
+    use twiggy::config::KeybindAction;
+    use twiggy::ui::settings_search::{first_match, match_count, matches};
+
+    #[test]
+    fn test_matches_is_case_insensitive_substring() {
+        assert!(matches("fetch", "Auto Fetch"));
+        assert!(matches("  FONT ", "Font Size"));
+        assert!(!matches("fetch", "Max Commits"));
+    }
+
+    #[test]
+    fn test_blank_query_matches_everything() {
+        assert!(matches("", "Width"));
+        assert!(matches("   ", "Width"));
+    }
+
+    #[test]
+    fn test_first_match_finds_tab_of_setting() {
+        let index = settings_index();
+
+        assert_eq!(first_match("stash", &index), Some(SettingsTab::Git));
+        assert_eq!(first_match("log level", &index), Some(SettingsTab::Logging));
+        assert_eq!(first_match("no such setting", &index), None);
+    }
+
+    #[test]
+    fn test_index_covers_keybind_actions() {
+        let index = settings_index();
+
+        for action in KeybindAction::ALL {
+            assert!(index.contains(&(SettingsTab::Keybinds, action.label().to_string())));
+        }
+        assert_eq!(match_count("", &index, &SettingsTab::Keybinds), KeybindAction::ALL.len());
+    }
+
+    #[test]
+    fn test_match_count_is_per_tab() {
+        let index = settings_index();
+
+        assert_eq!(match_count("fetch", &index, &SettingsTab::Git), 3);
+        assert_eq!(match_count("fetch", &index, &SettingsTab::Theme), 0);
+    }
+}
\ No newline at end of file
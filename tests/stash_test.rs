@@ -0,0 +1,43 @@
+mod common;
+
+#[cfg(test)]
+mod stash_tests {
+    use git2::{Repository, Signature};
+    use twiggy::git::filter::merge_stashes;
+    use twiggy::git::repository::GitRepository;
+    use twiggy::git::types::CommitId;
+    use crate::common::commit_file;
+
+    #[test]
+    fn test_stashes_are_listed_and_merged_above_their_base() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let mut raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let first = commit_file(&raw, "a.txt", "one\n", "First");
+        let second = commit_file(&raw, "a.txt", "two\n", "Second");
+
+        std::fs::write(temp_dir.path().join("a.txt"), "work in progress\n").expect("Failed to modify file");
+        let signature = Signature::now("Test User", "test@example.com").expect("Failed to create signature");
+        let stash_id = raw.stash_save(&signature, "experiment", None).expect("Failed to stash changes");
+
+        let mut repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let stashes = repo.list_stashes().expect("Failed to list stashes");
+
+        assert_eq!(stashes.len(), 1);
+        assert_eq!(stashes[0].index, 0);
+        assert_eq!(stashes[0].id, CommitId(stash_id));
+        assert_eq!(stashes[0].reference_name(), "stash@{0}");
+        assert!(stashes[0].message.contains("experiment"));
+
+        repo.load_commits(None).expect("Failed to load commits");
+        let stash_commit = repo.find_commit_by_hash(&stash_id.to_string())
+            .expect("Failed to read stash commit")
+            .expect("Stash commit should exist");
+
+        let merged = merge_stashes(repo.get_commits(), &[stash_commit]);
+        let ids: Vec<CommitId> = merged.iter().map(|commit| commit.id).collect();
+
+        assert_eq!(ids, vec![CommitId(stash_id), CommitId(second), CommitId(first)]);
+        assert_eq!(merged[0].parents, vec![CommitId(second)]);
+    }
+}
\ No newline at end of file
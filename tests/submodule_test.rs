@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod submodule_tests {
+    use git2::{Oid, Repository, Signature};
+    use twiggy::git::repository::GitRepository;
+    use twiggy::git::types::CommitId;
+
+    fn commit_all(repo: &Repository, message: &str) -> Oid {
+        let signature = Signature::now("Test User", "test@example.com").expect("Failed to create signature");
+        let mut index = repo.index().unwrap();
+        index.add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+
+        repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)
+            .expect("Failed to create commit")
+    }
+
+    #[test]
+    fn test_repository_without_submodules_lists_none() {
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        std::fs::write(dir.path().join("file.txt"), "content").unwrap();
+        commit_all(&raw, "Initial commit");
+
+        let repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+
+        assert!(repo.list_submodules().expect("Failed to list submodules").is_empty());
+    }
+
+    #[test]
+    fn test_added_submodule_is_listed_in_sync() {
+        let library_dir = tempfile::tempdir().expect("Failed to create directory");
+        let library = Repository::init(library_dir.path()).expect("Failed to initialize repository");
+        std::fs::write(library_dir.path().join("lib.txt"), "library").unwrap();
+        let library_head = commit_all(&library, "Library commit");
+
+        let dir = tempfile::tempdir().expect("Failed to create directory");
+        let raw = Repository::init(dir.path()).expect("Failed to initialize repository");
+        std::fs::write(dir.path().join("file.txt"), "content").unwrap();
+        commit_all(&raw, "Initial commit");
+
+        let url = library_dir.path().to_str().unwrap();
+        let mut submodule = raw.submodule(url, std::path::Path::new("vendor/lib"), true)
+            .expect("Failed to add submodule");
+        submodule.clone(None).expect("Failed to clone submodule");
+        submodule.add_finalize().expect("Failed to finalize submodule");
+        commit_all(&raw, "Add submodule");
+
+        let repo = GitRepository::open(dir.path()).expect("Failed to open repository");
+        let submodules = repo.list_submodules().expect("Failed to list submodules");
+
+        assert_eq!(submodules.len(), 1);
+        let info = &submodules[0];
+        assert_eq!(info.path, "vendor/lib");
+        assert_eq!(info.url.as_deref(), Some(url));
+        assert_eq!(info.head_id, Some(CommitId(library_head)));
+        assert!(info.is_in_sync());
+    }
+}
\ No newline at end of file
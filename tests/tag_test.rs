@@ -0,0 +1,40 @@
+mod common;
+
+#[cfg(test)]
+mod tag_tests {
+    use git2::{Repository, Signature};
+    use twiggy::git::repository::GitRepository;
+    use twiggy::git::types::{CommitId, RefKind};
+    use crate::common::commit_file;
+
+    #[test]
+    fn test_list_lightweight_and_annotated_tags() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+
+        let first = commit_file(&raw, "a.txt", "one\n", "First");
+        let second = commit_file(&raw, "a.txt", "two\n", "Second");
+
+        let first_object = raw.find_object(first, None).expect("Failed to find commit");
+        raw.tag_lightweight("v0.1", &first_object, false).expect("Failed to create lightweight tag");
+
+        let second_object = raw.find_object(second, None).expect("Failed to find commit");
+        let signature = Signature::now("Test User", "test@example.com").expect("Failed to create signature");
+        raw.tag("v0.2", &second_object, &signature, "Second release\n", false)
+            .expect("Failed to create annotated tag");
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+        let tags = repo.list_tags().expect("Failed to list tags");
+
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[0].name, "v0.1");
+        assert_eq!(tags[0].target, CommitId(first));
+        assert_eq!(tags[0].message, None);
+        assert_eq!(tags[1].name, "v0.2");
+        assert_eq!(tags[1].target, CommitId(second));
+        assert_eq!(tags[1].message.as_deref(), Some("Second release"));
+
+        let labels = repo.refs_at(&CommitId(second)).expect("Failed to read ref labels");
+        assert!(labels.iter().any(|label| label.kind == RefKind::Tag && label.name == "v0.2"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod text_util_tests {
+    use twiggy::util::text::{find_ignore_case, truncate_middle};
+
+    #[test]
+    fn test_short_text_is_unchanged() {
+        assert_eq!(truncate_middle("Jane Doe", 32), "Jane Doe");
+        assert_eq!(truncate_middle("exact", 5), "exact");
+    }
+
+    #[test]
+    fn test_long_text_keeps_both_ends() {
+        let email = "very.long.name.for.testing@example.com";
+        let truncated = truncate_middle(email, 15);
+
+        assert_eq!(truncated.chars().count(), 15);
+        assert!(truncated.starts_with("very.lo"));
+        assert!(truncated.ends_with("ple.com"));
+        assert!(truncated.contains('…'));
+    }
+
+    #[test]
+    fn test_multibyte_characters() {
+        let truncated = truncate_middle("ÅÅÅÅÅÅÅÅÅÅ", 5);
+        assert_eq!(truncated, "ÅÅ…ÅÅ");
+    }
+
+    #[test]
+    fn test_tiny_limits() {
+        assert_eq!(truncate_middle("abcdef", 1), "…");
+        assert_eq!(truncate_middle("abcdef", 0), "");
+    }
+
+    #[test]
+    fn test_find_ignore_case_returns_byte_range() {
+        let summary = "Fix Parser crash";
+        let (start, end) = find_ignore_case(summary, "parser").expect("Should match");
+        assert_eq!(&summary[start..end], "Parser");
+
+        let accented = "Ärger über ÖL";
+        let (start, end) = find_ignore_case(accented, "öl").expect("Should match");
+        assert_eq!(&accented[start..end], "ÖL");
+
+        assert_eq!(find_ignore_case(summary, "missing"), None);
+        assert_eq!(find_ignore_case(summary, ""), None);
+    }
+}
\ No newline at end of file
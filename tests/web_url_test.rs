@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod web_url_tests {
+    use twiggy::git::web_url::{commit_url, repository_url};
+    use twiggy::util::os::open_url;
+
+    const SHA: &str = "0123456789abcdef0123456789abcdef01234567";
+
+    #[test]
+    fn test_github_remote_forms() {
+        let expected = format!("https://github.com/org/repo/commit/{}", SHA);
+
+        assert_eq!(commit_url("git@github.com:org/repo.git", SHA), Some(expected.clone()));
+        assert_eq!(commit_url("https://github.com/org/repo.git", SHA), Some(expected.clone()));
+        assert_eq!(commit_url("https://github.com/org/repo", SHA), Some(expected.clone()));
+        assert_eq!(commit_url("ssh://git@github.com/org/repo.git", SHA), Some(expected.clone()));
+        assert_eq!(commit_url("https://user@github.com/org/repo.git/", SHA), Some(expected));
+    }
+
+    #[test]
+    fn test_gitlab_and_bitbucket() {
+        assert_eq!(
+            commit_url("git@gitlab.com:group/sub/project.git", SHA),
+            Some(format!("https://gitlab.com/group/sub/project/-/commit/{}", SHA))
+        );
+        assert_eq!(
+            commit_url("ssh://git@altssh.gitlab.com:443/team/project.git", SHA),
+            Some(format!("https://altssh.gitlab.com/team/project/-/commit/{}", SHA))
+        );
+        assert_eq!(
+            commit_url("git@bitbucket.org:team/repo.git", SHA),
+            Some(format!("https://bitbucket.org/team/repo/commits/{}", SHA))
+        );
+    }
+
+    #[test]
+    fn test_unknown_hosts_and_local_paths() {
+        assert_eq!(commit_url("git@git.example.com:org/repo.git", SHA), None);
+        assert_eq!(commit_url("/home/user/repos/project", SHA), None);
+        assert_eq!(commit_url("C:\\repos\\project", SHA), None);
+        assert_eq!(repository_url("file:///srv/git/project.git"), None);
+        assert_eq!(repository_url("git@github.com:org/repo.git"), Some("https://github.com/org/repo".to_string()));
+    }
+
+    #[test]
+    fn test_lookalike_hosts_are_rejected() {
+        assert_eq!(commit_url("git@github.evil.example:org/repo.git", SHA), None);
+        assert_eq!(commit_url("https://notgithub.com/org/repo.git", SHA), None);
+        assert_eq!(commit_url("https://gitlab.example.com/org/repo.git", SHA), None);
+        assert_eq!(repository_url("https://bitbucket.org.evil.example/org/repo"), None);
+    }
+
+    #[test]
+    fn test_open_url_rejects_non_https() {
+        assert!(open_url("http://github.com/org/repo").is_err());
+        assert!(open_url("file:///etc/passwd").is_err());
+        assert!(open_url("calc.exe & https://github.com").is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod word_diff_tests {
+    use twiggy::git::word_diff::changed_words;
+
+    fn words<'a>(line: &'a str, ranges: &[std::ops::Range<usize>]) -> Vec<&'a str> {
+        ranges.iter().map(|range| &line[range.clone()]).collect()
+    }
+
+    #[test]
+    fn test_single_changed_word() {
+        let old = "let count = items.len();";
+        let new = "let total = items.len();";
+        let (old_ranges, new_ranges) = changed_words(old, new);
+
+        assert_eq!(words(old, &old_ranges), vec!["count"]);
+        assert_eq!(words(new, &new_ranges), vec!["total"]);
+    }
+
+    #[test]
+    fn test_adjacent_changes_merge_into_one_range() {
+        let old = "fn open(path: &Path) -> Result<()>";
+        let new = "fn open(path: impl AsRef<Path>) -> Result<()>";
+        let (old_ranges, new_ranges) = changed_words(old, new);
+
+        assert_eq!(words(old, &old_ranges), vec!["&Path)"]);
+        assert_eq!(words(new, &new_ranges), vec!["impl AsRef<Path>)"]);
+    }
+
+    #[test]
+    fn test_inserted_words_only_mark_the_new_side() {
+        let old = "hello world";
+        let new = "hello brave new world";
+        let (old_ranges, new_ranges) = changed_words(old, new);
+
+        assert!(old_ranges.is_empty());
+        assert_eq!(words(new, &new_ranges), vec!["brave new"]);
+    }
+
+    #[test]
+    fn test_unrelated_lines_have_no_emphasis() {
+        let (old_ranges, new_ranges) = changed_words("alpha beta", "gamma delta");
+
+        assert!(old_ranges.is_empty());
+        assert!(new_ranges.is_empty());
+    }
+
+    #[test]
+    fn test_multibyte_text_uses_byte_ranges() {
+        let old = "größe = 1";
+        let new = "größe = 2";
+        let (old_ranges, new_ranges) = changed_words(old, new);
+
+        assert_eq!(words(old, &old_ranges), vec!["1"]);
+        assert_eq!(words(new, &new_ranges), vec!["2"]);
+    }
+}
\ No newline at end of file
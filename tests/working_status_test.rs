@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod working_status_tests {
+    use git2::{Repository, Signature};
+    use std::path::Path;
+    use twiggy::git::repository::GitRepository;
+    use twiggy::git::types::{StatusCategory, StatusEntry};
+
+    fn stage(repo: &Repository, name: &str, contents: &str) {
+        let workdir = repo.workdir().expect("Repository should have a workdir");
+        std::fs::write(workdir.join(name), contents).expect("Failed to write file");
+
+        let mut index = repo.index().expect("Failed to open index");
+        index.add_path(Path::new(name)).expect("Failed to stage file");
+        index.write().expect("Failed to write index");
+    }
+
+    fn commit_index(repo: &Repository) {
+        let tree_id = repo.index().unwrap().write_tree().expect("Failed to write tree");
+        let tree = repo.find_tree(tree_id).expect("Failed to find tree");
+        let signature = Signature::now("Test User", "test@example.com").expect("Failed to create signature");
+
+        repo.commit(Some("HEAD"), &signature, &signature, "Initial", &tree, &[])
+            .expect("Failed to create commit");
+    }
+
+    fn entry(path: &str, category: StatusCategory) -> StatusEntry {
+        StatusEntry { path: path.to_string(), category }
+    }
+
+    #[test]
+    fn test_status_is_grouped_by_category() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+        stage(&raw, "tracked.txt", "one\n");
+        stage(&raw, "both.txt", "one\n");
+        stage(&raw, ".gitignore", "*.log\n");
+        commit_index(&raw);
+
+        let workdir = temp_dir.path();
+        std::fs::write(workdir.join("tracked.txt"), "two\n").unwrap();
+        stage(&raw, "both.txt", "two\n");
+        std::fs::write(workdir.join("both.txt"), "three\n").unwrap();
+        stage(&raw, "added.txt", "new\n");
+        std::fs::write(workdir.join("untracked.txt"), "loose\n").unwrap();
+        std::fs::write(workdir.join("debug.log"), "ignored\n").unwrap();
+
+        let repo = GitRepository::open(workdir).expect("Failed to open repository");
+        let status = repo.working_status().expect("Failed to read status");
+
+        assert_eq!(status, vec![
+            entry("added.txt", StatusCategory::Staged),
+            entry("both.txt", StatusCategory::Staged),
+            entry("both.txt", StatusCategory::Unstaged),
+            entry("tracked.txt", StatusCategory::Unstaged),
+            entry("untracked.txt", StatusCategory::Untracked),
+        ]);
+        assert_eq!(repo.count_uncommitted_changes().unwrap(), 4);
+    }
+
+    #[test]
+    fn test_clean_working_tree_has_no_entries() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let raw = Repository::init(temp_dir.path()).expect("Failed to initialize repository");
+        stage(&raw, "tracked.txt", "one\n");
+        commit_index(&raw);
+
+        let repo = GitRepository::open(temp_dir.path()).expect("Failed to open repository");
+
+        assert!(repo.working_status().expect("Failed to read status").is_empty());
+        assert_eq!(repo.count_uncommitted_changes().unwrap(), 0);
+    }
+}
\ No newline at end of file